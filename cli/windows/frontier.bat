@@ -6,22 +6,39 @@ set "MANIFEST=.frontier\Cargo.toml"
 
 if "%~1"=="build" goto :BUILD
 if "%~1"=="dev" goto :DEV
+if "%~1"=="doctor" goto :DOCTOR
+if "%~1"=="check" goto :CHECK
+if "%~1"=="completions" goto :MANAGER_QUIET
+if "%~1"=="man" goto :MANAGER_QUIET
 if "%~1"=="help" goto :HELP
 if "%~1"=="" goto :HELP
 
 goto :CARGO_PASS_THROUGH
 
 :BUILD
-echo [Frontier] Starting Build...
-cargo run --manifest-path %MANIFEST% --bin manager
-exit /b 0
+echo [Frontier] Starting Build... 1>&2
+cargo run --manifest-path %MANIFEST% --bin manager -- %*
+exit /b %errorlevel%
 
 :DEV
 echo [Frontier] Development Mode...
-set FRONTIER_DEV=true
-cargo run --manifest-path %MANIFEST% --bin core
+cargo run --manifest-path %MANIFEST% --bin core -- --dev %*
 exit /b 0
 
+:DOCTOR
+echo [Frontier] Checking Environment...
+cargo run --manifest-path %MANIFEST% --bin manager -- %*
+exit /b %errorlevel%
+
+:CHECK
+echo [Frontier] Validating Configuration...
+cargo run --manifest-path %MANIFEST% --bin manager -- %*
+exit /b %errorlevel%
+
+:MANAGER_QUIET
+cargo run --quiet --manifest-path %MANIFEST% --bin manager -- %*
+exit /b %errorlevel%
+
 :CARGO_PASS_THROUGH
 cargo %* --manifest-path %MANIFEST%
 exit /b %errorlevel%
@@ -31,7 +48,13 @@ echo.
 echo Commands:
 echo    .\frontier dev      - Test app with Hot Reload
 echo    .\frontier build    - Make a final binary
+echo    .\frontier doctor   - Diagnose the environment
+echo    .\frontier check    - Validate frontier.toml
+echo    .\frontier completions ^<shell^> - Print powershell/bash/zsh/fish completions
+echo    .\frontier man      - Print the man page
 echo    .\back [command]    - Run command in "app\backend"
 echo    .\front [command]   - Run command in "app\frontend"
 echo.
+echo Options of each command: .\frontier ^<command^> --help
+echo.
 exit /b 0
\ No newline at end of file
@@ -21,7 +21,9 @@ fn main() {
     }
 
     // 2. Configure Windows Resources (Icon and Metadata)
-    if cfg!(target_os = "windows") {
+    // (CARGO_CFG_TARGET_OS is the platform being built for, not the one running this script;
+    // rc.exe output doesn't depend on the CPU, so the same resources serve x64 and ARM64 builds)
+    if env::var("CARGO_CFG_TARGET_OS").as_deref() == Ok("windows") {
         let mut res = winres::WindowsResource::new();
         
         // Try to find the icon in the original App folder (Relative Path)
@@ -48,9 +50,17 @@ fn main() {
         }
     }
     
+    // 3. Console variant (`frontier build --console`): the runtime keeps the
+    // console subsystem on Windows instead of windows_subsystem = "windows"
+    println!("cargo:rustc-check-cfg=cfg(frontier_console)");
+    if env::var("FRONTIER_CONSOLE").as_deref() == Ok("1") {
+        println!("cargo:rustc-cfg=frontier_console");
+    }
+
     // Monitoring
     println!("cargo:rerun-if-changed=../app/frontend/icon.ico");
     println!("cargo:rerun-if-changed=icon.ico");
     println!("cargo:rerun-if-changed=build.rs");
     println!("cargo:rerun-if-env-changed=FRONTIER_APP_VERSION");
+    println!("cargo:rerun-if-env-changed=FRONTIER_CONSOLE");
 }
\ No newline at end of file
@@ -0,0 +1,180 @@
+// Copyright (c) 2026 The Frontier Framework Authors
+// SPDX-License-Identifier: Apache-2.0 WITH LLVM-exception OR MIT
+
+// App Data Protocol Module
+//
+// appdata://app/<folder>/<file> serves files from the folders of the app's
+// data folder listed in [appdata] folders (images a backend saved, exported
+// documents, ...), so pages can show them with <img src> instead of sending
+// base64 through IPC. Nothing else in the data folder (webview storage, lock
+// files) can be reached. Files are read-only unless [appdata] writable is on,
+// which lets pages PUT and DELETE files in those folders.
+// WebView2 serves the protocol from https://appdata.app/ instead.
+// Only the app's own pages (and the dev server's, in dev) may read responses
+// or write: requests from any other Origin, such as a whitelisted remote site
+// or its iframes, are refused.
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+use tracing::{debug, warn};
+use wry::http::{header, response::Builder, Method, Request, Response};
+
+use crate::config::AppDataConfig;
+use crate::serve;
+
+pub const SCHEME: &str = "appdata";
+
+#[derive(Clone, PartialEq)]
+pub struct AppData {
+    data_dir: PathBuf,
+    folders: Vec<PathBuf>,
+    writable: bool,
+    mime_types: HashMap<String, String>,
+    origins: Vec<String>, // Origins allowed to use the files
+}
+
+impl AppData {
+    // None when no folder is served; `scheme` is the app's, `dev_url` the dev server's
+    pub fn new(data_dir: &Path, config: &AppDataConfig, mime_types: &HashMap<String, String>, scheme: &str, dev_url: Option<&str>) -> Option<Self> {
+        let folders: Vec<PathBuf> = config.folders.iter().filter_map(|folder| {
+            let path = PathBuf::from(folder.trim_matches('/'));
+            let plain = path.components().count() > 0 && path.components().all(|c| matches!(c, Component::Normal(_)));
+            if !plain { warn!(target: "config", "⚠️ [CONFIG] [appdata] folder '{}' must be a folder inside the data folder", folder); }
+            plain.then_some(path)
+        }).collect();
+        if folders.is_empty() { return None; }
+        // Custom protocols are served from https://<scheme>.app/ by WebView2
+        let app_origin = if cfg!(target_os = "windows") { format!("https://{}.app", scheme) } else { format!("{}://app", scheme) };
+        let origins = std::iter::once(app_origin).chain(dev_url.map(origin_of)).collect();
+        Some(AppData { data_dir: data_dir.to_path_buf(), folders, writable: config.writable, mime_types: mime_types.clone(), origins })
+    }
+
+    // The request's Origin when it may use the files; Err for other origins.
+    // Requests without one (<img>, <video>) can only display files, not read them.
+    fn allowed_origin<'a>(&self, req: &'a Request<Vec<u8>>) -> Result<Option<&'a str>, ()> {
+        match req.headers().get(header::ORIGIN).map(|o| o.to_str().unwrap_or_default()) {
+            None => Ok(None),
+            Some(origin) if self.origins.iter().any(|allowed| allowed == origin) => Ok(Some(origin)),
+            Some(_) => Err(()),
+        }
+    }
+
+    // File a request path points to, when it lies in a served folder
+    fn resolve(&self, path: &str) -> Option<PathBuf> {
+        let decoded = percent_encoding::percent_decode_str(path).decode_utf8_lossy().to_string();
+        let relative = PathBuf::from(decoded.trim_start_matches('/'));
+        if !relative.components().all(|c| matches!(c, Component::Normal(_))) { return None; }
+        self.folders.iter()
+            .any(|folder| relative.starts_with(folder) && relative != *folder)
+            .then(|| self.data_dir.join(relative))
+    }
+
+    pub fn handle(&self, req: &Request<Vec<u8>>) -> wry::Result<Response<Cow<'static, [u8]>>> {
+        let Ok(origin) = self.allowed_origin(req) else {
+            warn!(target: "security", "🚫 [SECURITY] appdata: {} {} refused for origin {:?}", req.method(), req.uri().path(), req.headers().get(header::ORIGIN));
+            return Response::builder().status(403).body(Cow::Owned(b"403".to_vec())).map_err(|_| wry::Error::InitScriptError);
+        };
+        let status = |code: u16| cors(Response::builder(), origin).status(code).body(Cow::Owned(code.to_string().into_bytes()));
+        let methods = if self.writable { "GET, HEAD, PUT, DELETE" } else { "GET, HEAD" };
+        // Pages live on another origin (<scheme>://app), so writes are preflighted
+        if req.method() == Method::OPTIONS {
+            return cors(Response::builder(), origin)
+                .status(204)
+                .header("Access-Control-Allow-Methods", methods)
+                .header("Access-Control-Allow-Headers", "Content-Type, Range")
+                .body(Cow::Owned(Vec::new()))
+                .map_err(|_| wry::Error::InitScriptError);
+        }
+        // Writes come from fetch(), which always names its origin
+        if origin.is_none() && matches!(*req.method(), Method::PUT | Method::DELETE) {
+            warn!(target: "security", "🚫 [SECURITY] appdata: {} {} refused (no origin)", req.method(), req.uri().path());
+            return status(403).map_err(|_| wry::Error::InitScriptError);
+        }
+        let Some(path) = self.resolve(req.uri().path()) else {
+            warn!(target: "security", "🚫 [SECURITY] appdata: {} is outside the [appdata] folders", req.uri().path());
+            return status(403).map_err(|_| wry::Error::InitScriptError);
+        };
+
+        let response = match *req.method() {
+            Method::GET | Method::HEAD => return self.read(req, &path, origin),
+            Method::PUT | Method::DELETE if !self.writable => {
+                warn!(target: "security", "🚫 [SECURITY] appdata: {} {} refused ([appdata] writable is off)", req.method(), req.uri().path());
+                status(403)
+            }
+            Method::PUT => {
+                let written = path.parent().map_or(Ok(()), fs::create_dir_all).and_then(|_| fs::write(&path, req.body()));
+                match written {
+                    Ok(()) => { debug!(target: "asset", "📦 [APPDATA] Wrote {} ({} bytes)", path.display(), req.body().len()); status(204) }
+                    Err(e) => { warn!(target: "asset", "❌ [APPDATA] Cannot write {}: {}", path.display(), e); status(500) }
+                }
+            }
+            Method::DELETE => match fs::remove_file(&path) {
+                Ok(()) => { debug!(target: "asset", "📦 [APPDATA] Deleted {}", path.display()); status(204) }
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => status(404),
+                Err(e) => { warn!(target: "asset", "❌ [APPDATA] Cannot delete {}: {}", path.display(), e); status(500) }
+            },
+            _ => Response::builder().status(405).header(header::ALLOW, methods).body(Cow::Owned(b"405".to_vec())),
+        };
+        response.map_err(|_| wry::Error::InitScriptError)
+    }
+
+    // Like the app's own files: kept in memory, with ETag/304 and byte ranges
+    fn read(&self, req: &Request<Vec<u8>>, path: &Path, origin: Option<&str>) -> wry::Result<Response<Cow<'static, [u8]>>> {
+        let asset = match serve::read(path) {
+            Ok(asset) => asset,
+            Err(_) => {
+                debug!(target: "asset", "📦 [APPDATA] Not found: {}", path.display());
+                return cors(Response::builder(), origin).status(404).body(Cow::Owned(b"404".to_vec()))
+                    .map_err(|_| wry::Error::InitScriptError);
+            }
+        };
+        let mut response = cors(Response::builder(), origin)
+            .header(header::CONTENT_TYPE, serve::content_type(path, &self.mime_types))
+            .header(header::CACHE_CONTROL, "no-cache")
+            .header(header::ACCEPT_RANGES, "bytes")
+            .header(header::ETAG, asset.etag.as_str())
+            .header(header::LAST_MODIFIED, asset.last_modified.as_str());
+        if serve::is_not_modified(req.headers(), &asset) {
+            return response.status(304).body(Cow::Owned(Vec::new())).map_err(|_| wry::Error::InitScriptError);
+        }
+        let range = match serve::requested_range(req.headers(), &asset) {
+            Ok(range) => range,
+            Err(()) => {
+                return response.status(416)
+                    .header(header::CONTENT_RANGE, format!("bytes */{}", asset.len))
+                    .body(Cow::Owned(Vec::new())).map_err(|_| wry::Error::InitScriptError);
+            }
+        };
+        if let Some((start, end)) = range {
+            response = response.status(206).header(header::CONTENT_RANGE, format!("bytes {}-{}/{}", start, end, asset.len));
+        }
+        let body = if *req.method() == Method::HEAD { Ok(Cow::Owned(Vec::new())) } else { asset.bytes(path, range) };
+        let body = match body {
+            Ok(body) => body,
+            Err(e) => {
+                warn!(target: "asset", "❌ [APPDATA] Cannot read {}: {}", path.display(), e);
+                return Response::builder().status(500).body(Cow::Owned(b"500".to_vec())).map_err(|_| wry::Error::InitScriptError);
+            }
+        };
+        response.body(body).map_err(|_| wry::Error::InitScriptError)
+    }
+}
+
+// Let an allowed origin read the response
+fn cors(builder: Builder, origin: Option<&str>) -> Builder {
+    let builder = builder.header(header::VARY, "Origin");
+    match origin {
+        Some(origin) => builder.header(header::ACCESS_CONTROL_ALLOW_ORIGIN, origin),
+        None => builder,
+    }
+}
+
+// "http://localhost:5173/index.html" -> "http://localhost:5173"
+fn origin_of(url: &str) -> String {
+    let host_start = url.find("://").map_or(0, |i| i + 3);
+    let end = url[host_start..].find('/').map_or(url.len(), |i| host_start + i);
+    url[..end].to_string()
+}
@@ -4,47 +4,225 @@
 // Assets Management Module
 // 
 // This module handles copying and organizing frontend assets (HTML, CSS, JS, images)
-// from the app/frontend directory to the build assets directory.
+// from the app/frontend directory to the build assets directory, or packing them
+// into a single archive ([frontend] archive).
 
 use std::fs;
-use std::path::Path;
+use std::io;
+use regex::Regex;
+use std::path::{Path, PathBuf};
 use walkdir::WalkDir;
+use zip::{write::FileOptions, CompressionMethod};
 
-// Copy frontend files to the assets directory
-pub fn copy_frontend_files(src: &Path, dst: &Path) {
-    if !src.exists() {
-        return;
+// Copy frontend files to the assets directory, skipping excluded paths
+pub fn copy_frontend_files(src: &Path, dst: &Path, exclude: &[String]) {
+    for (path, rel) in bundled_files(src, exclude) {
+        let dest_path = dst.join(rel);
+
+        // Create parent directory if needed
+        if let Some(parent) = dest_path.parent() {
+            let _ = fs::create_dir_all(parent);
+        }
+
+        let _ = fs::copy(path, &dest_path);
     }
+}
 
-    for entry in WalkDir::new(src).min_depth(1) {
-        let entry = match entry {
-            Ok(e) => e,
-            Err(_) => continue,
+// Pack frontend files into one zip ([frontend] archive), skipping excluded paths;
+// returns how many files it holds
+pub fn archive_frontend_files(src: &Path, dest: &Path, exclude: &[String]) -> zip::result::ZipResult<usize> {
+    let mut archive = zip::ZipWriter::new(fs::File::create(dest)?);
+    let mut count = 0;
+    for (path, rel) in bundled_files(src, exclude) {
+        // Images, media and fonts are compressed already
+        let method = match path.extension().and_then(|e| e.to_str()).map(|e| e.to_lowercase()).as_deref() {
+            Some("png" | "jpg" | "jpeg" | "gif" | "webp" | "avif" | "mp3" | "mp4" | "webm" | "ogg" | "woff" | "woff2" | "zip" | "gz" | "br") => CompressionMethod::Stored,
+            _ => CompressionMethod::Deflated,
         };
+        archive.start_file(rel, FileOptions::default().compression_method(method))?;
+        io::copy(&mut fs::File::open(&path)?, &mut archive)?;
+        count += 1;
+    }
+    archive.finish()?;
+    Ok(count)
+}
+
+// Files under `src` that are bundled: (path, path relative to src with forward slashes)
+fn bundled_files<'a>(src: &'a Path, exclude: &[String]) -> impl Iterator<Item = (PathBuf, String)> + 'a {
+    let patterns = compile_exclude_patterns(exclude);
+    let folder_patterns = patterns.clone();
+    // A "!" pattern can bring back a file inside an excluded folder, so folders
+    // are only skipped whole when there is none
+    let prune = !patterns.iter().any(|p| p.negated);
+    WalkDir::new(src).min_depth(1).into_iter()
+        .filter_entry(move |e| !(prune && e.file_type().is_dir() && is_excluded(&relative_path(src, e.path()), true, &folder_patterns)))
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_type().is_file())
+        .map(move |entry| (relative_path(src, entry.path()), entry.into_path()))
+        .filter(move |(rel, _)| !is_excluded(rel, false, &patterns))
+        .map(|(rel, path)| (path, rel))
+}
 
-        if entry.file_type().is_file() {
-            let filename = entry.file_name();
-            let dest_path = dst.join(filename);
+#[derive(Clone)]
+struct ExcludePattern {
+    regex: Regex,
+    full_path: bool, // Contains '/': matched against the whole relative path
+    folder: bool,    // Ends with '/': only matches folders
+    negated: bool,   // Starts with '!': brings back what earlier patterns excluded
+}
 
-            // Create parent directory if needed
-            if let Some(parent) = dest_path.parent() {
-                let _ = fs::create_dir_all(parent);
+// Compile glob patterns ("*", "**", "?") into anchored regexes
+//
+// Patterns containing '/' are matched against the whole relative path
+// (e.g. "node_modules/**"); patterns without '/' are matched against each
+// path component (e.g. "*.map" or "node_modules"). A trailing '/' limits a
+// pattern to folders, and a leading '!' re-includes matches; like .gitignore,
+// the last matching pattern decides.
+fn compile_exclude_patterns(patterns: &[String]) -> Vec<ExcludePattern> {
+    let mut compiled = Vec::new();
+    for pattern in patterns {
+        let pattern = pattern.trim();
+        let (negated, pattern) = match pattern.strip_prefix('!') {
+            Some(rest) => (true, rest),
+            None => (false, pattern),
+        };
+        let pattern = pattern.replace('\\', "/").trim_start_matches("./").to_string();
+        let (folder, pattern) = match pattern.strip_suffix('/') {
+            Some(rest) => (true, rest.to_string()),
+            None => (false, pattern),
+        };
+        if pattern.is_empty() {
+            continue;
+        }
+
+        let mut re = String::from("^");
+        let chars: Vec<char> = pattern.chars().collect();
+        let mut i = 0;
+        while i < chars.len() {
+            match chars[i] {
+                '*' if chars.get(i + 1) == Some(&'*') => {
+                    // "dir/**" also matches "dir" itself so the whole folder is skipped
+                    if re.ends_with('/') && i + 2 == chars.len() {
+                        re.pop();
+                        re.push_str("(/.*)?");
+                    } else if chars.get(i + 2) == Some(&'/') {
+                        // "**/" matches zero or more leading folders
+                        re.push_str("(.*/)?");
+                        i += 1;
+                    } else {
+                        re.push_str(".*");
+                    }
+                    i += 1;
+                }
+                '*' => re.push_str("[^/]*"),
+                '?' => re.push_str("[^/]"),
+                c => re.push_str(&regex::escape(&c.to_string())),
             }
+            i += 1;
+        }
+        re.push('$');
+
+        if let Ok(regex) = Regex::new(&re) {
+            compiled.push(ExcludePattern { regex, full_path: pattern.contains('/'), folder, negated });
+        }
+    }
+    compiled
+}
 
-            let _ = fs::copy(entry.path(), &dest_path);
+// Whether `rel` (a folder when `is_dir`) is excluded: the last pattern that matches decides
+fn is_excluded(rel: &str, is_dir: bool, patterns: &[ExcludePattern]) -> bool {
+    let parts: Vec<&str> = rel.split('/').collect();
+    let mut excluded = false;
+    for pattern in patterns {
+        // Each leading folder counts too, so a file inside an excluded folder is excluded
+        let matched = (0..parts.len()).any(|i| {
+            let subject = if pattern.full_path { parts[..=i].join("/") } else { parts[i].to_string() };
+            (is_dir || !pattern.folder || i + 1 < parts.len()) && pattern.regex.is_match(&subject)
+        });
+        if matched {
+            excluded = !pattern.negated;
         }
     }
+    excluded
+}
+
+// Relative path with forward slashes, used for pattern matching
+fn relative_path(root: &Path, path: &Path) -> String {
+    path.strip_prefix(root)
+        .unwrap_or(path)
+        .to_string_lossy()
+        .replace('\\', "/")
 }
 
-// Copy and organize icon file
-pub fn copy_icon(icon_path: &Path, assets_path: &Path) -> Option<()> {
+// Copy an icon file as <name>.<ext> (app_icon, tray_icon)
+pub fn copy_icon(icon_path: &Path, assets_path: &Path, name: &str) -> Option<()> {
     if !icon_path.exists() {
         return None;
     }
 
     let ext = icon_path.extension()?;
-    let dest = assets_path.join("app_icon").with_extension(ext);
+    let dest = assets_path.join(name).with_extension(ext);
 
     fs::copy(icon_path, dest).ok()?;
     Some(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn excluded(patterns: &[&str], rel: &str, is_dir: bool) -> bool {
+        let patterns: Vec<String> = patterns.iter().map(|p| p.to_string()).collect();
+        is_excluded(rel, is_dir, &compile_exclude_patterns(&patterns))
+    }
+
+    #[test]
+    fn star_stays_within_a_folder() {
+        assert!(excluded(&["src/*.js"], "src/app.js", false));
+        assert!(!excluded(&["src/*.js"], "src/lib/app.js", false));
+        assert!(excluded(&["img/logo?.png"], "img/logo2.png", false));
+        assert!(!excluded(&["img/logo?.png"], "img/logo10.png", false));
+        assert!(!excluded(&["a?b"], "a/b", false));
+    }
+
+    #[test]
+    fn double_star_spans_folders() {
+        assert!(excluded(&["node_modules/**"], "node_modules", true));
+        assert!(excluded(&["node_modules/**"], "node_modules/pkg/index.js", false));
+        assert!(!excluded(&["node_modules/**"], "lib/node_modules.js", false));
+        assert!(excluded(&["**/test/*.js"], "test/a.js", false));
+        assert!(excluded(&["**/test/*.js"], "src/deep/test/a.js", false));
+        assert!(excluded(&["docs/**/*.md"], "docs/a/b/c.md", false));
+        assert!(excluded(&["docs/**/*.md"], "docs/c.md", false));
+    }
+
+    #[test]
+    fn names_match_any_component() {
+        assert!(excluded(&["*.map"], "js/app.js.map", false));
+        assert!(excluded(&["node_modules"], "vendor/node_modules/x.js", false));
+        assert!(excluded(&["drafts"], "drafts", true));
+        assert!(!excluded(&["*.map"], "maps/index.html", false));
+        assert!(excluded(&["./*.psd"], "art/cover.psd", false));
+        assert!(excluded(&[" .\\art\\*.psd "], "art/cover.psd", false));
+    }
+
+    #[test]
+    fn trailing_slash_matches_only_folders() {
+        assert!(excluded(&["build/"], "build", true));
+        assert!(excluded(&["build/"], "build/app.js", false));
+        assert!(excluded(&["build/"], "src/build/app.js", false));
+        assert!(!excluded(&["build/"], "build", false));
+        assert!(excluded(&["out/tmp/"], "out/tmp/a.txt", false));
+        assert!(!excluded(&["out/tmp/"], "out/tmp", false));
+    }
+
+    #[test]
+    fn last_matching_pattern_decides() {
+        assert!(!excluded(&["*.map", "!keep.map"], "js/keep.map", false));
+        assert!(excluded(&["!keep.map", "*.map"], "js/keep.map", false));
+        assert!(excluded(&["*.map", "!keep.map"], "js/app.map", false));
+        assert!(!excluded(&["assets/**", "!assets/logo.png"], "assets/logo.png", false));
+        assert!(excluded(&["assets/**", "!assets/logo.png"], "assets/other.png", false));
+        assert!(!excluded(&["!*.js"], "app.js", false));
+    }
+}
@@ -0,0 +1,287 @@
+// Copyright (c) 2026 The Frontier Framework Authors
+// SPDX-License-Identifier: Apache-2.0 WITH LLVM-exception OR MIT
+
+// Automation Module
+//
+// A control socket for end-to-end tests, started with `--automation <port>`
+// (or FRONTIER_AUTOMATION=<port>; 0 picks a free port). It listens on
+// 127.0.0.1 only and speaks one JSON object per line:
+//
+//   -> {"id": 1, "token": "...", "cmd": "click", "window": "index_html", "selector": "#save"}
+//   <- {"id": 1, "ok": true, "result": true}
+//
+// Every request carries the random token of this run, so web pages (which can
+// POST to localhost) can't drive the app; a line that isn't a JSON request
+// with the token closes the connection.
+// Commands that touch windows are handed to the event loop; scripts run in the
+// page and post their result back through IPC (__automation|<id>|<json>).
+// The chosen port and token are printed as FRONTIER_AUTOMATION_PORT=<port> and
+// FRONTIER_AUTOMATION_TOKEN=<token>, and written to <data_dir>/automation.port
+// and automation.token for harnesses that don't read stdout.
+//
+// `--rpc-stdio` offers the same commands as JSON-RPC 2.0 on stdin/stdout, for
+// tools that start the app as a child process (logs stay on stderr):
+//
+//   -> {"jsonrpc": "2.0", "id": 1, "method": "trigger", "params": {"trigger": "sum", "args": "1 2"}}
+//   <- {"jsonrpc": "2.0", "id": 1, "result": {"reply": "3", "error": null}}
+
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::fs;
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::{Ipv4Addr, TcpListener, TcpStream};
+use std::path::Path;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc::{self, Sender};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use tracing::{info, warn};
+
+use crate::inspect;
+
+const DEFAULT_TIMEOUT_MS: u64 = 5000;
+// Commands handed to the event loop
+const LOOP_COMMANDS: [&str; 7] = ["open", "eval", "click", "type", "wait", "trigger", "quit"];
+
+// JSON-RPC error codes
+const PARSE_ERROR: i64 = -32700;
+const INVALID_REQUEST: i64 = -32600;
+const METHOD_NOT_FOUND: i64 = -32601;
+const COMMAND_FAILED: i64 = -32000;
+
+static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+
+lazy_static::lazy_static! {
+    // Scripts waiting for their page to answer, by request id
+    static ref PENDING: Mutex<HashMap<u64, Sender<Result<Value, String>>>> = Mutex::new(HashMap::new());
+}
+
+// Hands window commands to the event loop (event loop proxies aren't Sync)
+type Sink = Arc<Mutex<Box<dyn Fn(Request) + Send>>>;
+
+// A command for the event loop; answer it with `respond`, or run the script
+// from `into_page_script` in the target window
+pub struct Request {
+    id: u64,
+    pub command: String,
+    pub params: Value,
+    reply: Sender<Result<Value, String>>,
+}
+
+impl Request {
+    pub fn respond(self, result: Result<Value, String>) {
+        let _ = self.reply.send(result);
+    }
+
+    // Window the command targets ("window": page id or window key; None = the first window)
+    pub fn window(&self) -> Option<&str> {
+        self.params["window"].as_str()
+    }
+
+    // Script to evaluate for eval/click/type/wait, as the body of a function
+    // that may return a value or a Promise
+    fn script(&self) -> Result<String, String> {
+        let text = |name: &str| -> Result<String, String> {
+            self.params[name].as_str().map(str::to_string).ok_or_else(|| format!("'{}' is required", name))
+        };
+        let quoted = |name: &str| -> Result<String, String> { text(name).map(|t| Value::from(t).to_string()) };
+        Ok(match self.command.as_str() {
+            "eval" => text("script")?,
+            "click" => format!(
+                "var el = document.querySelector({}); if (!el) throw new Error('No element matches the selector'); el.click(); return true;",
+                quoted("selector")?
+            ),
+            "type" => format!(
+                "var el = document.querySelector({}); if (!el) throw new Error('No element matches the selector'); el.focus(); el.value = {}; \
+                 el.dispatchEvent(new Event('input', {{ bubbles: true }})); el.dispatchEvent(new Event('change', {{ bubbles: true }})); return true;",
+                quoted("selector")?, quoted("text")?
+            ),
+            "wait" => format!(
+                "var sel = {}; return new Promise(function(resolve) {{ (function poll() {{ \
+                 if (document.querySelector(sel)) resolve(true); else setTimeout(poll, 50); }})(); }});",
+                quoted("selector")?
+            ),
+            other => return Err(format!("'{}' is not a page command", other)),
+        })
+    }
+
+    // Wrap the script so its result comes back through IPC and park the reply
+    // until then (None when the command was already answered with an error)
+    pub fn into_page_script(self) -> Option<String> {
+        let body = match self.script() {
+            Ok(body) => body,
+            Err(e) => { self.respond(Err(e)); return None; }
+        };
+        let id = self.id;
+        if let Ok(mut pending) = PENDING.lock() { pending.insert(id, self.reply); }
+        Some(format!(
+            r#"(function() {{
+    function send(ok, value) {{ window.ipc.postMessage('__automation|{id}|' + JSON.stringify({{ ok: ok, value: value === undefined ? null : value }})); }}
+    try {{
+        Promise.resolve((function() {{ {body} }})()).then(function(v) {{ send(true, v); }}, function(e) {{ send(false, String(e && e.message || e)); }});
+    }} catch (e) {{ send(false, String(e && e.message || e)); }}
+}})();"#
+        ))
+    }
+}
+
+// Port from --automation <port> or FRONTIER_AUTOMATION
+pub fn requested_port(args: &[String]) -> Option<u16> {
+    args.windows(2).find(|w| w[0] == "--automation").map(|w| w[1].clone())
+        .or_else(|| std::env::var("FRONTIER_AUTOMATION").ok())
+        .and_then(|p| p.trim().parse().ok())
+}
+
+// --rpc-stdio: JSON-RPC on stdin/stdout instead of (or besides) the socket
+pub fn rpc_stdio_requested(args: &[String]) -> bool {
+    args.iter().any(|a| a == "--rpc-stdio")
+}
+
+// Start the control socket; `send` hands window commands to the event loop
+pub fn start(port: u16, data_dir: &Path, send: impl Fn(Request) + Send + 'static) {
+    let listener = match TcpListener::bind((Ipv4Addr::LOCALHOST, port)) {
+        Ok(listener) => listener,
+        Err(e) => { warn!(target: "app", "⚠️ [AUTOMATION] Cannot listen on port {}: {}", port, e); return; }
+    };
+    let port = listener.local_addr().map(|a| a.port()).unwrap_or(port);
+    let token: Arc<str> = uuid::Uuid::new_v4().simple().to_string().into();
+    // The token first, so a harness that sees the port can read it
+    if let Err(e) = fs::write(data_dir.join("automation.token"), token.as_bytes()) {
+        warn!(target: "app", "⚠️ [AUTOMATION] Cannot write automation.token: {}", e);
+    }
+    let _ = fs::write(data_dir.join("automation.port"), port.to_string());
+    println!("FRONTIER_AUTOMATION_PORT={}", port);
+    println!("FRONTIER_AUTOMATION_TOKEN={}", token);
+    info!(target: "app", "🤖 [AUTOMATION] Listening on 127.0.0.1:{}", port);
+
+    let sink: Sink = Arc::new(Mutex::new(Box::new(send)));
+    std::thread::Builder::new().name("frontier-automation".into()).spawn(move || {
+        for stream in listener.incoming().flatten() {
+            let (sink, token) = (sink.clone(), token.clone());
+            std::thread::spawn(move || serve(stream, &sink, &token));
+        }
+    }).ok();
+}
+
+// Answer JSON-RPC requests from stdin; each runs on its own thread, so a slow
+// `wait` or `trigger` doesn't hold up the others. The app quits when stdin closes.
+pub fn start_stdio(send: impl Fn(Request) + Send + 'static) {
+    info!(target: "app", "🤖 [AUTOMATION] Accepting JSON-RPC on stdin");
+    let sink: Sink = Arc::new(Mutex::new(Box::new(send)));
+    std::thread::Builder::new().name("frontier-rpc".into()).spawn(move || {
+        for line in io::stdin().lock().lines() {
+            let Ok(line) = line else { break };
+            if line.trim().is_empty() { continue; }
+            let sink = sink.clone();
+            std::thread::spawn(move || {
+                if let Some(answer) = rpc_answer(&line, &sink) { write_stdout(&answer); }
+            });
+        }
+        let _ = execute("quit", &json!({}), &sink);
+    }).ok();
+}
+
+// The response to one JSON-RPC line (None for notifications, which have no id)
+fn rpc_answer(line: &str, sink: &Sink) -> Option<Value> {
+    let error = |id: &Value, code: i64, message: String| json!({ "jsonrpc": "2.0", "id": id, "error": { "code": code, "message": message } });
+    let message: Value = match serde_json::from_str(line) {
+        Ok(message) => message,
+        Err(e) => return Some(error(&Value::Null, PARSE_ERROR, format!("Invalid JSON: {}", e))),
+    };
+    let id = message.get("id").cloned();
+    let answer = match message["method"].as_str() {
+        None => error(&Value::Null, INVALID_REQUEST, "'method' is required".into()),
+        Some(method) if !is_command(method) => error(&message["id"], METHOD_NOT_FOUND, format!("Unknown method '{}'", method)),
+        Some(method) => {
+            let params = if message["params"].is_object() { message["params"].clone() } else { json!({}) };
+            match execute(method, &params, sink) {
+                Ok(result) => json!({ "jsonrpc": "2.0", "id": message["id"], "result": result }),
+                Err(e) => error(&message["id"], COMMAND_FAILED, e),
+            }
+        }
+    };
+    id.map(|_| answer)
+}
+
+fn write_stdout(answer: &Value) {
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+    let _ = writeln!(out, "{}", answer);
+    let _ = out.flush();
+}
+
+// A page answered an eval (IPC __automation|<id>|<json>)
+pub fn resolve(id: &str, payload: &str) {
+    let Ok(id) = id.parse::<u64>() else { return };
+    let Some(reply) = PENDING.lock().ok().and_then(|mut p| p.remove(&id)) else { return };
+    let answer: Value = serde_json::from_str(payload).unwrap_or(Value::Null);
+    let result = if answer["ok"].as_bool() == Some(true) {
+        Ok(answer["value"].clone())
+    } else {
+        Err(answer["value"].as_str().unwrap_or("Script failed").to_string())
+    };
+    let _ = reply.send(result);
+}
+
+// One connection: answer each line in order, until one isn't a request with the token
+fn serve(stream: TcpStream, sink: &Sink, token: &str) {
+    let Ok(mut writer) = stream.try_clone() else { return };
+    for line in BufReader::new(stream).lines() {
+        let Ok(line) = line else { break };
+        if line.trim().is_empty() { continue; }
+        let message: Value = match serde_json::from_str(&line) {
+            Ok(message) => message,
+            Err(e) => {
+                // e.g. the headers of an HTTP request sent by a web page
+                let _ = writeln!(writer, "{}", json!({ "ok": false, "error": format!("Invalid JSON: {}", e) }));
+                break;
+            }
+        };
+        if message["token"].as_str() != Some(token) {
+            warn!(target: "security", "🚫 [AUTOMATION] Request without the automation token refused");
+            let _ = writeln!(writer, "{}", json!({ "id": message["id"], "ok": false, "error": "Invalid or missing 'token'" }));
+            break;
+        }
+        let result = match message["cmd"].as_str() {
+            Some(command) => execute(command, &message, sink),
+            None => Err("'cmd' is required".to_string()),
+        };
+        let answer = match result {
+            Ok(value) => json!({ "id": message["id"], "ok": true, "result": value }),
+            Err(error) => json!({ "id": message["id"], "ok": false, "error": error }),
+        };
+        if writeln!(writer, "{}", answer).is_err() { break; }
+    }
+}
+
+fn is_command(command: &str) -> bool {
+    matches!(command, "windows" | "ipc") || LOOP_COMMANDS.contains(&command)
+}
+
+// Run a command; `params` holds its arguments ("window", "selector", ...)
+fn execute(command: &str, params: &Value, sink: &Sink) -> Result<Value, String> {
+    match command {
+        // Answered here, from what inspect already tracks
+        "windows" => return Ok(inspect::snapshot()["windows"].clone()),
+        "ipc" => return Ok(inspect::ipc_since(params["since"].as_u64().unwrap_or(0))),
+        _ if LOOP_COMMANDS.contains(&command) => {}
+        other => return Err(format!("Unknown command '{}'", other)),
+    }
+
+    let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+    let (reply, answer) = mpsc::channel();
+    if let Ok(send) = sink.lock() {
+        send(Request { id, command: command.to_string(), params: params.clone(), reply });
+    }
+    // Backends are bounded by [backend] timeout unless the caller sets one
+    let timeout = match params["timeout_ms"].as_u64() {
+        Some(ms) => Duration::from_millis(ms),
+        None if command == "trigger" => return answer.recv().unwrap_or_else(|_| Err("'trigger' got no reply".into())),
+        None => Duration::from_millis(DEFAULT_TIMEOUT_MS),
+    };
+    answer.recv_timeout(timeout).unwrap_or_else(|_| {
+        if let Ok(mut pending) = PENDING.lock() { pending.remove(&id); }
+        Err(format!("'{}' timed out after {} ms", command, timeout.as_millis()))
+    })
+}
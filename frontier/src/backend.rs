@@ -19,6 +19,11 @@ pub struct ModuleManifest {
     pub interpreter: Option<String>,
     #[serde(default = "default_suppress")]
     pub suppress_window: bool,
+    pub worker: Option<String>,
+    pub timeout_ms: Option<u64>,
+    pub args_mode: Option<String>,
+    #[serde(default)]
+    pub serial: bool,
     pub build: Option<BuildRule>,
 }
 
@@ -37,10 +42,14 @@ pub struct RuntimeMeta {
     pub filename: String,
     pub interpreter: Option<String>,
     pub suppress_window: bool,
+    pub worker: Option<String>,
+    pub timeout_ms: Option<u64>,
+    pub args_mode: Option<String>,
+    pub serial: bool,
 }
 
 // Safely splits the command into parts, respecting quotes.
-fn split_shell_args(cmd: &str) -> Vec<String> {
+pub fn split_shell_args(cmd: &str) -> Vec<String> {
     let mut args = Vec::new();
     let mut current = String::new();
     let mut in_quotes = false;
@@ -87,34 +96,39 @@ pub fn load_modules(modules_path: &Path) -> HashMap<String, ModuleManifest> {
     builders
 }
 
-// Process backend files and generate metadata
+// Process backend files and write the command table (commands.json)
+//
+// The table is embedded in the executable and read straight from memory at
+// startup, so release builds never scan directories for triggers.
 pub fn process_backend_files(
     backend_path: &Path,
     assets_path: &Path,
     modules: &HashMap<String, ModuleManifest>,
 ) {
-    if !backend_path.exists() {
-        return;
-    }
-
+    let mut commands = Vec::new();
     if let Ok(entries) = fs::read_dir(backend_path) {
         for entry in entries.flatten() {
             let path = entry.path();
             if let Some(ext) = path.extension().and_then(|e| e.to_str()) {
                 if let Some(module) = modules.get(ext) {
-                    process_single_file(&path, assets_path, module);
+                    commands.extend(process_single_file(&path, assets_path, module));
                 }
             }
         }
     }
+    commands.sort_by(|a, b| a.trigger.cmp(&b.trigger));
+
+    if let Ok(json) = serde_json::to_string_pretty(&commands) {
+        let _ = fs::write(assets_path.join("commands.json"), json);
+    }
 }
 
-// Process a single backend file with its module
+// Process a single backend file with its module and return its command entry
 fn process_single_file(
     file_path: &Path,
     assets_path: &Path,
     module: &ModuleManifest,
-) {
+) -> Option<RuntimeMeta> {
     let stem = file_path
         .file_stem()
         .unwrap_or_default()
@@ -123,7 +137,8 @@ fn process_single_file(
 
     if let Some(rule) = &module.build {
         // Agnostic extension logic:
-        // If it's a JAR engine, use .jar. Otherwise, default to original or .exe.
+        // If it's a JAR engine, use .jar. Otherwise, default to original or a native
+        // executable name (.exe on Windows, no extension on Linux).
         let out_filename = if let Some(interp) = &module.interpreter {
             if interp.contains("-jar") {
                 format!("{}.jar", stem)
@@ -131,8 +146,7 @@ fn process_single_file(
                 file_path.file_name().unwrap().to_str().unwrap().to_string()
             }
         } else {
-            let exe_ext = if cfg!(windows) { "exe" } else { "" };
-            if exe_ext.is_empty() { stem.to_string() } else { format!("{}.{}", stem, exe_ext) }
+            format!("{}{}", stem, std::env::consts::EXE_SUFFIX)
         };
 
         let out_path = assets_path.join(&out_filename);
@@ -150,15 +164,17 @@ fn process_single_file(
             .collect();
 
         if cmd_parts.is_empty() {
-            return;
+            return None;
         }
 
-        println!("   > Building {}", stem);
+        crate::report::step("backend", stem, &format!("   > Building {}", stem));
 
         // Execute command as an array of arguments. 
         // Rust handles spaces and batch files automatically on Windows.
-        let status = Command::new(&cmd_parts[0])
-            .args(&cmd_parts[1..])
+        let mut cmd = Command::new(&cmd_parts[0]);
+        cmd.args(&cmd_parts[1..]);
+        crate::report::redirect_child_output(&mut cmd);
+        let status = cmd
             .status()
             .unwrap_or_else(|_| std::process::ExitStatus::default());
 
@@ -166,31 +182,31 @@ fn process_single_file(
             panic!("Failed to build {}", stem);
         }
 
-        // Generate metadata pointing to the correct final file (e.g. .jar)
-        let meta = RuntimeMeta {
+        // Metadata pointing to the correct final file (e.g. .jar)
+        Some(RuntimeMeta {
             trigger: stem.to_string(),
             filename: out_filename,
             interpreter: module.interpreter.clone(),
             suppress_window: module.suppress_window,
-        };
-
-        if let Ok(json) = serde_json::to_string(&meta) {
-            let _ = fs::write(assets_path.join(format!("{}.meta.json", stem)), json);
-        }
+            worker: module.worker.clone(),
+            timeout_ms: module.timeout_ms,
+            args_mode: module.args_mode.clone(),
+            serial: module.serial,
+        })
     } else {
         // No build rule: just copy the file and generate metadata
         let out_filename = file_path.file_name().unwrap().to_str().unwrap();
         let _ = fs::copy(file_path, assets_path.join(out_filename));
-        
-        let meta = RuntimeMeta {
+
+        Some(RuntimeMeta {
             trigger: stem.to_string(),
             filename: out_filename.to_string(),
             interpreter: module.interpreter.clone(),
             suppress_window: module.suppress_window,
-        };
-
-        if let Ok(json) = serde_json::to_string(&meta) {
-            let _ = fs::write(assets_path.join(format!("{}.meta.json", stem)), json);
-        }
+            worker: module.worker.clone(),
+            timeout_ms: module.timeout_ms,
+            args_mode: module.args_mode.clone(),
+            serial: module.serial,
+        })
     }
 }
\ No newline at end of file
@@ -0,0 +1,706 @@
+// Copyright (c) 2026 The Frontier Framework Authors
+// SPDX-License-Identifier: Apache-2.0 WITH LLVM-exception OR MIT
+
+// Page Bridge Module
+//
+// This module builds the initialization script injected into every page before
+// its own scripts run. It creates `window.Frontier` with the built-in APIs; a
+// page that assigns `window.Frontier = { dispatch: ... }` (as in the manual) is
+// merged into the built-in object instead of replacing it.
+
+use serde_json::Value;
+use std::collections::HashMap;
+
+// Runtime data exposed to pages
+pub struct BridgeConfig<'a> {
+    pub i18n: &'a Value,
+    pub features: &'a HashMap<String, bool>,
+    pub devtools: bool,
+    pub is_dev: bool,
+    pub scheme: &'a str,
+    pub accessibility: &'a Value,           // OS settings (platform::accessibility_settings)
+    pub high_contrast: bool,                // Add the high-contrast stylesheet ([accessibility] high_contrast)
+    pub high_contrast_css: Option<&'a str>, // Stylesheet in app/frontend used instead of the built-in one
+    pub sync_title: bool,
+    pub shortcuts: &'a str,                 // physical or character ([keyboard] shortcuts)
+    pub composition_guard: bool,
+    pub launch: &'a Value,                  // { args, files } the app was started with
+}
+
+pub fn init_script(cfg: &BridgeConfig) -> String {
+    let features = serde_json::to_string(cfg.features).unwrap_or_else(|_| "{}".into());
+    // Custom protocols are served from https://<scheme>.app/ by WebView2
+    let reply_base = if cfg!(target_os = "windows") {
+        format!("https://{}.app/{}", cfg.scheme, crate::payload::REPLY_PATH)
+    } else {
+        format!("{}://app/{}", cfg.scheme, crate::payload::REPLY_PATH)
+    };
+    let appdata_base = if cfg!(target_os = "windows") {
+        format!("https://{}.app/", crate::appdata::SCHEME)
+    } else {
+        format!("{}://app/", crate::appdata::SCHEME)
+    };
+    // Root-relative, so it resolves from pages in subfolders and on the dev server
+    let contrast = match (cfg.high_contrast, cfg.high_contrast_css) {
+        (false, _) => Value::Null,
+        (true, Some(path)) => serde_json::json!({ "href": format!("/{}", path.trim_start_matches('/')) }),
+        (true, None) => serde_json::json!({ "css": HIGH_CONTRAST_CSS }),
+    };
+    format!(
+        r#"(function() {{
+    var api = {{ dispatch: function() {{}} }};
+
+    api.features = Object.freeze({features});
+
+    // How the app was started; later launches arrive as 'app:launch' events
+    var launch = {launch};
+    api.app = {{
+        args: function() {{ return launch.args.slice(); }},
+        files: function() {{ return launch.files.slice(); }}
+    }};
+
+    // Query parameters the window was opened with ('open|report.html?item=42' -> {{ item: '42' }})
+    var params = {{}};
+    new URLSearchParams(location.search).forEach(function(value, key) {{ params[key] = value; }});
+    api.params = Object.freeze(params);
+
+    var i18n = {i18n};
+    function lookup(table, key) {{
+        return key.split('.').reduce(function(node, part) {{
+            return (node && typeof node === 'object') ? node[part] : undefined;
+        }}, table);
+    }}
+    api.i18n = {{
+        locale: i18n.locale,
+        available: i18n.available,
+        t: function(key, vars) {{
+            var text = lookup(i18n.messages, key);
+            if (typeof text !== 'string') text = lookup(i18n.fallback, key);
+            if (typeof text !== 'string') return key;
+            return text.replace(/\{{(\w+)\}}/g, function(m, name) {{
+                return (vars && name in vars) ? vars[name] : m;
+            }});
+        }}
+    }};
+
+    // MessagePack replies ([backend] msgpack) are fetched as binary and decoded here
+    {msgpack}
+    api.msgpack = {{ decode: decodeMsgpack }};
+    api.__reply = function(trigger, id, callbackId) {{
+        function deliver(result) {{
+            if (callbackId === null) api.dispatch('data', Object.assign({{ trigger: trigger }}, result));
+            else api.__callback(callbackId, result);
+        }}
+        fetch({reply_base} + id)
+            .then(function(r) {{ if (!r.ok) throw new Error('Reply ' + id + ' is no longer available'); return r.arrayBuffer(); }})
+            .then(function(buf) {{ deliver({{ value: decodeMsgpack(buf) }}); }})
+            .catch(function(e) {{ deliver({{ error: String(e && e.message || e) }}); }});
+    }};
+
+    // Frontier.run('analyze', ['a b.csv', {{ sep: ';' }}]) resolves with the reply of this
+    // call only (the decoded value for MessagePack replies) and rejects when it fails, with
+    // error.code 'timeout', 'cancelled' or 'busy' when it was stopped; {{ signal }} (an AbortSignal)
+    // cancels the call, and {{ detailed: true }} resolves with {{ stdout, stderr, exitCode,
+    // durationMs }}; errors carry stderr, exitCode and durationMs either way
+    var calls = {{}}, nextCall = 1;
+    api.run = function(trigger, args, options) {{
+        var signal = options && options.signal, detailed = !!(options && options.detailed);
+        return new Promise(function(resolve, reject) {{
+            if (!window.ipc) return reject(new Error('Backends are not available in this page'));
+            if (signal && signal.aborted) {{
+                var aborted = new Error("'" + trigger + "' was cancelled");
+                aborted.code = 'cancelled';
+                return reject(aborted);
+            }}
+            var id = nextCall++;
+            calls[id] = {{ resolve: resolve, reject: reject, detailed: detailed }};
+            if (signal) signal.addEventListener('abort', function() {{
+                if (calls[id]) window.ipc.postMessage('__cancel_call|' + id);
+            }});
+            window.ipc.postMessage(JSON.stringify({{ cmd: trigger, args: args === undefined ? null : args, callbackId: id }}));
+        }});
+    }};
+    // Replies to JSON calls ({{"cmd", "args", "callbackId"}}); the ones not made by run() arrive as a 'reply' event
+    api.__callback = function(callbackId, result) {{
+        var call = calls[callbackId];
+        if (!call) return api.dispatch('reply', Object.assign({{ callbackId: callbackId }}, result));
+        delete calls[callbackId];
+        if (result.error) {{
+            var error = new Error(result.error);
+            error.reply = result.reply;
+            error.code = result.code || null;
+            error.stderr = result.stderr || '';
+            error.exitCode = result.exitCode === undefined ? null : result.exitCode;
+            error.durationMs = result.durationMs === undefined ? null : result.durationMs;
+            call.reject(error);
+        }} else if ('value' in result) {{
+            call.resolve(result.value);
+        }} else {{
+            call.resolve(call.detailed ? {{ stdout: result.reply, stderr: result.stderr, exitCode: result.exitCode, durationMs: result.durationMs }} : result.reply);
+        }}
+    }};
+
+    // URL of a file in an [appdata] folder ('images/photo.png')
+    api.appdata = {{
+        url: function(path) {{
+            return {appdata_base} + String(path).replace(/^\/+/, '').split('/').map(encodeURIComponent).join('/');
+        }}
+    }};
+
+    // Long-lived backends: send() writes a line to its stdin, its stdout lines arrive
+    // as 'session-output' {{ id, line }} and its end as 'session-end' {{ id, exit, error }}
+    var nextSession = 1, sessionPrefix = Date.now().toString(36);
+    api.session = {{
+        start: function(trigger, args) {{
+            var id = sessionPrefix + '-' + nextSession++;
+            if (window.ipc) window.ipc.postMessage('__session_start|' + id + '|' + JSON.stringify({{ cmd: trigger, args: args === undefined ? null : args }}));
+            return id;
+        }},
+        send: function(id, data) {{
+            if (window.ipc) window.ipc.postMessage('__session_send|' + id + '|' + (typeof data === 'string' ? data : JSON.stringify(data)));
+        }},
+        kill: function(id) {{
+            if (window.ipc) window.ipc.postMessage('__session_kill|' + id);
+        }}
+    }};
+
+    // Stop running backends started by this window (all of them without a trigger)
+    api.cancel = function(trigger) {{
+        if (window.ipc) window.ipc.postMessage('__cancel|' + (trigger || ''));
+    }};
+
+    // Keyboard shortcuts: "CmdOrCtrl+Shift+K" matched by key position (the same
+    // key in every layout) or by character, never while an IME is composing
+    var shortcuts = {shortcuts};
+    var mac = navigator.platform.indexOf('Mac') === 0;
+    var punctuation = {{ '/': 'Slash', '\\': 'Backslash', ',': 'Comma', '.': 'Period', ';': 'Semicolon', "'": 'Quote',
+        '[': 'BracketLeft', ']': 'BracketRight', '-': 'Minus', '=': 'Equal', '`': 'Backquote' }};
+    function composing(e) {{ return e.isComposing || e.keyCode === 229; }}
+    function parseCombo(combo) {{
+        var parts = combo.split('+'), key = parts.pop() || '+';
+        var want = {{ ctrl: false, shift: false, alt: false, meta: false }};
+        parts.forEach(function(part) {{
+            switch (part.trim().toLowerCase()) {{
+                case 'ctrl': case 'control': want.ctrl = true; break;
+                case 'shift': want.shift = true; break;
+                case 'alt': case 'option': want.alt = true; break;
+                case 'meta': case 'cmd': case 'command': case 'super': want.meta = true; break;
+                case 'cmdorctrl': case 'commandorcontrol': if (mac) want.meta = true; else want.ctrl = true; break;
+            }}
+        }});
+        if (key.length === 1 && shortcuts === 'physical') {{
+            if (/[a-z]/i.test(key)) want.code = 'Key' + key.toUpperCase();
+            else if (/[0-9]/.test(key)) want.code = 'Digit' + key;
+            else if (punctuation[key]) want.code = punctuation[key];
+        }}
+        want.key = key.toLowerCase() === 'space' ? ' ' : key.toLowerCase();
+        return want;
+    }}
+    function matches(e, combo) {{
+        if (composing(e)) return false;
+        var want = typeof combo === 'string' ? parseCombo(combo) : combo;
+        if (e.ctrlKey !== want.ctrl || e.shiftKey !== want.shift || e.altKey !== want.alt || e.metaKey !== want.meta) return false;
+        return want.code ? e.code === want.code : (e.key || '').toLowerCase() === want.key;
+    }}
+    api.keyboard = {{
+        shortcuts: shortcuts,
+        matches: matches,
+        isComposing: composing,
+        // Returns a function that removes the shortcut
+        on: function(combo, handler) {{
+            var want = parseCombo(combo);
+            function listener(e) {{ if (matches(e, want)) {{ e.preventDefault(); handler(e); }} }}
+            window.addEventListener('keydown', listener);
+            return function() {{ window.removeEventListener('keydown', listener); }};
+        }}
+    }};
+    // Key events of an IME composition (e.g. the Enter that picks a candidate) never reach the page
+    if ({composition_guard}) {{
+        ['keydown', 'keypress', 'keyup'].forEach(function(type) {{
+            window.addEventListener(type, function(e) {{ if (composing(e)) e.stopImmediatePropagation(); }}, true);
+        }});
+    }}
+
+    // Windows taskbar: Frontier.jumpList.setTasks([{{ title, open | trigger, args, description }}]), addRecent(path)
+    api.jumpList = {{
+        setTasks: function(tasks) {{ if (window.ipc) window.ipc.postMessage('__jumplist|' + JSON.stringify(tasks || [])); }},
+        addRecent: function(path) {{ if (window.ipc && path) window.ipc.postMessage('__recent|' + path); }}
+    }};
+
+    // macOS Dock: Frontier.dock.setBadge(text) ('' clears it), setMenu([{{ title, open | trigger, args }}])
+    api.dock = {{
+        setBadge: function(text) {{ if (window.ipc) window.ipc.postMessage('__dock_badge|' + (text == null ? '' : String(text))); }},
+        setMenu: function(items) {{ if (window.ipc) window.ipc.postMessage('__dock_menu|' + JSON.stringify(items || [])); }}
+    }};
+
+    // OS notifications: Frontier.notify({{ title, body, icon, tag, onClick, actions: [{{ id, title, open | trigger, args, reply }}] }})
+    // returns the tag; actions with neither open nor trigger come back as 'notification-action' events
+    var notifyNext = 1, notifyClicks = {{}};
+    api.notify = function(options) {{
+        var request = Object.assign({{ tag: 'n' + Date.now().toString(36) + '-' + notifyNext++ }}, options);
+        if (typeof request.onClick === 'function') {{
+            notifyClicks[request.tag] = request.onClick;
+            request.click = true;
+        }}
+        delete request.onClick;
+        if (window.ipc) window.ipc.postMessage('__notify|' + JSON.stringify(request));
+        return request.tag;
+    }};
+    // Clicks on a notification go to its onClick, or (after a reload, or in another
+    // window) to a 'notification-click' event
+    api.__notificationClick = function(tag) {{
+        var handler = notifyClicks[tag];
+        delete notifyClicks[tag];
+        if (handler) handler({{ tag: tag }});
+        else api.dispatch('notification-click', {{ tag: tag }});
+    }};
+
+    // Zoom and scroll: Frontier.view.setZoom(factor); pages with frontier-remember-view report
+    // their scroll position (reportScroll(x, y) when an element scrolls instead of the window)
+    // and get it back, with the zoom, when their window opens again ('view-restored' event)
+    var viewReporting = false, viewTimer = null;
+    function sendScroll(x, y) {{
+        if (window.ipc) window.ipc.postMessage('__scroll|' + Math.round(x) + '|' + Math.round(y));
+    }}
+    api.view = {{
+        zoom: 1,
+        setZoom: function(factor) {{
+            factor = Number(factor);
+            if (!(factor > 0)) return;
+            api.view.zoom = factor;
+            if (window.ipc) window.ipc.postMessage('__zoom|' + factor);
+        }},
+        reportScroll: function(x, y) {{ if (viewReporting) sendScroll(x, y); }}
+    }};
+    window.addEventListener('scroll', function() {{
+        if (!viewReporting) return;
+        clearTimeout(viewTimer);
+        viewTimer = setTimeout(function() {{ sendScroll(window.scrollX, window.scrollY); }}, 250);
+    }}, {{ passive: true }});
+    api.__view = function(view) {{
+        viewReporting = true;
+        if (view.zoom) api.view.zoom = view.zoom;
+        if (view.scroll) {{
+            window.scrollTo(view.scroll[0], view.scroll[1]);
+            api.dispatch('view-restored', {{ scrollX: view.scroll[0], scrollY: view.scroll[1], zoom: api.view.zoom }});
+        }}
+    }};
+
+    // Window layouts: Frontier.layout.apply(name), save(name) (the open windows as they are),
+    // define(name, [{{ page, monitor, x, y, width, height, maximized }}])
+    api.layout = {{
+        apply: function(name) {{ if (window.ipc && name) window.ipc.postMessage('__layout_apply|' + name); }},
+        save: function(name) {{ if (window.ipc && name) window.ipc.postMessage('__layout_save|' + name); }},
+        define: function(name, windows) {{
+            if (!window.ipc || !name) return;
+            var entries = (windows || []).map(function(w) {{
+                // Positions may be formulas; numbers are sent as text like in frontier.toml
+                var entry = Object.assign({{}}, w);
+                if (typeof entry.x === 'number') entry.x = String(entry.x);
+                if (typeof entry.y === 'number') entry.y = String(entry.y);
+                return entry;
+            }});
+            window.ipc.postMessage('__layout_define|' + name + '|' + JSON.stringify(entries));
+        }}
+    }};
+
+    // Frontier.onCloseRequested(handler) -> unregister: handlers run, one after the other, when
+    // the window is about to close (close button, Frontier.window.close()); one that returns
+    // false, or a Promise of false, keeps the window open, e.g. to save or confirm first
+    var closeHandlers = [];
+    api.onCloseRequested = function(handler) {{
+        closeHandlers.push(handler);
+        if (closeHandlers.length === 1 && window.ipc) window.ipc.postMessage('__close_guard|on');
+        return function() {{
+            var index = closeHandlers.indexOf(handler);
+            if (index < 0) return;
+            closeHandlers.splice(index, 1);
+            if (!closeHandlers.length && window.ipc) window.ipc.postMessage('__close_guard|off');
+        }};
+    }};
+    api.__closeRequested = function(ask) {{
+        window.ipc.postMessage('__close_reply|' + ask + '|handling');
+        var handlers = closeHandlers.slice();
+        function next(i) {{
+            if (i >= handlers.length) return Promise.resolve(true);
+            return Promise.resolve().then(function() {{ return handlers[i](); }})
+                .then(function(result) {{ return result === false ? false : next(i + 1); }});
+        }}
+        // A failing handler doesn't keep the window open
+        next(0).catch(function(e) {{ console.error(e); return true; }}).then(function(close) {{
+            window.ipc.postMessage('__close_reply|' + ask + '|' + (close ? 'close' : 'stay'));
+        }});
+    }};
+
+    // Frontier.ready(): the app is ready to be seen, so the [window] splash screen closes
+    api.ready = function() {{ if (window.ipc) window.ipc.postMessage('__ready'); }};
+
+    // Window controls for custom title bars: Frontier.window.minimize(), maximize(), unmaximize(),
+    // toggleMaximize(), close(), focus(), center(), setTitle(title), setSize(width, height) and
+    // startDrag() (from a mousedown); elements with data-frontier-drag-region drag the window
+    function windowCommand(action, argument) {{
+        if (window.ipc) window.ipc.postMessage('__window|' + action + (argument === undefined ? '' : '|' + argument));
+    }}
+    api.window = {{
+        minimize: function() {{ windowCommand('minimize'); }},
+        maximize: function() {{ windowCommand('maximize'); }},
+        unmaximize: function() {{ windowCommand('unmaximize'); }},
+        toggleMaximize: function() {{ windowCommand('toggleMaximize'); }},
+        close: function() {{ windowCommand('close'); }},
+        focus: function() {{ windowCommand('focus'); }},
+        center: function() {{ windowCommand('center'); }},
+        setTitle: function(title) {{ windowCommand('setTitle', String(title)); }},
+        setSize: function(width, height) {{ windowCommand('setSize', JSON.stringify([Number(width), Number(height)])); }},
+        startDrag: function() {{ windowCommand('startDrag'); }}
+    }};
+    function dragRegion(e) {{
+        return e.button === 0 && e.target.closest && e.target.closest('[data-frontier-drag-region]')
+            && !e.target.closest('button, a, input, select, textarea, [data-frontier-no-drag]');
+    }}
+    document.addEventListener('mousedown', function(e) {{
+        if (e.detail === 1 && dragRegion(e)) {{ e.preventDefault(); api.window.startDrag(); }}
+    }});
+    document.addEventListener('dblclick', function(e) {{
+        if (dragRegion(e)) api.window.toggleMaximize();
+    }});
+
+    // Dialogs: Frontier.openDialog(page, data) opens a window and resolves with what it passes
+    // to Frontier.closeWithResult(value) (null when it's closed otherwise); the dialog reads
+    // its data with Frontier.dialogData()
+    var dialogWaiting = {{}}, dialogNext = 1;
+    function dialogCall(message) {{
+        if (!window.ipc) return Promise.resolve(null);
+        return new Promise(function(resolve) {{
+            var id = dialogNext++;
+            dialogWaiting[id] = resolve;
+            window.ipc.postMessage(message(id));
+        }});
+    }}
+    api.openDialog = function(page, data) {{
+        var request = JSON.stringify({{ page: page, data: data === undefined ? null : data }});
+        return dialogCall(function(id) {{ return '__dialog_open|' + id + '|' + request; }});
+    }};
+    api.dialogData = function() {{
+        return dialogCall(function(id) {{ return '__dialog_data|' + id; }});
+    }};
+    api.closeWithResult = function(value) {{
+        if (window.ipc) window.ipc.postMessage('__dialog_close|' + JSON.stringify(value === undefined ? null : value));
+    }};
+    // File pickers: Frontier.dialog.open/save/folder(options) resolve with the chosen
+    // path (an array with multiple: true) or null when cancelled
+    function fileDialog(kind, options) {{
+        var request = JSON.stringify(Object.assign({{}}, options, {{ kind: kind }}));
+        return dialogCall(function(id) {{ return '__file_dialog|' + id + '|' + request; }});
+    }}
+    api.dialog = {{
+        open: function(options) {{ return fileDialog('open', options); }},
+        save: function(options) {{ return fileDialog('save', options); }},
+        folder: function(options) {{ return fileDialog('folder', options); }}
+    }};
+    // Frontier.monitors.list() resolves with every monitor: {{ index, name, x, y, width, height,
+    // scale, primary, current }} (logical pixels; index is the one frontier-monitor takes)
+    api.monitors = {{
+        list: function() {{
+            return dialogCall(function(id) {{ return '__monitors|' + id; }}).then(function(list) {{ return list || []; }});
+        }}
+    }};
+    // Key/value storage in the data folder, kept across restarts: Frontier.store.get(key),
+    // set(key, value) (null removes), delete(key) and keys() return Promises;
+    // Frontier.store.namespace(name) is a separate store, Frontier.store.window() this page's own
+    function storeIn(target) {{
+        function call(op, key, value) {{
+            var request = JSON.stringify(Object.assign({{ op: op, key: key, value: value === undefined ? null : value }}, target));
+            return dialogCall(function(id) {{ return '__store|' + id + '|' + request; }}).then(function(reply) {{
+                if (reply && reply.error) throw new Error(reply.error);
+                return reply ? reply.value : null;
+            }});
+        }}
+        return {{
+            get: function(key) {{ return call('get', String(key)); }},
+            set: function(key, value) {{ return call('set', String(key), value); }},
+            delete: function(key) {{ return call('delete', String(key)); }},
+            keys: function() {{ return call('keys').then(function(keys) {{ return keys || []; }}); }}
+        }};
+    }}
+    api.store = storeIn({{}});
+    api.store.namespace = function(name) {{ return storeIn({{ namespace: String(name) }}); }};
+    api.store.window = function() {{ return storeIn({{ window: true }}); }};
+    api.__dialog = function(id, value) {{
+        var resolve = dialogWaiting[id];
+        delete dialogWaiting[id];
+        if (resolve) resolve(value);
+    }};
+
+    // Shared state of all windows: Frontier.state.get(path) (a Promise), set(path, value)
+    // (null removes), subscribe(path, handler) -> unsubscribe; handlers get {{ path, value }}
+    var stateWaiting = {{}}, stateNext = 1, stateHandlers = [];
+    function stateRelated(a, b) {{
+        return !a || !b || a === b || a.indexOf(b + '.') === 0 || b.indexOf(a + '.') === 0;
+    }}
+    api.state = {{
+        get: function(path) {{
+            if (!window.ipc) return Promise.resolve(null);
+            return new Promise(function(resolve) {{
+                var id = stateNext++;
+                stateWaiting[id] = resolve;
+                window.ipc.postMessage('__state_get|' + id + '|' + (path || ''));
+            }});
+        }},
+        set: function(path, value) {{
+            if (window.ipc) window.ipc.postMessage('__state_set|' + (path || '') + '|' + JSON.stringify(value === undefined ? null : value));
+        }},
+        subscribe: function(path, handler) {{
+            var entry = {{ path: path || '', handler: handler }};
+            stateHandlers.push(entry);
+            if (stateHandlers.length === 1 && window.ipc) window.ipc.postMessage('__state_subscribe|on');
+            return function() {{
+                var i = stateHandlers.indexOf(entry);
+                if (i < 0) return;
+                stateHandlers.splice(i, 1);
+                if (!stateHandlers.length && window.ipc) window.ipc.postMessage('__state_subscribe|off');
+            }};
+        }}
+    }};
+    api.__state = function(id, value) {{
+        var resolve = stateWaiting[id];
+        delete stateWaiting[id];
+        if (resolve) resolve(value);
+    }};
+    api.__stateChanged = function(changes) {{
+        changes.forEach(function(change) {{
+            stateHandlers.slice().forEach(function(entry) {{
+                if (stateRelated(entry.path, change.path)) entry.handler(change);
+            }});
+        }});
+    }};
+
+    // Devtools: Frontier.devtools.open()/close()/toggle(), F12 or Ctrl+Shift+I
+    var devtools = {devtools};
+    function devtoolsCall(action) {{
+        if (devtools && window.ipc) window.ipc.postMessage('__devtools|' + action);
+    }}
+    api.devtools = {{
+        enabled: devtools,
+        open: function() {{ devtoolsCall('open'); }},
+        close: function() {{ devtoolsCall('close'); }},
+        toggle: function() {{ devtoolsCall('toggle'); }}
+    }};
+    if (devtools) {{
+        window.addEventListener('keydown', function(e) {{
+            if (matches(e, 'F12') || matches(e, 'Ctrl+Shift+I')) {{ e.preventDefault(); devtoolsCall('toggle'); }}
+        }}, true);
+    }}
+
+    // Dev error overlay: asset 404s, backend failures and script errors (dev only)
+    if ({is_dev}) {{
+        var box = null, list = null;
+        api.__overlay = function(source, message) {{
+            if (!document.body) {{
+                document.addEventListener('DOMContentLoaded', function() {{ api.__overlay(source, message); }});
+                return;
+            }}
+            if (!box) {{
+                box = document.createElement('div');
+                box.id = '__frontier_overlay';
+                box.style.cssText = 'position:fixed;left:12px;right:12px;bottom:12px;max-height:40%;overflow:auto;z-index:2147483647;' +
+                    'background:#1e1e1e;color:#f48771;font:12px/1.5 Consolas,monospace;border:1px solid #f48771;border-radius:6px;padding:10px 12px;box-shadow:0 4px 16px rgba(0,0,0,.4)';
+                var close = document.createElement('button');
+                close.textContent = '✕';
+                close.title = 'Dismiss';
+                close.style.cssText = 'float:right;background:none;border:0;color:#ccc;font-size:14px;cursor:pointer';
+                close.onclick = function() {{ box.remove(); box = null; }};
+                list = document.createElement('div');
+                box.appendChild(close);
+                box.appendChild(list);
+                document.body.appendChild(box);
+            }}
+            var row = document.createElement('pre');
+            row.style.cssText = 'margin:4px 0;white-space:pre-wrap';
+            row.textContent = '[' + source + '] ' + message;
+            list.appendChild(row);
+        }};
+        window.addEventListener('error', function(e) {{
+            api.__overlay('Script', e.message + (e.filename ? ' (' + e.filename + ':' + e.lineno + ')' : ''));
+        }});
+        window.addEventListener('unhandledrejection', function(e) {{
+            api.__overlay('Promise', String(e.reason && e.reason.stack || e.reason));
+        }});
+    }}
+
+    // Console forwarding: console.* output is echoed in the dev terminal (dev only)
+    if ({is_dev} && window.ipc) {{
+        function format(value) {{
+            if (typeof value === 'string') return value;
+            if (value instanceof Error) return value.stack || String(value);
+            try {{ return JSON.stringify(value); }} catch (e) {{ return String(value); }}
+        }}
+        ['log', 'info', 'warn', 'error', 'debug'].forEach(function(level) {{
+            var original = console[level];
+            console[level] = function() {{
+                try {{
+                    var text = Array.prototype.map.call(arguments, format).join(' ');
+                    window.ipc.postMessage('__console|' + level + '|' + text);
+                }} catch (e) {{}}
+                return original.apply(console, arguments);
+            }};
+        }});
+    }}
+
+    // Accessibility: OS settings (query() asks the OS again), the high-contrast
+    // stylesheet, a page language for screen readers and the window title
+    var accessibility = {accessibility}, waiting = [];
+    api.accessibility = Object.assign({{}}, accessibility, {{
+        query: function() {{
+            if (!window.ipc) return Promise.resolve(Object.assign({{}}, accessibility));
+            return new Promise(function(resolve) {{
+                waiting.push(resolve);
+                if (waiting.length === 1) window.ipc.postMessage('__accessibility');
+            }});
+        }}
+    }});
+    api.__accessibility = function(current) {{
+        accessibility = current;
+        Object.assign(api.accessibility, current);
+        waiting.splice(0).forEach(function(resolve) {{ resolve(Object.assign({{}}, current)); }});
+    }};
+    var contrast = {contrast};
+    document.addEventListener('DOMContentLoaded', function() {{
+        var root = document.documentElement;
+        if (!root.lang && i18n.locale) root.lang = i18n.locale;
+        if (contrast) {{
+            var sheet = document.createElement(contrast.href ? 'link' : 'style');
+            if (contrast.href) {{ sheet.rel = 'stylesheet'; sheet.href = contrast.href; }} else {{ sheet.textContent = contrast.css; }}
+            sheet.id = '__frontier_contrast';
+            document.head.appendChild(sheet);
+            root.classList.add('frontier-high-contrast');
+        }}
+        if ({sync_title} && window.ipc) {{
+            var title = null;
+            function syncTitle() {{
+                if (document.title && document.title !== title) {{
+                    title = document.title;
+                    window.ipc.postMessage('__title|' + title);
+                }}
+            }}
+            new MutationObserver(syncTitle).observe(document.head, {{ childList: true, subtree: true, characterData: true }});
+            syncTitle();
+        }}
+    }});
+
+    // First paint: lets the runtime time window startup (see perf.rs)
+    window.addEventListener('DOMContentLoaded', function() {{
+        requestAnimationFrame(function() {{
+            requestAnimationFrame(function() {{ if (window.ipc) window.ipc.postMessage('__paint'); }});
+        }});
+    }});
+
+    Object.defineProperty(window, 'Frontier', {{
+        configurable: true,
+        get: function() {{ return api; }},
+        set: function(value) {{ if (value) Object.assign(api, value); }}
+    }});
+}})();"#,
+        i18n = cfg.i18n,
+        features = features,
+        devtools = cfg.devtools,
+        is_dev = cfg.is_dev,
+        msgpack = MSGPACK_DECODER,
+        reply_base = serde_json::to_string(&reply_base).unwrap_or_default(),
+        appdata_base = serde_json::to_string(&appdata_base).unwrap_or_default(),
+        accessibility = cfg.accessibility,
+        contrast = contrast,
+        sync_title = cfg.sync_title,
+        shortcuts = serde_json::to_string(cfg.shortcuts).unwrap_or_default(),
+        composition_guard = cfg.composition_guard,
+        launch = cfg.launch
+    )
+}
+
+// Built-in high-contrast stylesheet: the OS system colors, solid borders and
+// visible focus rings
+const HIGH_CONTRAST_CSS: &str = "html.frontier-high-contrast *, html.frontier-high-contrast *::before, html.frontier-high-contrast *::after {
+    background-color: Canvas !important; color: CanvasText !important; border-color: CanvasText !important;
+    background-image: none !important; box-shadow: none !important; text-shadow: none !important;
+}
+html.frontier-high-contrast a, html.frontier-high-contrast a * { color: LinkText !important; }
+html.frontier-high-contrast button, html.frontier-high-contrast input, html.frontier-high-contrast select, html.frontier-high-contrast textarea {
+    background-color: ButtonFace !important; color: ButtonText !important; border: 1px solid ButtonText !important;
+}
+html.frontier-high-contrast :disabled { color: GrayText !important; border-color: GrayText !important; }
+html.frontier-high-contrast :focus-visible { outline: 3px solid Highlight !important; outline-offset: 2px !important; }
+html.frontier-high-contrast ::selection { background-color: Highlight !important; color: HighlightText !important; }";
+
+// MessagePack decoder (the whole spec: ints up to 64 bits as Number or BigInt,
+// bin as Uint8Array, timestamps as Date, other extensions as { type, data })
+const MSGPACK_DECODER: &str = r#"function decodeMsgpack(input) {
+        var bytes = input instanceof Uint8Array ? input : new Uint8Array(input);
+        var view = new DataView(bytes.buffer, bytes.byteOffset, bytes.byteLength);
+        var utf8 = new TextDecoder();
+        var pos = 0;
+        function take(n) {
+            if (pos + n > bytes.length) throw new Error('MessagePack: unexpected end of data');
+            pos += n;
+            return pos - n;
+        }
+        function str(n) { var at = take(n); return utf8.decode(bytes.subarray(at, at + n)); }
+        function bin(n) { var at = take(n); return bytes.slice(at, at + n); }
+        function arr(n) { var a = new Array(n); for (var i = 0; i < n; i++) a[i] = read(); return a; }
+        function map(n) { var m = {}; for (var i = 0; i < n; i++) { var k = read(); m[k] = read(); } return m; }
+        function safe(v) { return (v <= BigInt(Number.MAX_SAFE_INTEGER) && v >= BigInt(Number.MIN_SAFE_INTEGER)) ? Number(v) : v; }
+        function ext(n) {
+            var type = view.getInt8(take(1));
+            var data = bin(n);
+            if (type === -1) {
+                var d = new DataView(data.buffer);
+                if (n === 4) return new Date(d.getUint32(0) * 1000);
+                if (n === 8) return new Date(((d.getUint32(0) & 3) * 4294967296 + d.getUint32(4)) * 1000 + (d.getUint32(0) >>> 2) / 1e6);
+                if (n === 12) return new Date(Number(d.getBigInt64(4)) * 1000 + d.getUint32(0) / 1e6);
+            }
+            return { type: type, data: data };
+        }
+        function read() {
+            var b = bytes[take(1)];
+            if (b < 0x80) return b;
+            if (b < 0x90) return map(b & 0x0f);
+            if (b < 0xa0) return arr(b & 0x0f);
+            if (b < 0xc0) return str(b & 0x1f);
+            if (b >= 0xe0) return b - 256;
+            switch (b) {
+                case 0xc0: return null;
+                case 0xc2: return false;
+                case 0xc3: return true;
+                case 0xc4: return bin(view.getUint8(take(1)));
+                case 0xc5: return bin(view.getUint16(take(2)));
+                case 0xc6: return bin(view.getUint32(take(4)));
+                case 0xc7: return ext(view.getUint8(take(1)));
+                case 0xc8: return ext(view.getUint16(take(2)));
+                case 0xc9: return ext(view.getUint32(take(4)));
+                case 0xca: return view.getFloat32(take(4));
+                case 0xcb: return view.getFloat64(take(8));
+                case 0xcc: return view.getUint8(take(1));
+                case 0xcd: return view.getUint16(take(2));
+                case 0xce: return view.getUint32(take(4));
+                case 0xcf: return safe(view.getBigUint64(take(8)));
+                case 0xd0: return view.getInt8(take(1));
+                case 0xd1: return view.getInt16(take(2));
+                case 0xd2: return view.getInt32(take(4));
+                case 0xd3: return safe(view.getBigInt64(take(8)));
+                case 0xd4: return ext(1);
+                case 0xd5: return ext(2);
+                case 0xd6: return ext(4);
+                case 0xd7: return ext(8);
+                case 0xd8: return ext(16);
+                case 0xd9: return str(view.getUint8(take(1)));
+                case 0xda: return str(view.getUint16(take(2)));
+                case 0xdb: return str(view.getUint32(take(4)));
+                case 0xdc: return arr(view.getUint16(take(2)));
+                case 0xdd: return arr(view.getUint32(take(4)));
+                case 0xde: return map(view.getUint16(take(2)));
+                case 0xdf: return map(view.getUint32(take(4)));
+            }
+            throw new Error('MessagePack: unknown type 0x' + b.toString(16));
+        }
+        var value = read();
+        if (pos !== bytes.length) throw new Error('MessagePack: ' + (bytes.length - pos) + ' trailing bytes');
+        return value;
+    }"#;
@@ -7,7 +7,9 @@
 // It coordinates the building of the core binary.
 
 use std::process::Command;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+
+use crate::config::FileAssociation;
 
 // Cargo build configuration
 pub struct BuildConfig {
@@ -15,6 +17,26 @@ pub struct BuildConfig {
     pub version: Option<String>,
     pub description: Option<String>,
     pub copyright: Option<String>,
+    // Size options (None keeps the value from .frontier/Cargo.toml)
+    pub strip: Option<bool>,
+    pub lto: Option<bool>,
+    pub panic_abort: Option<bool>,
+    pub opt_level: Option<String>,
+    // UPX compression of the final executable
+    pub upx: bool,
+    pub upx_args: Vec<String>,
+    // Target triple passed to cargo --target (None builds for this machine)
+    pub target: Option<String>,
+    // Console-subsystem executable on Windows (build.rs sets cfg(frontier_console))
+    pub console: bool,
+}
+
+// Folder where cargo leaves the release binaries for `target`
+pub fn release_dir(target_root: &Path, target: Option<&str>) -> PathBuf {
+    match target {
+        Some(triple) => target_root.join(triple).join("release"),
+        None => target_root.join("release"),
+    }
 }
 
 // Run cargo build with the specified configuration
@@ -25,6 +47,9 @@ pub fn run_cargo_build(manifest_path: &Path, bin_name: &str, config: &BuildConfi
               "--release", 
               "--bin", 
               bin_name]);
+    if let Some(target) = &config.target {
+        cmd.args(["--target", target]);
+    }
 
     // Pass metadata as environment variables
     if let Some(name) = &config.app_name {
@@ -39,7 +64,23 @@ pub fn run_cargo_build(manifest_path: &Path, bin_name: &str, config: &BuildConfi
     if let Some(copyright) = &config.copyright {
         cmd.env("FRONTIER_APP_COPYRIGHT", copyright);
     }
+    cmd.env("FRONTIER_CONSOLE", if config.console { "1" } else { "0" });
 
+    // Override the release profile through Cargo's environment configuration
+    if let Some(strip) = config.strip {
+        cmd.env("CARGO_PROFILE_RELEASE_STRIP", if strip { "symbols" } else { "none" });
+    }
+    if let Some(lto) = config.lto {
+        cmd.env("CARGO_PROFILE_RELEASE_LTO", if lto { "true" } else { "false" });
+    }
+    if let Some(panic_abort) = config.panic_abort {
+        cmd.env("CARGO_PROFILE_RELEASE_PANIC", if panic_abort { "abort" } else { "unwind" });
+    }
+    if let Some(level) = &config.opt_level {
+        cmd.env("CARGO_PROFILE_RELEASE_OPT_LEVEL", level);
+    }
+
+    crate::report::redirect_child_output(&mut cmd);
     let status = cmd.status()
         .map_err(|e| format!("Failed to run cargo: {}", e))?;
 
@@ -54,6 +95,7 @@ pub fn run_cargo_build(manifest_path: &Path, bin_name: &str, config: &BuildConfi
 pub fn finalize_executable(
     source_exe: &Path,
     dest_exe: &Path,
+    config: &BuildConfig,
 ) -> Result<(), String> {
     if !source_exe.exists() {
         return Err("CRITICAL ERROR: Executable not generated.".to_string());
@@ -62,5 +104,222 @@ pub fn finalize_executable(
     std::fs::copy(source_exe, dest_exe)
         .map_err(|e| format!("Failed to copy executable: {}", e))?;
 
+    // UPX can't pack ARM64 Windows executables (it would fail on every build)
+    let arm64_windows = config.target.as_deref().is_some_and(|t| t.starts_with("aarch64") && t.contains("windows"));
+    if config.upx && arm64_windows {
+        crate::report::warn("UPX doesn't support ARM64 Windows executables, keeping the uncompressed executable");
+    } else if config.upx {
+        compress_with_upx(dest_exe, &config.upx_args);
+    }
+
     Ok(())
 }
+
+// Compress the executable in place with UPX (a missing or failing UPX only warns)
+fn compress_with_upx(exe: &Path, extra_args: &[String]) {
+    let mut cmd = Command::new("upx");
+    if extra_args.is_empty() {
+        cmd.arg("--best");
+    } else {
+        cmd.args(extra_args);
+    }
+    cmd.arg(exe);
+    crate::report::redirect_child_output(&mut cmd);
+
+    match cmd.status() {
+        Ok(s) if s.success() => crate::report::step("bundle", "upx", "🗜️  [UPX] Executable compressed"),
+        Ok(_) => crate::report::warn("UPX compression failed, keeping the uncompressed executable"),
+        Err(_) => crate::report::warn("UPX not found in PATH, keeping the uncompressed executable"),
+    }
+}
+
+// Linux launcher: <app_id>.desktop and a <app_id>.png icon next to the executable
+//
+// The file name and StartupWMClass match the window's app id ([linux] app_id,
+// or the executable name), which is how desktops pair windows with launchers
+// and, on Wayland, where the window icon comes from. Install with the binary in ~/.local/bin (or any PATH folder), the icon in
+// ~/.local/share/icons/hicolor/256x256/apps and the .desktop file in
+// ~/.local/share/applications. With [[file_associations]], the entry lists
+// their MIME types and <app_id>.xml defines them (for ~/.local/share/mime/packages).
+pub fn write_desktop_entry(dist_dir: &Path, app_name: &str, app_id: &str, config: &BuildConfig, icon: Option<&Path>, associations: &[FileAssociation]) -> Result<(), String> {
+    let exec = if associations.is_empty() { app_name.to_string() } else { format!("{} %F", app_name) };
+    let mut entry = format!(
+        "[Desktop Entry]\nType=Application\nName={}\nExec={}\nTerminal=false\nCategories=Utility;\nStartupWMClass={}\n",
+        app_name, exec, app_id
+    );
+    if let Some(desc) = &config.description {
+        entry.push_str(&format!("Comment={}\n", desc.replace('\n', " ")));
+    }
+    if let Some(icon) = icon {
+        // Desktop environments want PNG (or SVG); .ico files are converted
+        let img = image::open(icon).map_err(|e| format!("Cannot read icon '{}': {}", icon.display(), e))?;
+        img.resize(256, 256, image::imageops::FilterType::Lanczos3)
+            .save(dist_dir.join(format!("{}.png", app_id)))
+            .map_err(|e| format!("Cannot write icon: {}", e))?;
+        entry.push_str(&format!("Icon={}\n", app_id));
+    }
+    if !associations.is_empty() {
+        let types: Vec<String> = associations.iter().map(|a| mime_type(app_id, a)).collect();
+        entry.push_str(&format!("MimeType={};\n", types.join(";")));
+        write_mime_package(&dist_dir.join(format!("{}.xml", app_id)), app_id, associations)?;
+    }
+
+    std::fs::write(dist_dir.join(format!("{}.desktop", app_id)), entry)
+        .map_err(|e| format!("Failed to write desktop entry: {}", e))
+}
+
+
+// macOS app bundle: moves the executable into <app>.app/Contents/MacOS and
+// writes Info.plist and the .icns icon
+//
+// <app>.app/Contents/Info.plist
+// <app>.app/Contents/MacOS/<app>
+// <app>.app/Contents/Resources/<app>.icns
+pub fn write_app_bundle(dist_dir: &Path, app_name: &str, exe: &Path, config: &BuildConfig, bundle: &MacBundle, icon: Option<&Path>) -> Result<(), String> {
+    let app_dir = dist_dir.join(format!("{}.app", app_name));
+    let contents = app_dir.join("Contents");
+    let macos_dir = contents.join("MacOS");
+    let resources = contents.join("Resources");
+    std::fs::create_dir_all(&macos_dir).map_err(|e| format!("Failed to create app bundle: {}", e))?;
+    std::fs::create_dir_all(&resources).map_err(|e| format!("Failed to create app bundle: {}", e))?;
+    std::fs::rename(exe, macos_dir.join(app_name)).map_err(|e| format!("Failed to move executable into the bundle: {}", e))?;
+
+    let mut keys = vec![
+        ("CFBundleName", app_name.to_string()),
+        ("CFBundleDisplayName", app_name.to_string()),
+        ("CFBundleExecutable", app_name.to_string()),
+        ("CFBundleIdentifier", bundle.identifier.clone()),
+        ("CFBundlePackageType", "APPL".to_string()),
+        ("CFBundleInfoDictionaryVersion", "6.0".to_string()),
+        ("CFBundleVersion", config.version.clone().unwrap_or_else(|| "0.1.0".into())),
+        ("CFBundleShortVersionString", config.version.clone().unwrap_or_else(|| "0.1.0".into())),
+        ("LSMinimumSystemVersion", bundle.minimum_system_version.clone()),
+    ];
+    if let Some(copyright) = &config.copyright {
+        keys.push(("NSHumanReadableCopyright", copyright.clone()));
+    }
+    if let Some(icon) = icon {
+        write_icns(icon, &resources.join(format!("{}.icns", app_name)))?;
+        keys.push(("CFBundleIconFile", app_name.to_string()));
+    }
+
+    let mut plist = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<!DOCTYPE plist PUBLIC \"-//Apple//DTD PLIST 1.0//EN\" \"http://www.apple.com/DTDs/PropertyList-1.0.dtd\">\n<plist version=\"1.0\">\n<dict>\n");
+    for (key, value) in keys {
+        plist.push_str(&format!("    <key>{}</key>\n    <string>{}</string>\n", key, xml_escape(&value)));
+    }
+    // Documents Finder may open with the app (delivered through application:openURLs:)
+    if !bundle.file_associations.is_empty() {
+        plist.push_str("    <key>CFBundleDocumentTypes</key>\n    <array>\n");
+        for association in &bundle.file_associations {
+            let ext = association.extensions.first().map(|e| e.trim_start_matches('.').to_uppercase()).unwrap_or_default();
+            let name = association.name.clone().unwrap_or_else(|| format!("{} File", ext));
+            let role = association.role.clone().unwrap_or_else(|| "Editor".into());
+            plist.push_str(&format!(
+                "        <dict>\n            <key>CFBundleTypeName</key>\n            <string>{}</string>\n            <key>CFBundleTypeRole</key>\n            <string>{}</string>\n            <key>LSHandlerRank</key>\n            <string>Default</string>\n            <key>CFBundleTypeExtensions</key>\n            <array>\n",
+                xml_escape(&name), xml_escape(&role)
+            ));
+            for ext in &association.extensions {
+                plist.push_str(&format!("                <string>{}</string>\n", xml_escape(ext.trim_start_matches('.'))));
+            }
+            plist.push_str("            </array>\n        </dict>\n");
+        }
+        plist.push_str("    </array>\n");
+    }
+    // Retina rendering
+    plist.push_str("    <key>NSHighResolutionCapable</key>\n    <true/>\n</dict>\n</plist>\n");
+    std::fs::write(contents.join("Info.plist"), plist).map_err(|e| format!("Failed to write Info.plist: {}", e))
+}
+
+// MIME type of a file association (made up from the app id when not set)
+fn mime_type(app_id: &str, association: &FileAssociation) -> String {
+    association.mime_type.clone().unwrap_or_else(|| {
+        let ext = association.extensions.first().map(|e| e.trim_start_matches('.')).unwrap_or("file");
+        let id: String = app_id.to_lowercase().chars().map(|c| if c.is_ascii_alphanumeric() { c } else { '-' }).collect();
+        format!("application/x-{}-{}", id, ext.to_lowercase())
+    })
+}
+
+// shared-mime-info package mapping each association's extensions to its type
+fn write_mime_package(dest: &Path, app_id: &str, associations: &[FileAssociation]) -> Result<(), String> {
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<mime-info xmlns=\"http://www.freedesktop.org/standards/shared-mime-info\">\n");
+    for association in associations {
+        xml.push_str(&format!("  <mime-type type=\"{}\">\n", xml_escape(&mime_type(app_id, association))));
+        if let Some(name) = &association.name {
+            xml.push_str(&format!("    <comment>{}</comment>\n", xml_escape(name)));
+        }
+        for ext in &association.extensions {
+            xml.push_str(&format!("    <glob pattern=\"*.{}\"/>\n", xml_escape(ext.trim_start_matches('.'))));
+        }
+        xml.push_str("  </mime-type>\n");
+    }
+    xml.push_str("</mime-info>\n");
+    std::fs::write(dest, xml).map_err(|e| format!("Failed to write MIME types: {}", e))
+}
+
+// Info.plist values from [macos] and [[file_associations]]
+pub struct MacBundle {
+    pub identifier: String,
+    pub minimum_system_version: String,
+    pub file_associations: Vec<FileAssociation>,
+}
+
+// .icns with PNG entries (128 to 1024 px, never upscaled past the source
+// except for the smallest one)
+fn write_icns(icon: &Path, dest: &Path) -> Result<(), String> {
+    let img = image::open(icon).map_err(|e| format!("Cannot read icon '{}': {}", icon.display(), e))?;
+    let largest = img.width().max(img.height());
+
+    let mut entries = Vec::new();
+    for (kind, size) in [(b"ic07", 128u32), (b"ic08", 256), (b"ic09", 512), (b"ic10", 1024)] {
+        if size > largest && size != 128 { continue; }
+        let mut png = std::io::Cursor::new(Vec::new());
+        img.resize_exact(size, size, image::imageops::FilterType::Lanczos3)
+            .write_to(&mut png, image::ImageOutputFormat::Png)
+            .map_err(|e| format!("Cannot encode icon: {}", e))?;
+        let png = png.into_inner();
+        entries.extend_from_slice(kind);
+        entries.extend_from_slice(&(png.len() as u32 + 8).to_be_bytes());
+        entries.extend_from_slice(&png);
+    }
+
+    let mut icns = Vec::with_capacity(entries.len() + 8);
+    icns.extend_from_slice(b"icns");
+    icns.extend_from_slice(&(entries.len() as u32 + 8).to_be_bytes());
+    icns.extend_from_slice(&entries);
+    std::fs::write(dest, icns).map_err(|e| format!("Cannot write icon: {}", e))
+}
+
+fn xml_escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;")
+}
+
+// Run a user-defined build hook through the system shell
+//
+// The hook receives the app metadata and build paths as environment variables
+// (FRONTIER_HOOK, FRONTIER_APP_NAME, FRONTIER_APP_VERSION, FRONTIER_DIST_DIR, ...).
+pub fn run_hook(hook_name: &str, command: &str, env: &[(&str, String)]) -> Result<(), String> {
+    let mut cmd = if cfg!(windows) {
+        let mut c = Command::new("cmd");
+        c.args(["/C", command]);
+        c
+    } else {
+        let mut c = Command::new("sh");
+        c.args(["-c", command]);
+        c
+    };
+
+    cmd.env("FRONTIER_HOOK", hook_name);
+    crate::report::redirect_child_output(&mut cmd);
+    for (key, value) in env {
+        cmd.env(key, value);
+    }
+
+    let status = cmd.status()
+        .map_err(|e| format!("Failed to run hook '{}': {}", hook_name, e))?;
+
+    if !status.success() {
+        return Err(format!("Hook '{}' failed: {}", hook_name, command));
+    }
+
+    Ok(())
+}
\ No newline at end of file
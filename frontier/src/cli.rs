@@ -0,0 +1,55 @@
+// Copyright (c) 2026 The Frontier Framework Authors
+// SPDX-License-Identifier: Apache-2.0 WITH LLVM-exception OR MIT
+
+// Command Line Module
+//
+// The manager's commands and options, described once with clap. The same
+// description parses the arguments, prints `--help`, and generates the shell
+// completions and the man page, so they never drift from what is accepted.
+
+use std::io;
+
+use clap::{value_parser, Arg, ArgAction, Command};
+use clap_complete::Shell;
+
+// Name users type (the ./frontier and .\frontier scripts)
+pub const BIN_NAME: &str = "frontier";
+
+pub fn command() -> Command {
+    Command::new(BIN_NAME)
+        .about("Build, run and diagnose Frontier apps")
+        .version(env!("CARGO_PKG_VERSION"))
+        .after_help("Without a command, `build` runs with its defaults. `./back` and `./front` run commands in app/backend and app/frontend.")
+        .subcommand(
+            Command::new("build")
+                .about("Make the final executable in dist/")
+                .arg(Arg::new("profile").long("profile").value_name("NAME")
+                    .help("Build profile from [build.profiles.<NAME>] (default: [build] profile, or release)"))
+                .arg(Arg::new("target").long("target").value_name("TRIPLE")
+                    .help("Rust target to build for, e.g. aarch64-pc-windows-msvc (default: [build] target, or this machine)"))
+                .arg(Arg::new("console").long("console").action(ArgAction::SetTrue)
+                    .help("Windows: build a console variant that shows its log, for troubleshooting (also [build.profiles.<NAME>] console)"))
+                .arg(Arg::new("format").long("format").value_name("FORMAT").value_parser(["text", "json"]).default_value("text")
+                    .help("Progress output; json prints one record per line for CI systems and GUIs")),
+        )
+        .subcommand(Command::new("dev").about("Run the app from app/ with hot reload and the dev console"))
+        .subcommand(Command::new("doctor").about("Diagnose the toolchain, webview runtime, modules and frontier.toml"))
+        .subcommand(Command::new("check").about("Validate frontier.toml and its overlays strictly"))
+        .subcommand(
+            Command::new("completions")
+                .about("Print shell completions (e.g. `./frontier completions bash > ~/.local/share/bash-completion/completions/frontier`)")
+                .arg(Arg::new("shell").required(true).value_name("SHELL").value_parser(value_parser!(Shell))
+                    .help("bash, zsh, fish, powershell or elvish")),
+        )
+        .subcommand(Command::new("man").about("Print the man page (e.g. `./frontier man > frontier.1`)"))
+        // Called by the installer scripts; nothing to do here
+        .subcommand(Command::new("install").hide(true))
+}
+
+pub fn print_completions(shell: Shell) {
+    clap_complete::generate(shell, &mut command(), BIN_NAME, &mut io::stdout());
+}
+
+pub fn print_man_page() -> io::Result<()> {
+    clap_mangen::Man::new(command()).render(&mut io::stdout())
+}
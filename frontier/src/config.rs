@@ -1,67 +1,1019 @@
 // Copyright (c) 2026 The Frontier Framework Authors
 // SPDX-License-Identifier: Apache-2.0 WITH LLVM-exception OR MIT
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use tracing::{error, warn};
 
-#[derive(Deserialize, Clone)]
+#[derive(Deserialize, Clone, Default)]
+#[serde(deny_unknown_fields)]
 #[allow(dead_code)]
 pub struct AppConfig {
     pub name: Option<String>,
     pub version: Option<String>,
     pub description: Option<String>,
     pub copyright: Option<String>,
+    pub author: Option<String>,
+    pub icon: Option<String>,
+    pub scheme: Option<String>,
+    pub qualifier: Option<String>,    // Reverse-domain prefix of the app's folders and bundle id (e.g. "com")
+    pub organization: Option<String>, // Company or author folder the app's data lives under
 }
 
-#[derive(Deserialize, Clone)]
+#[derive(Deserialize, Clone, Default)]
+#[serde(deny_unknown_fields)]
 #[allow(dead_code)]
 pub struct WindowConfig {
     pub icon: Option<String>,
+    // Defaults for every page (meta tags in the HTML still take precedence)
+    pub title: Option<String>,
+    pub width: Option<f64>,
+    pub height: Option<f64>,
+    pub min_width: Option<f64>,
+    pub min_height: Option<f64>,
+    pub max_width: Option<f64>,
+    pub max_height: Option<f64>,
+    pub resizable: Option<bool>,
+    pub maximized: Option<bool>,
+    pub minimizable: Option<bool>,
+    pub maximizable: Option<bool>,
+    pub prewarm: Option<bool>, // Keep a hidden webview ready so new windows open instantly
+    pub startup: Option<Vec<String>>, // Pages opened at launch (default: index.html)
+    pub restore_session: Option<bool>, // Reopen the windows that were open when the app closed, where they were
+    // Guards against pages opening windows in a loop (open, spawn, openDialog, target="_blank")
+    pub max_windows: Option<usize>,          // Open windows at a time
+    pub max_opens_per_minute: Option<usize>, // Windows each window may open per minute
+    // Splash screen shown while the startup windows load
+    pub splash: Option<String>,         // Page or image in app/frontend, e.g. "splash.html"
+    pub splash_width: Option<f64>,      // Size of a splash page (default: 480x320; images keep theirs)
+    pub splash_height: Option<f64>,
+    pub splash_timeout: Option<u64>,    // Seconds it waits for Frontier.ready() (default: 10)
+    pub close_timeout: Option<u64>,     // Seconds a page with onCloseRequested handlers gets to respond (default: 3)
+}
+
+// Per-page section: [windows."settings.html"] (same keys as the frontier-* meta tags)
+#[derive(Deserialize, Clone, Default)]
+#[serde(deny_unknown_fields)]
+#[allow(dead_code)]
+pub struct WindowOverride {
+    pub title: Option<String>,
+    pub id: Option<String>,
+    pub icon: Option<String>, // Relative to app/frontend, like frontier-icon
+    pub width: Option<f64>,
+    pub height: Option<f64>,
+    pub min_width: Option<f64>,
+    pub min_height: Option<f64>,
+    pub max_width: Option<f64>,
+    pub max_height: Option<f64>,
+    pub x: Option<String>,
+    pub y: Option<String>,
+    pub resizable: Option<bool>,
+    pub maximized: Option<bool>,
+    pub minimizable: Option<bool>,
+    pub maximizable: Option<bool>,
+    pub persistent: Option<bool>,
+    pub remember_view: Option<bool>, // Like frontier-remember-view
+    pub allowed_internal: Option<Vec<String>>,
+    pub allowed_browser: Option<Vec<String>>,
+    pub ignore_global_security: Option<bool>,
+    pub partition: Option<String>, // Separate cookies/storage, like frontier-partition
+    pub menu: Option<bool>,        // false hides the [[menu]] menus, like frontier-menu="none"
+    pub decorations: Option<bool>, // Like frontier-decorations
+    pub transparent: Option<bool>,
+    pub always_on_top: Option<bool>,
+    pub skip_taskbar: Option<bool>,
+    pub monitor: Option<usize>,    // Like frontier-monitor
 }
 
 #[derive(Deserialize, Clone)]
+#[serde(deny_unknown_fields)]
 #[allow(dead_code)]
 pub struct SecurityConfig {
     #[serde(default)]
     pub allowed_internal: Vec<String>, // Open inside the App
     #[serde(default)]
     pub allowed_browser: Vec<String>,  // Open in system browser (Chrome/Edge)
+    #[serde(default)]
+    pub windows: HashMap<String, WindowSecurity>, // [security.windows.<page id>]
+    #[serde(default)]
+    pub devtools: bool,                // Allow devtools in release builds (always on in dev)
+    #[serde(default)]
+    pub automation: bool,              // Accept --automation in release builds (always on in dev)
+}
+
+// Per-window security, keyed by the page id (frontier-id or file name with '.' -> '_')
+#[derive(Deserialize, Clone, Default)]
+#[serde(deny_unknown_fields)]
+#[allow(dead_code)]
+pub struct WindowSecurity {
+    #[serde(default)]
+    pub allowed_internal: Vec<String>, // Added to the global/page whitelist
+    #[serde(default)]
+    pub allowed_browser: Vec<String>,
+    pub csp: Option<String>,           // Content-Security-Policy for frontier:// responses
+    pub commands: Option<Vec<String>>, // Backend triggers this window may run (None = all)
+}
+
+#[derive(Deserialize, Clone, Default)]
+#[serde(deny_unknown_fields)]
+#[allow(dead_code)]
+pub struct FrontendConfig {
+    #[serde(default)]
+    pub exclude: Vec<String>, // Glob patterns skipped when bundling (e.g. "node_modules/**")
+    #[serde(default)]
+    pub spa_fallback: bool, // Unknown paths under <scheme>://app/ get index.html (client-side routers)
+    #[serde(default)]
+    pub archive: bool, // Bundle the frontend as one compressed zip, read in place by the runtime
+}
+
+#[derive(Deserialize, Clone, Default)]
+#[serde(deny_unknown_fields)]
+#[allow(dead_code)]
+pub struct AppDataConfig {
+    #[serde(default)]
+    pub folders: Vec<String>, // Folders of the data folder served over appdata:// (none: off)
+    #[serde(default)]
+    pub writable: bool, // Pages may also PUT and DELETE files in them
+}
+
+#[derive(Deserialize, Clone, Default)]
+#[serde(deny_unknown_fields)]
+#[allow(dead_code)]
+pub struct HooksConfig {
+    pub pre_build: Option<String>,    // Before backend processing
+    pub post_backend: Option<String>, // After backend files and assets are prepared
+    pub post_bundle: Option<String>,  // After the final executable is in dist/
+}
+
+#[derive(Deserialize, Clone, Default)]
+#[serde(deny_unknown_fields)]
+#[allow(dead_code)]
+pub struct BuildProfile {
+    pub strip: Option<bool>,       // Strip symbols (CARGO_PROFILE_RELEASE_STRIP)
+    pub lto: Option<bool>,         // Link-time optimization
+    pub panic_abort: Option<bool>, // panic = "abort" instead of unwinding
+    pub opt_level: Option<String>, // "z", "s", "3", ...
+    #[serde(default)]
+    pub console: bool,             // Windows: console-subsystem executable that always shows its log (for troubleshooting)
+    #[serde(default)]
+    pub upx: bool,                 // Compress the final executable with UPX
+    #[serde(default)]
+    pub upx_args: Vec<String>,     // Extra UPX arguments (default: --best)
+    #[serde(default)]
+    pub features: HashMap<String, bool>, // Overrides [features] in builds made with this profile
+}
+
+#[derive(Deserialize, Clone, Default)]
+#[serde(deny_unknown_fields)]
+#[allow(dead_code)]
+pub struct BuildSettings {
+    pub profile: Option<String>, // Default profile for `frontier build`
+    pub target: Option<String>,  // Rust target triple to build for (e.g. "aarch64-pc-windows-msvc"; default: this machine)
+    #[serde(default)]
+    pub profiles: HashMap<String, BuildProfile>,
+}
+
+#[derive(Deserialize, Clone, Default)]
+#[serde(deny_unknown_fields)]
+#[allow(dead_code)]
+pub struct MacosConfig {
+    pub activation_policy: Option<String>, // regular (default), accessory (no Dock icon or menu bar) or prohibited
+    pub quit_on_last_window: Option<bool>, // Quit when the last window closes (default: false, clicking the Dock icon reopens)
+    pub bundle_id: Option<String>,         // CFBundleIdentifier of the .app (default: <qualifier>.<organization>.<name> from [app])
+    pub minimum_system_version: Option<String>, // LSMinimumSystemVersion (default: 10.13)
+    #[serde(default)]
+    pub dock_menu: Vec<JumpTask>, // [[macos.dock_menu]]: title and open or trigger/args, like jump list tasks
+}
+
+// [[file_associations]]
+#[derive(Deserialize, Clone, Default)]
+#[serde(deny_unknown_fields)]
+#[allow(dead_code)]
+pub struct FileAssociation {
+    pub extensions: Vec<String>,   // Without the dot, e.g. ["md", "markdown"]
+    pub name: Option<String>,      // Document type shown by the OS (default: "<EXT> File")
+    pub mime_type: Option<String>, // Linux MIME type (default: application/x-<app id>-<first extension>)
+    pub role: Option<String>,      // macOS CFBundleTypeRole: Editor (default) or Viewer
+}
+
+// [jump_list] (Windows taskbar)
+#[derive(Deserialize, Clone, Default)]
+#[serde(deny_unknown_fields)]
+#[allow(dead_code)]
+pub struct JumpListConfig {
+    pub recent: Option<bool>, // Show the Recent category (default: true)
+    #[serde(default)]
+    pub tasks: Vec<JumpTask>,
+}
+
+// [lifecycle]: backend triggers the runtime runs at points of the app's life
+#[derive(Deserialize, Clone, Default)]
+#[serde(deny_unknown_fields)]
+#[allow(dead_code)]
+pub struct LifecycleConfig {
+    pub on_start: Option<String>,  // Before the first window opens
+    pub on_exit: Option<String>,   // After the last window closed, before the app quits
+    pub on_update: Option<String>, // First start after [app] version changed (args: the previous version)
+}
+
+// [[service]]: a backend started at launch (after [lifecycle] on_start) and
+// stopped when the app quits, e.g. a local server or a folder watcher
+#[derive(Deserialize, Clone, Default)]
+#[serde(deny_unknown_fields)]
+#[allow(dead_code)]
+pub struct ServiceConfig {
+    pub trigger: String,            // Backend to run
+    pub args: Option<String>,       // Arguments for the trigger
+    pub restart: Option<String>,    // When it stops: "on-failure" (default), "always" or "never"
+    pub max_restarts: Option<u32>,  // Restarts in a row before it's given up on (default: 5)
+    pub restart_delay: Option<u64>, // Seconds before a restart (default: 1)
+}
+
+// [tray]: an icon in the system tray (notification area / menu bar extras)
+#[derive(Deserialize, Clone, Default)]
+#[serde(deny_unknown_fields)]
+#[allow(dead_code)]
+pub struct TrayConfig {
+    pub icon: Option<String>,         // Icon file (default: the app icon, [window] icon)
+    pub tooltip: Option<String>,      // Default: the app name
+    pub start_hidden: Option<bool>,   // Open no window at launch; the tray opens them (default: false)
+    pub keep_running: Option<bool>,   // Stay in the tray when the last window closes (default: true)
+    pub close_to_tray: Option<bool>,  // Closing a window hides it, page and all, until the tray shows it (default: false)
+    pub hotkey: Option<String>,       // Global shortcut that shows the app, e.g. "CmdOrCtrl+Shift+Space"
+    pub show_title: Option<String>,   // Label of a first menu item that shows the app (default: none)
+    pub quit_title: Option<String>,   // Label of the Quit item (default: "Quit")
+    #[serde(default)]
+    pub menu: Vec<JumpTask>,          // [[tray.menu]]: title and open or trigger/args, like jump list tasks
+}
+
+// [[menu]]: a menu of the window menu bar
+#[derive(Deserialize, Clone, Default)]
+#[serde(deny_unknown_fields)]
+#[allow(dead_code)]
+pub struct MenuConfig {
+    pub title: String,
+    #[serde(default)]
+    pub items: Vec<MenuItemConfig>, // [[menu.items]]
+}
+
+// [[menu.items]]: a native item, or a titled item that is sent to the page
+// (id) and/or runs like a jump list task (open or trigger/args)
+#[derive(Deserialize, Clone, Default)]
+#[serde(deny_unknown_fields)]
+#[allow(dead_code)]
+pub struct MenuItemConfig {
+    pub title: Option<String>,
+    pub id: Option<String>,          // Sent to the page as Frontier.dispatch('menu', id)
+    pub accelerator: Option<String>, // e.g. "CmdOrCtrl+S"
+    pub open: Option<String>,        // Page to open, e.g. "settings.html"
+    pub trigger: Option<String>,     // Backend trigger run as if the window called it
+    pub args: Option<String>,        // Arguments for the trigger
+    pub native: Option<String>,      // separator, undo, redo, cut, copy, paste, select_all, minimize, zoom, fullscreen, close or quit
+}
+
+// [[jump_list.tasks]], also what Frontier.jumpList.setTasks() takes
+#[derive(Deserialize, Clone, Default)]
+#[serde(deny_unknown_fields)]
+#[allow(dead_code)]
+pub struct JumpTask {
+    pub title: String,
+    pub description: Option<String>, // Tooltip
+    pub open: Option<String>,        // Page to open, e.g. "editor.html"
+    pub trigger: Option<String>,     // Backend trigger run as if the app's window called it
+    pub args: Option<String>,        // Arguments for the trigger
+}
+
+// [[layouts.<name>]]: one window of a named layout (Frontier.layout.apply);
+// layouts saved at runtime are kept in the same shape
+#[derive(Deserialize, Serialize, Clone, Default)]
+#[serde(deny_unknown_fields)]
+#[allow(dead_code)]
+pub struct LayoutWindow {
+    pub page: String,           // Page (or spawn:// request) to open, or to move when it's open
+    pub monitor: Option<usize>, // Position in the OS's monitor list (default: the primary monitor)
+    pub x: Option<String>,      // Numbers or formulas, like frontier-x/y, relative to the monitor
+    pub y: Option<String>,
+    pub width: Option<f64>,
+    pub height: Option<f64>,
+    pub maximized: Option<bool>,
+}
+
+#[derive(Deserialize, Clone, Default)]
+#[serde(deny_unknown_fields)]
+#[allow(dead_code)]
+pub struct WebView2Config {
+    pub mode: Option<String>,         // evergreen (default: offer the download), bootstrapper or fixed
+    pub min_version: Option<String>,  // Oldest runtime accepted, e.g. "110.0.1587.40" (older counts as missing)
+    pub bootstrapper: Option<String>, // MicrosoftEdgeWebview2Setup.exe bundled for mode = "bootstrapper"
+    pub runtime_dir: Option<String>,  // Extracted Fixed Version runtime for mode = "fixed" (copied to dist/WebView2)
+}
+
+#[derive(Deserialize, Clone, Default)]
+#[serde(deny_unknown_fields)]
+#[allow(dead_code)]
+pub struct AccessibilityConfig {
+    pub high_contrast: Option<String>,     // auto (default: follow the OS setting), always or off
+    pub high_contrast_css: Option<String>, // Stylesheet in app/frontend added in high contrast (default: a built-in one)
+    pub min_font_scale: Option<f64>,       // Smallest page zoom, e.g. 1.25 (default: 1.0)
+    pub follow_text_scale: Option<bool>,   // Zoom pages by the OS text size setting (default: false)
+    pub sync_title: Option<bool>,          // Window title (the name screen readers announce) follows document.title (default: false)
+}
+
+#[derive(Deserialize, Clone, Default)]
+#[serde(deny_unknown_fields)]
+#[allow(dead_code)]
+pub struct KeyboardConfig {
+    pub shortcuts: Option<String>,       // physical (default: by key position, the same in every layout) or character (by the typed character)
+    pub composition_guard: Option<bool>, // Hide key events that belong to an IME composition from page handlers (default: false)
+    pub im_module: Option<String>,       // Linux input method (GTK_IM_MODULE), e.g. ibus, fcitx or wayland; a value set by the user wins
+}
+
+#[derive(Deserialize, Clone, Default)]
+#[serde(deny_unknown_fields)]
+#[allow(dead_code)]
+pub struct LinuxConfig {
+    pub backend: Option<String>,     // auto (default), x11 or wayland; GDK_BACKEND set by the user wins
+    pub decorations: Option<String>, // auto (default), client (GTK draws the title bar) or server (the window manager does)
+    pub app_id: Option<String>,      // Wayland app id / X11 WM_CLASS, matched against the .desktop file (default: executable name)
+}
+
+#[derive(Deserialize, Clone, Default)]
+#[serde(deny_unknown_fields)]
+#[allow(dead_code)]
+pub struct StorageConfig {
+    // Release data directory; supports %APPDATA%, %LOCALAPPDATA%, %HOME%, %APPNAME%, %EXEDIR%
+    // and any other %ENV_VAR%. Relative paths are resolved against the executable's folder.
+    pub data_dir: Option<String>,
+}
+
+#[derive(Deserialize, Clone, Default)]
+#[serde(deny_unknown_fields)]
+#[allow(dead_code)]
+pub struct DevConfig {
+    pub frontend_url: Option<String>, // External dev server (Vite/webpack) loaded by `frontier dev`
+}
+
+#[derive(Deserialize, Clone, Default)]
+#[serde(deny_unknown_fields)]
+#[allow(dead_code)]
+pub struct NetworkConfig {
+    pub user_agent: Option<String>,   // Replaces the webview's user-agent string
+    #[serde(default)]
+    pub headers: HashMap<String, String>, // Sent with the initial request of every window
+}
+
+#[derive(Deserialize, Clone, Default)]
+#[serde(deny_unknown_fields)]
+#[allow(dead_code)]
+pub struct I18nConfig {
+    pub dir: Option<String>,          // Folder with <locale>.json / <locale>.toml files (default: app/locales)
+    pub locale: Option<String>,       // Forced locale; "auto" or unset follows the OS language
+    pub fallback: Option<String>,     // Used for missing keys and unknown locales (default: en)
 }
 
 #[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
 #[allow(dead_code)]
 pub struct FrontierToml {
     pub app: Option<AppConfig>,
     pub window: Option<WindowConfig>,
+    #[serde(default)]
+    pub windows: HashMap<String, WindowOverride>,
     pub security: Option<SecurityConfig>,
+    pub frontend: Option<FrontendConfig>,
+    pub appdata: Option<AppDataConfig>,
+    pub hooks: Option<HooksConfig>,
+    pub build: Option<BuildSettings>,
+    pub storage: Option<StorageConfig>,
+    pub dev: Option<DevConfig>,
+    pub network: Option<NetworkConfig>,
+    pub i18n: Option<I18nConfig>,
+    #[serde(default)]
+    pub features: HashMap<String, bool>,
+    #[serde(default)]
+    pub mime: HashMap<String, String>, // [mime] file extension -> Content-Type of served assets
+    pub env: Option<EnvConfig>,
+    pub logging: Option<LoggingConfig>,
+    pub backend: Option<BackendConfig>,
+    pub macos: Option<MacosConfig>,
+    pub linux: Option<LinuxConfig>,
+    pub webview2: Option<WebView2Config>,
+    pub accessibility: Option<AccessibilityConfig>,
+    pub keyboard: Option<KeyboardConfig>,
+    pub jump_list: Option<JumpListConfig>,
+    pub tray: Option<TrayConfig>,
+    #[serde(default)]
+    pub menu: Vec<MenuConfig>, // [[menu]]: the window menu bar
+    pub lifecycle: Option<LifecycleConfig>,
+    #[serde(default)]
+    pub service: Vec<ServiceConfig>, // [[service]]: backends kept running while the app runs
+    #[serde(default)]
+    pub file_associations: Vec<FileAssociation>,
+    #[serde(default)]
+    pub layouts: HashMap<String, Vec<LayoutWindow>>, // [[layouts.<name>]]
 }
 
+#[derive(Deserialize, Clone, Default)]
+#[serde(deny_unknown_fields)]
 #[allow(dead_code)]
-pub fn load_config(config_path: &Path) -> AppConfig {
-    let mut config = AppConfig { name: Some("App".into()), version: None, description: None, copyright: None };
-    if let Ok(content) = fs::read_to_string(config_path) {
-        if let Ok(parsed) = toml::from_str::<FrontierToml>(&content) {
-            if let Some(app) = parsed.app { config = app; }
+pub struct EnvConfig {
+    #[serde(default)]
+    pub strict: bool, // Fail instead of substituting "" when a ${VAR} without default is not set
+    #[serde(default)]
+    pub vars: HashMap<String, String>, // [env.vars]: environment variables set for every backend
+}
+
+#[derive(Deserialize, Clone, Default)]
+#[serde(deny_unknown_fields)]
+#[allow(dead_code)]
+pub struct LoggingConfig {
+    pub level: Option<String>,        // error, warn, info, debug, trace (default: debug in dev, warn in release)
+    pub file: Option<String>,         // Log file; relative paths are placed in the data directory
+    pub max_size_mb: Option<u64>,     // Rotate the file once it reaches this size (default: 5)
+    pub max_files: Option<u32>,       // Rotated files kept as <file>.1, <file>.2, ... (default: 3)
+    #[serde(default)]
+    pub filters: HashMap<String, String>, // Per-category levels, e.g. routing = "warn"
+}
+
+#[derive(Deserialize, Clone, Default)]
+#[serde(deny_unknown_fields)]
+#[allow(dead_code)]
+pub struct BackendConfig {
+    pub timeout: Option<u64>, // Seconds before a backend is killed (default: no limit)
+    #[serde(default)]
+    pub timeouts: HashMap<String, u64>, // [backend.timeouts]: milliseconds per trigger, over timeout and the modules' timeout_ms
+    #[serde(default)]
+    pub stream: bool,         // Dispatch each stdout line as an 'output' event while the backend runs
+    pub pool: Option<usize>,  // Warm interpreter workers per interpreter for modules with `worker` (default: 0, off)
+    pub max_output: Option<usize>, // Bytes of stdout kept for the reply (default: 16 MB); streamed lines aren't limited
+    #[serde(default)]
+    pub msgpack: Vec<String>, // Triggers whose stdout is MessagePack, decoded in the page ('data' event)
+    pub shutdown_timeout: Option<u64>, // Seconds backends get to stop when the app quits before they are killed (default: 3)
+    pub max_concurrent: Option<usize>, // Page calls running at once; the rest wait their turn (default: no limit)
+    pub max_queue: Option<usize>,      // Calls waiting at once before new ones are refused (default: no limit)
+    #[serde(default)]
+    pub concurrency: HashMap<String, usize>, // [backend.concurrency]: calls of a trigger running at once
+}
+
+// Active config environment: FRONTIER_ENV (which `frontier build` sets to the build profile), otherwise "dev" under `frontier dev` and "prod" for builds
+#[allow(dead_code)]
+pub fn active_environment() -> String {
+    std::env::var("FRONTIER_ENV")
+        .ok()
+        .filter(|e| !e.trim().is_empty())
+        .unwrap_or_else(|| if std::env::var("FRONTIER_DEV").is_ok() { "dev".into() } else { "prod".into() })
+}
+
+// Read frontier.toml merged with its environment overlay, with ${VAR} references expanded
+//
+// A missing variable without a default becomes "" unless [env] strict = true,
+// in which case the config is reported as invalid and defaults are used.
+#[allow(dead_code)]
+pub fn read_config(config_path: &Path) -> Option<String> {
+    let (content, missing, strict) = expand_config(config_path)?;
+    if strict && !missing.is_empty() {
+        report_config_error(config_path, &format!("Missing environment variable(s): {}", missing.join(", ")));
+        return None;
+    }
+    Some(content)
+}
+
+// Environment variables referenced by the config that are not set, and whether [env] strict is on
+#[allow(dead_code)]
+pub fn missing_env_vars(config_path: &Path) -> (Vec<String>, bool) {
+    expand_config(config_path)
+        .map(|(_, missing, strict)| (missing, strict))
+        .unwrap_or_default()
+}
+
+fn expand_config(config_path: &Path) -> Option<(String, Vec<String>, bool)> {
+    read_merged_config(config_path).map(expand_config_text)
+}
+
+// A config's text with ${VAR} references expanded, the missing variables and whether [env] strict is on
+#[allow(dead_code)]
+pub fn expand_config_text(merged: String) -> (String, Vec<String>, bool) {
+    if !merged.contains('$') {
+        return (merged, Vec::new(), false);
+    }
+    // Let the caller report the parse error
+    let Ok(mut doc) = merged.parse::<toml::Value>() else {
+        return (merged, Vec::new(), false);
+    };
+
+    let mut missing = Vec::new();
+    expand_env_value(&mut doc, &mut missing);
+    missing.sort();
+    missing.dedup();
+    let strict = doc.get("env").and_then(|e| e.get("strict")).and_then(|v| v.as_bool()).unwrap_or(false);
+    (toml::to_string(&doc).unwrap_or(merged), missing, strict)
+}
+
+fn expand_env_value(value: &mut toml::Value, missing: &mut Vec<String>) {
+    match value {
+        toml::Value::String(s) => *s = expand_env_str(s, missing),
+        toml::Value::Array(items) => items.iter_mut().for_each(|v| expand_env_value(v, missing)),
+        toml::Value::Table(table) => table.iter_mut().for_each(|(_, v)| expand_env_value(v, missing)),
+        _ => {}
+    }
+}
+
+// "${NAME}", "${NAME:-default}" and "$${" for a literal "${"
+fn expand_env_str(input: &str, missing: &mut Vec<String>) -> String {
+    let mut out = String::new();
+    let mut rest = input;
+    while let Some(pos) = rest.find('$') {
+        out.push_str(&rest[..pos]);
+        let after = &rest[pos..];
+        if let Some(literal) = after.strip_prefix("$${") {
+            out.push_str("${");
+            rest = literal;
+        } else if let (true, Some(end)) = (after.starts_with("${"), after.find('}')) {
+            let expr = &after[2..end];
+            let (name, default) = match expr.split_once(":-") {
+                Some((n, d)) => (n.trim(), Some(d)),
+                None => (expr.trim(), None),
+            };
+            match std::env::var(name) {
+                Ok(v) if !(v.is_empty() && default.is_some()) => out.push_str(&v),
+                _ => match default {
+                    Some(d) => out.push_str(d),
+                    None => missing.push(name.to_string()),
+                },
+            }
+            rest = &after[end + 1..];
+        } else {
+            out.push('$');
+            rest = &after[1..];
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+// frontier.toml merged with its environment overlay (e.g. frontier.dev.toml)
+//
+// Tables are merged recursively; any other value in the overlay replaces the base value.
+fn read_merged_config(config_path: &Path) -> Option<String> {
+    let base = fs::read_to_string(config_path).ok()?;
+
+    let stem = config_path.file_stem().and_then(|s| s.to_str()).unwrap_or("frontier");
+    let overlay_path = config_path.with_file_name(format!("{}.{}.toml", stem, active_environment()));
+    let overlay = match fs::read_to_string(&overlay_path) {
+        Ok(o) => o,
+        Err(_) => return Some(base),
+    };
+
+    match (base.parse::<toml::Value>(), overlay.parse::<toml::Value>()) {
+        (Ok(mut merged), Ok(over)) => {
+            merge_toml(&mut merged, over);
+            toml::to_string(&merged).ok()
+        }
+        // Let the caller report the parse error of the base file
+        (Err(_), _) => Some(base),
+        (Ok(_), Err(e)) => {
+            warn!(target: "config", "⚠️ [CONFIG] Ignoring invalid {}: {}", overlay_path.display(), e);
+            Some(base)
+        }
+    }
+}
+
+fn merge_toml(base: &mut toml::Value, overlay: toml::Value) {
+    match (base, overlay) {
+        (toml::Value::Table(base_table), toml::Value::Table(over_table)) => {
+            for (key, value) in over_table {
+                match base_table.get_mut(&key) {
+                    Some(existing) => merge_toml(existing, value),
+                    None => { base_table.insert(key, value); }
+                }
+            }
         }
+        (base_value, over_value) => *base_value = over_value,
+    }
+}
+
+// Write the merged config for bundling, so release builds never see dev overlays.
+// ${VAR} references are kept as they are and expanded on the user's machine, so
+// the build machine's values (secrets, local paths) never end up in the executable.
+#[allow(dead_code)]
+pub fn write_merged_config(config_path: &Path, dest: &Path) -> std::io::Result<()> {
+    match read_merged_config(config_path) {
+        Some(content) => fs::write(dest, content),
+        None => Ok(()),
+    }
+}
+
+// Parse the (merged) config strictly, reporting errors once with line/column
+//
+// `frontier build` refuses an invalid config, so this only matters while
+// developing: only the keys and entries with errors are dropped, the rest
+// (such as the [security] whitelists) still applies, and the error is printed
+// so typos like `allowed_brower` are noticed.
+#[allow(dead_code)]
+pub fn parse_config(config_path: &Path) -> Option<FrontierToml> {
+    let content = read_config(config_path)?;
+    match toml::from_str::<FrontierToml>(&content) {
+        Ok(parsed) => Some(parsed),
+        Err(e) => {
+            let (parsed, dropped) = match content.parse::<toml::Table>() {
+                Ok(table) => salvage_config(table),
+                Err(_) => (None, vec![]), // Not even TOML: nothing to keep
+            };
+            let ignored = if parsed.is_none() { "using defaults".to_string() } else { format!("ignoring {}", dropped.join(", ")) };
+            report_config_error(config_path, &format!("{} ({})", e, ignored));
+            parsed
+        }
+    }
+}
+
+// Rebuild a config that fails strict parsing from its valid parts: tables key
+// by key, arrays entry by entry, keeping each one only if the whole still parses.
+// Returns the config and the dotted names of what was dropped.
+fn salvage_config(table: toml::Table) -> (Option<FrontierToml>, Vec<String>) {
+    let mut kept = toml::Table::new();
+    let mut dropped = Vec::new();
+    for (key, value) in table {
+        salvage_value(&mut kept, &[], key, value, &mut dropped);
+    }
+    (toml::Value::Table(kept).try_into().ok(), dropped)
+}
+
+fn salvage_value(root: &mut toml::Table, path: &[String], key: String, value: toml::Value, dropped: &mut Vec<String>) {
+    let name = path.iter().chain([&key]).cloned().collect::<Vec<_>>().join(".");
+    table_at(root, path).insert(key.clone(), value.clone());
+    if parses(root) {
+        return;
+    }
+
+    // Refill an emptied table or array with the entries that parse; unknown
+    // keys and wrong types fail even when empty
+    let empty = match value {
+        toml::Value::Table(_) => toml::Value::Table(toml::Table::new()),
+        toml::Value::Array(_) => toml::Value::Array(vec![]),
+        _ => {
+            table_at(root, path).remove(&key);
+            dropped.push(name);
+            return;
+        }
+    };
+    table_at(root, path).insert(key.clone(), empty);
+    if !parses(root) {
+        table_at(root, path).remove(&key);
+        dropped.push(name);
+        return;
+    }
+    match value {
+        toml::Value::Table(entries) => {
+            let inner: Vec<String> = path.iter().cloned().chain([key]).collect();
+            for (k, v) in entries {
+                salvage_value(root, &inner, k, v, dropped);
+            }
+        }
+        toml::Value::Array(items) => {
+            for (i, item) in items.into_iter().enumerate() {
+                if let Some(toml::Value::Array(array)) = table_at(root, path).get_mut(&key) { array.push(item); }
+                if !parses(root) {
+                    if let Some(toml::Value::Array(array)) = table_at(root, path).get_mut(&key) { array.pop(); }
+                    dropped.push(format!("{}[{}]", name, i));
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+fn parses(root: &toml::Table) -> bool {
+    toml::Value::Table(root.clone()).try_into::<FrontierToml>().is_ok()
+}
+
+// The table at a dotted path of `root`, which salvage_value already created
+fn table_at<'a>(root: &'a mut toml::Table, path: &[String]) -> &'a mut toml::Table {
+    path.iter().fold(root, |table, key| match table.get_mut(key) {
+        Some(toml::Value::Table(inner)) => inner,
+        _ => unreachable!("salvage_value inserts tables before descending"),
+    })
+}
+
+static REPORTED_ERRORS: Mutex<Vec<PathBuf>> = Mutex::new(Vec::new());
+
+fn report_config_error(config_path: &Path, error: &str) {
+    // Errors hit before logging is set up go to stderr, and are logged again on the next load
+    if !tracing::dispatcher::has_been_set() {
+        eprintln!("❌ [CONFIG] {} is invalid:\n{}", config_path.display(), error);
+        return;
+    }
+    let mut reported = REPORTED_ERRORS.lock().unwrap();
+    if !reported.contains(&config_path.to_path_buf()) {
+        reported.push(config_path.to_path_buf());
+        error!(target: "config", "❌ [CONFIG] {} is invalid:\n{}", config_path.display(), error);
+    }
+}
+
+// Validate frontier.toml and its environment overlays for `frontier check`
+//
+// Each file is checked on its own so reported lines match the file on disk.
+#[allow(dead_code)]
+pub fn check_config_files(config_path: &Path) -> Vec<(PathBuf, Result<(), String>)> {
+    let mut results = Vec::new();
+    let mut files = vec![config_path.to_path_buf()];
+
+    let stem = config_path.file_stem().and_then(|s| s.to_str()).unwrap_or("frontier");
+    let dir = config_path.parent().filter(|d| !d.as_os_str().is_empty()).unwrap_or(Path::new("."));
+    if let Ok(entries) = fs::read_dir(dir) {
+        let mut overlays: Vec<PathBuf> = entries
+            .flatten()
+            .map(|e| e.path())
+            .filter(|p| {
+                let name = p.file_name().and_then(|n| n.to_str()).unwrap_or("");
+                name.starts_with(&format!("{}.", stem)) && name.ends_with(".toml") && name.matches('.').count() == 2
+            })
+            .collect();
+        overlays.sort();
+        files.extend(overlays);
+    }
+
+    for file in files {
+        let outcome = match fs::read_to_string(&file) {
+            Ok(content) => toml::from_str::<FrontierToml>(&content).map(|_| ()).map_err(|e| e.to_string()),
+            Err(e) => Err(format!("Cannot read file: {}", e)),
+        };
+        results.push((file, outcome));
+    }
+    results
+}
+
+#[allow(dead_code)]
+pub fn load_config(config_path: &Path) -> AppConfig {
+    let mut config = AppConfig { name: Some("App".into()), ..Default::default() };
+    if let Some(parsed) = parse_config(config_path) {
+        if let Some(app) = parsed.app { config = app; }
     }
     config
 }
 
+// Custom protocol scheme for app pages; falls back to "frontier" when unset or invalid
+#[allow(dead_code)]
+pub fn load_app_scheme(config_path: &Path) -> String {
+    let scheme = load_config(config_path).scheme.unwrap_or_default().to_lowercase();
+    let valid = scheme.starts_with(|c: char| c.is_ascii_lowercase())
+        && scheme.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || "+-.".contains(c));
+    if valid { scheme } else { "frontier".into() }
+}
+
 #[allow(dead_code)]
 pub fn load_security_config(config_path: &Path) -> SecurityConfig {
-    if let Ok(content) = fs::read_to_string(config_path) {
-        if let Ok(parsed) = toml::from_str::<FrontierToml>(&content) {
-            if let Some(sec) = parsed.security { return sec; }
-        }
+    if let Some(parsed) = parse_config(config_path) {
+        if let Some(sec) = parsed.security { return sec; }
     }
-    SecurityConfig { allowed_internal: vec![], allowed_browser: vec![] }
+    SecurityConfig { allowed_internal: vec![], allowed_browser: vec![], windows: HashMap::new(), devtools: false, automation: false }
 }
 
 #[allow(dead_code)]
 pub fn load_window_config(config_path: &Path) -> Option<WindowConfig> {
-    if let Ok(content) = fs::read_to_string(config_path) {
-        if let Ok(parsed) = toml::from_str::<FrontierToml>(&content) { return parsed.window; }
+    parse_config(config_path).and_then(|parsed| parsed.window)
+}
+
+#[allow(dead_code)]
+pub fn load_frontend_config(config_path: &Path) -> FrontendConfig {
+    parse_config(config_path).and_then(|parsed| parsed.frontend).unwrap_or_default()
+}
+
+#[allow(dead_code)]
+pub fn load_appdata_config(config_path: &Path) -> AppDataConfig {
+    parse_config(config_path).and_then(|parsed| parsed.appdata).unwrap_or_default()
+}
+
+#[allow(dead_code)]
+pub fn load_hooks_config(config_path: &Path) -> HooksConfig {
+    parse_config(config_path).and_then(|parsed| parsed.hooks).unwrap_or_default()
+}
+
+#[allow(dead_code)]
+pub fn load_build_settings(config_path: &Path) -> BuildSettings {
+    parse_config(config_path).and_then(|parsed| parsed.build).unwrap_or_default()
+}
+
+#[allow(dead_code)]
+pub fn load_window_overrides(config_path: &Path) -> HashMap<String, WindowOverride> {
+    parse_config(config_path).map(|parsed| parsed.windows).unwrap_or_default()
+}
+
+#[allow(dead_code)]
+pub fn load_layouts(config_path: &Path) -> HashMap<String, Vec<LayoutWindow>> {
+    parse_config(config_path).map(|parsed| parsed.layouts).unwrap_or_default()
+}
+
+
+// macOS bundle identifier: [macos] bundle_id, or <qualifier>.<organization>.<name>
+// (default dev.frontier.<name>); also names the app's folders in ~/Library
+#[allow(dead_code)]
+pub fn bundle_identifier(app: &AppConfig, macos: &MacosConfig) -> String {
+    if let Some(id) = &macos.bundle_id { return id.clone(); }
+    // Bundle identifiers only allow letters, digits, '-' and '.'
+    let part = |s: &str| -> String { s.chars().map(|c| if c.is_ascii_alphanumeric() || c == '-' { c } else { '-' }).collect() };
+    format!(
+        "{}.{}.{}",
+        part(app.qualifier.as_deref().unwrap_or("dev")),
+        part(app.organization.as_deref().unwrap_or("frontier")),
+        part(app.name.as_deref().unwrap_or("App"))
+    )
+}
+
+#[allow(dead_code)]
+pub fn load_macos_config(config_path: &Path) -> MacosConfig {
+    parse_config(config_path).and_then(|parsed| parsed.macos).unwrap_or_default()
+}
+
+#[allow(dead_code)]
+pub fn load_linux_config(config_path: &Path) -> LinuxConfig {
+    parse_config(config_path).and_then(|parsed| parsed.linux).unwrap_or_default()
+}
+
+#[allow(dead_code)]
+pub fn load_file_associations(config_path: &Path) -> Vec<FileAssociation> {
+    parse_config(config_path).map(|parsed| parsed.file_associations).unwrap_or_default()
+}
+
+// None when there is no [jump_list] section
+#[allow(dead_code)]
+pub fn load_jump_list_config(config_path: &Path) -> Option<JumpListConfig> {
+    parse_config(config_path).and_then(|parsed| parsed.jump_list)
+}
+
+#[allow(dead_code)]
+pub fn load_lifecycle_config(config_path: &Path) -> LifecycleConfig {
+    parse_config(config_path).and_then(|parsed| parsed.lifecycle).unwrap_or_default()
+}
+
+// None when there is no [tray] section
+#[allow(dead_code)]
+pub fn load_tray_config(config_path: &Path) -> Option<TrayConfig> {
+    parse_config(config_path).and_then(|parsed| parsed.tray)
+}
+
+#[allow(dead_code)]
+pub fn load_menu_config(config_path: &Path) -> Vec<MenuConfig> {
+    parse_config(config_path).map(|parsed| parsed.menu).unwrap_or_default()
+}
+
+#[allow(dead_code)]
+pub fn load_service_config(config_path: &Path) -> Vec<ServiceConfig> {
+    parse_config(config_path).map(|parsed| parsed.service).unwrap_or_default()
+}
+
+#[allow(dead_code)]
+pub fn load_webview2_config(config_path: &Path) -> WebView2Config {
+    parse_config(config_path).and_then(|parsed| parsed.webview2).unwrap_or_default()
+}
+
+#[allow(dead_code)]
+pub fn load_accessibility_config(config_path: &Path) -> AccessibilityConfig {
+    parse_config(config_path).and_then(|parsed| parsed.accessibility).unwrap_or_default()
+}
+
+#[allow(dead_code)]
+pub fn load_keyboard_config(config_path: &Path) -> KeyboardConfig {
+    parse_config(config_path).and_then(|parsed| parsed.keyboard).unwrap_or_default()
+}
+
+#[allow(dead_code)]
+pub fn load_storage_config(config_path: &Path) -> StorageConfig {
+    parse_config(config_path).and_then(|parsed| parsed.storage).unwrap_or_default()
+}
+
+#[allow(dead_code)]
+pub fn load_dev_config(config_path: &Path) -> DevConfig {
+    parse_config(config_path).and_then(|parsed| parsed.dev).unwrap_or_default()
+}
+
+#[allow(dead_code)]
+pub fn load_network_config(config_path: &Path) -> NetworkConfig {
+    parse_config(config_path).and_then(|parsed| parsed.network).unwrap_or_default()
+}
+
+#[allow(dead_code)]
+pub fn load_features(config_path: &Path) -> HashMap<String, bool> {
+    parse_config(config_path).map(|parsed| parsed.features).unwrap_or_default()
+}
+
+// [mime] with the extensions lowercased and without their dot
+#[allow(dead_code)]
+pub fn load_mime_types(config_path: &Path) -> HashMap<String, String> {
+    parse_config(config_path).map(|parsed| parsed.mime).unwrap_or_default().into_iter()
+        .map(|(ext, mime)| (ext.trim_start_matches('.').to_lowercase(), mime))
+        .collect()
+}
+
+// Merge a build profile's feature overrides into a bundled frontier.toml
+#[allow(dead_code)]
+pub fn write_feature_overrides(bundled_path: &Path, overrides: &HashMap<String, bool>) -> std::io::Result<()> {
+    if overrides.is_empty() {
+        return Ok(());
     }
-    None
-}
\ No newline at end of file
+    let content = fs::read_to_string(bundled_path).unwrap_or_default();
+    let mut doc = content.parse::<toml::Value>().unwrap_or_else(|_| toml::Value::Table(toml::value::Table::new()));
+    let mut features = toml::value::Table::new();
+    for (name, enabled) in overrides {
+        features.insert(name.clone(), toml::Value::Boolean(*enabled));
+    }
+    let mut overlay = toml::value::Table::new();
+    overlay.insert("features".into(), toml::Value::Table(features));
+    merge_toml(&mut doc, toml::Value::Table(overlay));
+    fs::write(bundled_path, toml::to_string(&doc).unwrap_or_default())
+}
+
+#[allow(dead_code)]
+pub fn load_logging_config(config_path: &Path) -> LoggingConfig {
+    parse_config(config_path).and_then(|parsed| parsed.logging).unwrap_or_default()
+}
+
+#[allow(dead_code)]
+pub fn load_env_vars(config_path: &Path) -> HashMap<String, String> {
+    parse_config(config_path).and_then(|parsed| parsed.env).map(|env| env.vars).unwrap_or_default()
+}
+
+#[allow(dead_code)]
+pub fn load_backend_config(config_path: &Path) -> BackendConfig {
+    parse_config(config_path).and_then(|parsed| parsed.backend).unwrap_or_default()
+}
+
+#[allow(dead_code)]
+pub fn load_i18n_config(config_path: &Path) -> I18nConfig {
+    parse_config(config_path).and_then(|parsed| parsed.i18n).unwrap_or_default()
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn expand(input: &str) -> (String, Vec<String>) {
+        let mut missing = Vec::new();
+        (expand_env_str(input, &mut missing), missing)
+    }
+
+    #[test]
+    fn expands_set_variables() {
+        std::env::set_var("FRONTIER_TEST_TOKEN", "secret");
+        assert_eq!(expand("Bearer ${FRONTIER_TEST_TOKEN}"), ("Bearer secret".to_string(), vec![]));
+        assert_eq!(expand("${ FRONTIER_TEST_TOKEN }/x"), ("secret/x".to_string(), vec![]));
+    }
+
+    #[test]
+    fn defaults_apply_when_unset_or_empty() {
+        std::env::set_var("FRONTIER_TEST_EMPTY", "");
+        assert_eq!(expand("${FRONTIER_TEST_UNSET:-eu}"), ("eu".to_string(), vec![]));
+        assert_eq!(expand("${FRONTIER_TEST_EMPTY:-eu}"), ("eu".to_string(), vec![]));
+        assert_eq!(expand("${FRONTIER_TEST_UNSET:-}"), ("".to_string(), vec![]));
+    }
+
+    #[test]
+    fn missing_variables_are_reported() {
+        assert_eq!(expand("a${FRONTIER_TEST_UNSET}b"), ("ab".to_string(), vec!["FRONTIER_TEST_UNSET".to_string()]));
+    }
+
+    #[test]
+    fn escapes_and_plain_dollars_are_kept() {
+        assert_eq!(expand("$${FRONTIER_TEST_TOKEN}"), ("${FRONTIER_TEST_TOKEN}".to_string(), vec![]));
+        assert_eq!(expand("costs $5"), ("costs $5".to_string(), vec![]));
+        assert_eq!(expand("${unclosed"), ("${unclosed".to_string(), vec![]));
+    }
+
+    #[test]
+    fn expand_config_text_reads_strict() {
+        let (text, missing, strict) = expand_config_text("[env]\nstrict = true\n[app]\nname = \"${FRONTIER_TEST_UNSET}\"\n".into());
+        assert!(strict);
+        assert_eq!(missing, vec!["FRONTIER_TEST_UNSET".to_string()]);
+        assert!(text.contains("name = \"\""));
+    }
+
+    #[test]
+    fn overlay_tables_merge_and_values_replace() {
+        let mut base: toml::Value = "[window]\nwidth = 800\nheight = 600\n[app]\nname = \"A\"\nlist = [1, 2]\n".parse().unwrap();
+        let overlay: toml::Value = "[window]\nwidth = 1024\n[app]\nlist = [3]\n[backend]\npool = 2\n".parse().unwrap();
+        merge_toml(&mut base, overlay);
+        assert_eq!(base["window"]["width"].as_integer(), Some(1024));
+        assert_eq!(base["window"]["height"].as_integer(), Some(600));
+        assert_eq!(base["app"]["name"].as_str(), Some("A"));
+        assert_eq!(base["app"]["list"].as_array().map(|l| l.len()), Some(1));
+        assert_eq!(base["backend"]["pool"].as_integer(), Some(2));
+    }
+
+    #[test]
+    fn salvage_drops_only_invalid_parts() {
+        let text = "[security]\nallowed_brower = [\"x\"]\nallowed_internal = [\"https://api.example.com\", 5]\n\
+                    [security.windows.settings]\ncommands = [\"save\"]\ncsp_typo = \"x\"\n\
+                    [window]\nwidth = \"wide\"\nheight = 600\n";
+        assert!(toml::from_str::<FrontierToml>(text).is_err());
+        let (parsed, dropped) = salvage_config(text.parse().unwrap());
+        let parsed = parsed.unwrap();
+        let security = parsed.security.unwrap();
+        assert_eq!(security.allowed_internal, vec!["https://api.example.com".to_string()]);
+        assert_eq!(security.windows["settings"].commands, Some(vec!["save".to_string()]));
+        assert_eq!(parsed.window.unwrap().height, Some(600.0));
+        for name in ["security.allowed_brower", "security.allowed_internal[1]", "security.windows.settings.csp_typo", "window.width"] {
+            assert!(dropped.contains(&name.to_string()), "{} not dropped: {:?}", name, dropped);
+        }
+    }
+}
@@ -1,38 +1,64 @@
 // Copyright (c) 2026 The Frontier Framework Authors
 // SPDX-License-Identifier: Apache-2.0 WITH LLVM-exception OR MIT
 
-#![windows_subsystem = "windows"]
+// Console builds (`frontier build --console`) keep the console subsystem
+#![cfg_attr(not(frontier_console), windows_subsystem = "windows")]
 
 mod window;
 mod system;
 mod config;
+mod i18n;
+mod bridge;
+mod logging;
+mod crash;
+mod repl;
+mod inspect;
+mod perf;
+mod pool;
+mod serve;
+mod embedded;
+mod security;
+mod payload;
+mod platform;
+mod webview2;
+mod instance;
+mod automation;
+mod jumplist;
+mod shared;
+mod layout;
+mod tray;
+mod notification;
+mod error;
+mod appdata;
+mod envelope;
+mod menu;
+mod filedialog;
+mod store;
 
-use rust_embed::RustEmbed;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::borrow::Cow;
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex};
-use std::thread;
 use walkdir::WalkDir;
 use wry::{
     application::{
         event::{Event, WindowEvent},
         event_loop::{ControlFlow, EventLoop, EventLoopProxy, EventLoopWindowTarget},
-        window::{WindowBuilder, WindowId, Icon},
-        dpi::{LogicalSize, LogicalPosition},
+        menu::MenuBar,
+        window::{Window, WindowBuilder, WindowId, Icon},
+        dpi::{LogicalSize, LogicalPosition, PhysicalPosition},
     },
     webview::{WebViewBuilder, WebContext, WebView},
     http::{Response, header},
 };
 use image::imageops::FilterType;
+use error::FrontierError;
+use security::{SecurityRules, SharedSecurity, UrlWhitelist};
 use notify::{Watcher, RecursiveMode, EventKind};
 use std::time::{Duration, Instant};
-use native_dialog::{MessageDialog, MessageType};
+use tracing::{debug, error, info, info_span, warn};
 
-#[derive(RustEmbed)]
-#[folder = "assets/"]
-struct Assets;
 
 // --- GLOBAL BROWSER LOCK FOR DEDUPLICATION ---
 // Prevents multiple threads from simultaneously opening browser windows for the same URL.
@@ -45,77 +71,366 @@ lazy_static::lazy_static! {
 struct AppState {
     webviews: HashMap<WindowId, WebView>,
     persistence: HashMap<WindowId, PersistenceConfig>,
+    dependencies: HashMap<WindowId, Arc<Mutex<HashSet<String>>>>,
     system: Arc<Mutex<system::SystemState>>,
     main_proxy: EventLoopProxy<FrontierEvent>,
-    debounce: HashMap<PathBuf, Instant>,
+    pending_changes: Vec<PathBuf>,
+    runtime: tokio::runtime::Runtime,
+    jobs: Arc<Mutex<system::RunningJobs>>,
+    warm: Option<WarmWindow>,
+    session: Vec<(WindowId, String)>, // Open pages, in the order they were opened
+    bounds: HashMap<WindowId, window::WindowState>, // Last position and size while neither maximized nor minimized
+    opened_files: Vec<String>,        // Files waiting for a loaded page ('open-file' events)
+    launch_events: Vec<serde_json::Value>, // Later launches waiting for a loaded page ('app:launch' events)
+    launch_tasks: Vec<String>,        // Jump list tasks waiting for a loaded page
+    ready: HashSet<WindowId>,         // Windows whose page has loaded
+    state_subscribers: HashSet<WindowId>, // Windows with Frontier.state.subscribe() handlers
+    views: HashMap<WindowId, window::PageView>, // Zoom and scroll of frontier-remember-view pages
+    unrestored: HashSet<WindowId>,              // Windows whose saved view the page hasn't been given yet
+    dialogs: HashMap<WindowId, DialogWindow>,   // Open Frontier.openDialog windows
+    opens: HashMap<WindowId, VecDeque<Instant>>, // Windows each window opened in the last minute
+    notifications: HashMap<String, WindowId>,   // Window that showed each notification, by tag
+    menu: menu::AppMenu,                        // [[menu]] items of the window menu bars
+    splash: Option<Splash>,                     // [window] splash, while it's shown
+    close_guards: HashSet<WindowId>,            // Windows with Frontier.onCloseRequested() handlers
+    closing: HashMap<WindowId, CloseAsk>,       // Windows whose page is being asked whether to close
+    close_asks: u64,                            // Last CloseAsk id
+    store: store::Store,                        // Frontier.store namespaces
 }
 
+// A close the page's onCloseRequested handlers haven't answered yet
+struct CloseAsk {
+    id: u64,
+    handling: bool, // The page responded, so the handlers may take their time (a confirmation)
+}
+
+// The page's answer to a CloseAsk
+enum CloseReply {
+    Handling,
+    Close,
+    Stay,
+    TimedOut, // No response within [window] close_timeout
+}
+
+// The splash screen and the windows kept hidden behind it
+struct Splash {
+    webview: WebView, // Closes the window when dropped
+    hidden: Vec<WindowId>,
+}
+
+// A window opened with Frontier.openDialog, until it returns its result
+struct DialogWindow {
+    opener: WindowId,
+    id: String, // The opener's request id
+    data: serde_json::Value,
+}
+
+// How long file changes are collected before pages are reloaded
+const RELOAD_COALESCE_MS: u64 = 200;
+// Windows open when the app last closed ([window] restore_session)
+const SESSION_FILE: &str = "session.json";
+// [app] version of the last start, to tell when an update was applied ([lifecycle] on_update)
+const VERSION_FILE: &str = "version";
+// Seconds running backends get to stop when the app quits ([backend] shutdown_timeout)
+const DEFAULT_SHUTDOWN_TIMEOUT: u64 = 3;
+// Period of [window] max_opens_per_minute
+const OPEN_RATE_PERIOD: Duration = Duration::from_secs(60);
+// Idle time after a window opens before the next hidden webview is prepared
+const PREWARM_DELAY_MS: u64 = 1000;
+// Seconds the splash screen waits for Frontier.ready() ([window] splash_timeout)
+const DEFAULT_SPLASH_TIMEOUT: u64 = 10;
+// Size of a splash page ([window] splash_width and splash_height)
+const DEFAULT_SPLASH_SIZE: (f64, f64) = (480.0, 320.0);
+// Seconds a page with onCloseRequested handlers gets to respond before its window closes anyway ([window] close_timeout)
+const DEFAULT_CLOSE_TIMEOUT: u64 = 3;
+
 struct PersistenceConfig {
     should_save: bool,
     save_file: PathBuf,
+    remember_view: bool, // frontier-remember-view
 }
 
 enum FrontierEvent {
-    RunCommand(WindowId, String),
-    BackendReply(WindowId, String),
-    OpenWindow(String), 
+    RunCommand(WindowId, envelope::BackendCall),
+    BackendReply(WindowId, String, Option<envelope::Callback>), // Reply, and where a JSON call wants it
+    BackendOutput(WindowId, String, String, Option<u64>),       // Trigger, line, callbackId
+    BackendData(WindowId, String, Vec<u8>, Option<u64>),        // Trigger, MessagePack bytes, callbackId
+    SessionStart(WindowId, String, envelope::BackendCall),     // Frontier.session.start (session id, call)
+    SessionOutput(WindowId, String, String),                   // Session id, stdout line
+    SessionEnd(WindowId, serde_json::Value),                   // { id, exit, error }
+    OpenWindow(Option<WindowId>, String), // The window that asked for it, and the page
     FileChanged(PathBuf),
+    FlushChanges,
+    Prewarm,
+    #[cfg_attr(not(target_os = "macos"), allow(dead_code))]
+    Reopen, // Dock icon clicked while no window is open (macOS)
+    Launch(instance::Launch), // Associated files opened, or jump list tasks or notification buttons picked, while the app runs
+    JumpList(Vec<config::JumpTask>), // Frontier.jumpList.setTasks()
+    Accessibility(WindowId, platform::AccessibilitySettings), // Answer to Frontier.accessibility.query()
+    State(WindowId, StateRequest), // Frontier.state calls
+    StatePatch(Vec<serde_json::Value>), // ::frontier-state:: lines printed by backends
+    Layout(LayoutRequest), // Frontier.layout calls
+    Dialog(WindowId, DialogRequest), // Frontier.openDialog and the dialog's calls
+    Notify(WindowId, notification::Notification), // Frontier.notify()
+    WindowControl(WindowId, WindowCommand), // Frontier.window calls
+    CloseSplash(Option<WindowId>), // The window that called Frontier.ready(), or None when the splash timed out
+    Monitors(WindowId, String), // Frontier.monitors.list(), with its request id
+    Store(WindowId, String, String), // Frontier.store calls: request id, { op, namespace, window, key, value }
+    CloseGuard(WindowId, bool), // The page has (or no longer has) onCloseRequested handlers
+    CloseReply(WindowId, u64, CloseReply),
+    PageReady(WindowId),
+    Zoom(WindowId, f64),          // Frontier.view.setZoom()
+    Scroll(WindowId, [f64; 2]),   // Scroll position reported by a frontier-remember-view page
+    Automation(automation::Request),
+    Devtools(WindowId, String),
+    DevError(WindowId, String, String),
+}
+
+// Frontier.state calls, answered in the event loop (see shared.rs)
+enum StateRequest {
+    Get(String, String), // Request id, path
+    Set(String, serde_json::Value),
+    Subscribe(bool),
+}
+
+// Frontier.layout calls (see layout.rs)
+enum LayoutRequest {
+    Apply(String),
+    Save(String), // The open windows, as they are now
+    Define(String, Vec<config::LayoutWindow>),
 }
 
 #[derive(PartialEq, Clone, Copy, Debug)]
 enum UrlCategory { Frontier, Internal, Browser, Blocked }
 
+// Frontier.window calls, on the calling window
+enum WindowCommand {
+    Minimize,
+    Maximize,
+    Unmaximize,
+    ToggleMaximize,
+    Close, // Like its close button, so [tray] close_to_tray applies
+    Focus,
+    Center,
+    SetTitle(String),
+    SetSize(f64, f64), // Logical pixels
+}
+
+// Frontier.openDialog calls and the dialog's side of them
+enum DialogRequest {
+    Open(String, String, serde_json::Value), // Request id, page, data
+    Data(String),                            // Request id
+    Close(serde_json::Value),                // Frontier.closeWithResult()
+    File(String, filedialog::Request),       // Request id, Frontier.dialog.open/save/folder
+    Picked(String, serde_json::Value),       // Request id, chosen path(s) or null
+}
+
 // --- MAIN ---
 
 fn main() {
     if let Err(e) = run_application() {
-        let _ = MessageDialog::new()
-            .set_type(MessageType::Error)
-            .set_title("Frontier Runtime Error")
-            .set_text(&format!("{}", e))
-            .show_alert();
+        error!(target: "app", "❌ [APP] {} ({} error, exit code {})", e, e.kind(), e.exit_code());
+        crash::report(&i18n::message("stopped", &[]), &format!("Error: {}", e), None);
+        std::process::exit(e.exit_code());
     }
 }
 
-fn run_application() -> Result<(), Box<dyn std::error::Error>> {
+fn run_application() -> Result<(), FrontierError> {
+    let args: Vec<String> = std::env::args().collect();
+    // `--dev` behaves like FRONTIER_DEV (the env var also selects frontier.dev.toml)
+    if args.iter().any(|a| a == "--dev") {
+        std::env::set_var("FRONTIER_DEV", "true");
+    }
     let is_dev = std::env::var("FRONTIER_DEV").is_ok();
+    let log_file = args.windows(2).find(|w| w[0] == "--log-file").map(|w| w[1].clone());
+    let trace_file = args.windows(2).find(|w| w[0] == "--trace-file").map(|w| PathBuf::from(&w[1]));
+    perf::init(trace_file);
 
-    if is_dev {
-        #[cfg(target_os = "windows")]
-        unsafe {
-            use winapi::um::wincon::{AttachConsole, ATTACH_PARENT_PROCESS};
-            let _ = AttachConsole(ATTACH_PARENT_PROCESS);
-        }
+    // --console (and console builds) show the log in a terminal, to troubleshoot installed apps
+    let console = is_dev || cfg!(frontier_console) || args.iter().any(|a| a == "--console");
+    if console {
+        attach_console();
     }
 
-    let (base_dir, data_dir, dev_cache) = setup_paths(is_dev)?;
-    let (commands, _modules_map) = scan_environment(&base_dir, &dev_cache, is_dev);
+    let (base_dir, dirs) = setup_paths(is_dev).map_err(|e| FrontierError::Config(format!("Cannot set up the app's folders: {}", e)))?;
+    let data_dir = dirs.data.clone();
+    let mut logging_config = config::load_logging_config(&base_dir.join("frontier.toml"));
+    if log_file.is_some() { logging_config.file = log_file; }
+    logging::init(&logging_config, is_dev, console, &data_dir);
+    crash::install(&data_dir);
+    info!(target: "app", "🚀 [APP] Starting ({} mode, data in {})", if is_dev { "dev" } else { "release" }, data_dir.display());
+    let (commands, _modules_map) = scan_environment(&base_dir, &dirs.cache, is_dev);
     let security_global = config::load_security_config(&base_dir.join("frontier.toml"));
+    let automation_port = automation::requested_port(&args).filter(|_| {
+        let allowed = is_dev || security_global.automation;
+        if !allowed { warn!(target: "security", "🚫 [SECURITY] --automation ignored ([security] automation is off)"); }
+        allowed
+    });
+    let rpc_stdio = automation::rpc_stdio_requested(&args) && {
+        let allowed = is_dev || security_global.automation;
+        if !allowed { warn!(target: "security", "🚫 [SECURITY] --rpc-stdio ignored ([security] automation is off)"); }
+        allowed
+    };
+    inspect::trace_ipc(is_dev || automation_port.is_some() || rpc_stdio);
+    let window_defaults = load_window_defaults(&base_dir.join("frontier.toml"));
+    let window_overrides = config::load_window_overrides(&base_dir.join("frontier.toml"));
+    let network = config::load_network_config(&base_dir.join("frontier.toml"));
+    let scheme = config::load_app_scheme(&base_dir.join("frontier.toml"));
+    // Files opened through [[file_associations]], jump list tasks and notification
+    // buttons go to the running instance, if any
+    let file_associations = config::load_file_associations(&base_dir.join("frontier.toml"));
+    let jump_list = config::load_jump_list_config(&base_dir.join("frontier.toml"));
+    let launch = instance::Launch::from_args(&args);
+    if !launch.is_empty() && instance::forward(&data_dir, &launch) {
+        return Ok(());
+    }
+    let i18n_bundle = load_i18n(&base_dir, is_dev);
+    let dev_frontend_url = if is_dev { dev_server_url(&base_dir.join("frontier.toml")) } else { None };
+    let backend_config = config::load_backend_config(&base_dir.join("frontier.toml"));
+    let accessibility = config::load_accessibility_config(&base_dir.join("frontier.toml"));
+    let keyboard = config::load_keyboard_config(&base_dir.join("frontier.toml"));
+    if let Some(mode) = accessibility.high_contrast.as_deref().filter(|m| !matches!(*m, "auto" | "always" | "off")) {
+        warn!(target: "config", "⚠️ [CONFIG] Unknown [accessibility] high_contrast '{}' (use auto, always or off)", mode);
+    }
 
+    let children = Arc::new(system::ChildProcesses::default());
     let system = Arc::new(Mutex::new(system::SystemState {
         commands,
         #[cfg(debug_assertions)]
         modules_map: _modules_map,
         base_dir: base_dir.clone(),
         data_dir: data_dir.clone(),
+        config_dir: dirs.config.clone(),
+        cache_dir: dirs.cache.clone(),
         #[cfg(debug_assertions)]
-        dev_cache,
-        allowed_internal: security_global.allowed_internal,
-        allowed_browser: security_global.allowed_browser,
+        dev_cache: dirs.cache.clone(),
         is_dev,
-        window_icon: load_application_icon(&base_dir),
+        devtools: is_dev || security_global.devtools,
+        security: SecurityRules::shared(security_global),
+        scheme,
+        dev_frontend_url,
+        network,
+        backend: backend_config.clone(),
+        workers: Arc::new(pool::WorkerPool::new(data_dir.join("workers"), backend_config.pool.unwrap_or(0), children.clone())),
+        i18n: i18n_bundle,
+        features: config::load_features(&base_dir.join("frontier.toml")),
+        window_icon: load_application_icon(&base_dir, is_dev, &window_defaults),
+        window_defaults,
+        window_overrides,
+        accessibility,
+        os_accessibility: platform::accessibility_settings(),
+        keyboard,
+        frontend: config::load_frontend_config(&base_dir.join("frontier.toml")),
+        appdata: config::load_appdata_config(&base_dir.join("frontier.toml")),
+        mime_types: config::load_mime_types(&base_dir.join("frontier.toml")),
+        backend_env: config::load_env_vars(&base_dir.join("frontier.toml")),
+        shared_state: serde_json::json!({}),
+        launch_args: launch.args.clone(),
+        launch_files: launch.files.clone(),
+        children: children.clone(),
+        scheduler: Arc::new(system::Scheduler::new(backend_config.max_concurrent, backend_config.max_queue)),
     }));
+    // A crash of the event loop kills the backends right away instead of leaving them
+    // running (panics in backend tasks only end that task)
+    let crash_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        if std::thread::current().name() == Some("main") { children.kill_all(); }
+        crash_hook(info);
+    }));
+
+    if is_dev { publish_runtime_state(&system.lock().unwrap()); }
 
+    platform::apply_linux_options(&config::load_linux_config(&base_dir.join("frontier.toml")));
+    platform::apply_webkit_quirks();
+    platform::apply_keyboard_options(&system.lock().unwrap().keyboard);
+    // A missing WebView2 runtime is explained to the user instead of failing in wry,
+    // so it ends without a crash report
+    if !webview2::ensure_runtime(&base_dir, &config::load_webview2_config(&base_dir.join("frontier.toml")), is_dev) {
+        let e = FrontierError::Webview("No usable WebView2 runtime".into());
+        error!(target: "app", "❌ [APP] {} ({} error, exit code {})", e, e.kind(), e.exit_code());
+        std::process::exit(e.exit_code());
+    }
     let event_loop = EventLoop::<FrontierEvent>::with_user_event();
     let main_proxy = event_loop.create_proxy();
-    let mut web_context = WebContext::new(Some(data_dir));
+    let macos = config::load_macos_config(&base_dir.join("frontier.toml"));
+    #[cfg(target_os = "macos")]
+    let event_loop = setup_macos(event_loop, &macos, main_proxy.clone());
+    if let Some(port) = automation_port {
+        let automation_proxy = main_proxy.clone();
+        automation::start(port, &data_dir, move |request| { let _ = automation_proxy.send_event(FrontierEvent::Automation(request)); });
+    }
+    if rpc_stdio {
+        let rpc_proxy = main_proxy.clone();
+        automation::start_stdio(move |request| { let _ = rpc_proxy.send_event(FrontierEvent::Automation(request)); });
+    }
+    let launch_proxy = main_proxy.clone();
+    instance::listen(&data_dir, move |launch| { let _ = launch_proxy.send_event(FrontierEvent::Launch(launch)); });
+    // The taskbar keeps the list between runs; built apps refresh it on every start
+    if let Some(jump_list) = jump_list.filter(|_| !is_dev) {
+        jumplist::set(jump_list.tasks, jump_list.recent.unwrap_or(true));
+    }
+    if !file_associations.is_empty() {
+        #[cfg(target_os = "macos")]
+        {
+            // Finder hands files to the running app (or the one it launches) through Apple Events
+            let files_proxy = main_proxy.clone();
+            platform::on_open_files(move |files| { let _ = files_proxy.send_event(FrontierEvent::Launch(instance::Launch { files, ..Default::default() })); });
+        }
+        // The executable registers itself (on Windows; the bundle or .desktop file does it elsewhere)
+        if !is_dev {
+            let app = config::load_config(&base_dir.join("frontier.toml"));
+            platform::register_file_associations(app.name.as_deref().unwrap_or("App"), &file_associations);
+        }
+    }
+    // Background apps live in the tray, shown or not
+    let tray_config = config::load_tray_config(&base_dir.join("frontier.toml"));
+    let mut tray = tray_config.as_ref().and_then(|tray_config| {
+        let app = config::load_config(&base_dir.join("frontier.toml"));
+        let icon = load_tray_icon(&base_dir, is_dev, tray_config).or_else(|| system.lock().unwrap().window_icon.clone());
+        tray::Tray::create(&event_loop, tray_config, icon, app.name.as_deref().unwrap_or("App"))
+    });
+    let start_hidden = tray.is_some() && tray_config.as_ref().and_then(|t| t.start_hidden).unwrap_or(false);
+    let close_to_tray = tray.is_some() && tray_config.as_ref().and_then(|t| t.close_to_tray).unwrap_or(false);
+    // On macOS apps stay in the Dock after their last window closes, and tray apps in the tray
+    let keep_running = (cfg!(target_os = "macos") && !macos.quit_on_last_window.unwrap_or(false))
+        || (tray.is_some() && tray_config.as_ref().and_then(|t| t.keep_running).unwrap_or(true));
+    let mut web_contexts = WebContexts::new(data_dir.clone());
+    // Backends run as tokio processes, so many can stream, time out or be cancelled at once
+    let runtime = tokio::runtime::Builder::new_multi_thread()
+        .enable_all()
+        .thread_name("frontier-backend")
+        .build()
+        .map_err(|e| FrontierError::Backend(format!("Cannot start the backend runtime: {}", e)))?;
+    warm_worker_pool(&system.lock().unwrap(), &runtime);
 
     let mut app_state = AppState {
         webviews: HashMap::new(),
         persistence: HashMap::new(),
+        dependencies: HashMap::new(),
         system: system.clone(),
         main_proxy: main_proxy.clone(),
-        debounce: HashMap::new(),
+        pending_changes: Vec::new(),
+        runtime,
+        jobs: Arc::new(Mutex::new(system::RunningJobs::default())),
+        warm: None,
+        session: Vec::new(),
+        bounds: HashMap::new(),
+        opened_files: launch.files,
+        launch_events: Vec::new(),
+        launch_tasks: launch.tasks,
+        ready: HashSet::new(),
+        state_subscribers: HashSet::new(),
+        views: HashMap::new(),
+        unrestored: HashSet::new(),
+        dialogs: HashMap::new(),
+        opens: HashMap::new(),
+        notifications: HashMap::new(),
+        menu: menu::AppMenu::new(config::load_menu_config(&base_dir.join("frontier.toml"))),
+        splash: None,
+        close_guards: HashSet::new(),
+        closing: HashMap::new(),
+        close_asks: 0,
+        store: store::Store::new(data_dir.join("store")),
     };
 
     let mut _watcher = None;
@@ -129,49 +444,506 @@ fn run_application() -> Result<(), Box<dyn std::error::Error>> {
                     }
                 }
             }
-        })?;
+        }).map_err(|e| FrontierError::Asset(format!("Cannot watch app/ for changes: {}", e)))?;
         let _ = w.watch(&base_dir.join("app"), RecursiveMode::Recursive);
+        // frontier.toml and its overlays (frontier.dev.toml, ...) live in the project root
+        let _ = w.watch(&base_dir, RecursiveMode::NonRecursive);
         _watcher = Some(w);
     }
 
-    create_new_window(&event_loop, &mut app_state, &mut web_context, "index.html", main_proxy.clone())?;
+    // Dev REPL on stdin: run triggers and open pages from the terminal (unless
+    // stdin carries JSON-RPC)
+    if is_dev && !rpc_stdio {
+        let repl_proxy = main_proxy.clone();
+        repl::start(system.clone(), app_state.runtime.handle().clone(), move |page| {
+            let _ = repl_proxy.send_event(FrontierEvent::OpenWindow(None, page));
+        });
+    }
+
+    // [lifecycle] hooks run to the end before any page can call a backend
+    let lifecycle = config::load_lifecycle_config(&base_dir.join("frontier.toml"));
+    if let (Some(trigger), Some(previous)) = (&lifecycle.on_update, updated_from(&base_dir, &data_dir)) {
+        run_lifecycle_hook(&app_state, "on_update", trigger, &previous);
+    }
+    if let Some(trigger) = &lifecycle.on_start {
+        run_lifecycle_hook(&app_state, "on_start", trigger, "");
+    }
+    // [[service]] backends: their output is logged, and state patches they print are applied
+    let services = {
+        let proxy = main_proxy.clone();
+        let on_line: system::ServiceSink = Arc::new(move |trigger: &str, line: &str| {
+            let (line, patches) = shared::take_patches(line);
+            if !patches.is_empty() {
+                let _ = proxy.send_event(FrontierEvent::StatePatch(patches));
+                return;
+            }
+            debug!(target: "backend", "🛰️ [SERVICE:{}] {}", trigger, line);
+        });
+        let config = config::load_service_config(&base_dir.join("frontier.toml"));
+        system::Services::start(&system, app_state.runtime.handle(), config, on_line)
+    };
+
+    if start_hidden {
+        info!(target: "app", "📌 [TRAY] Started in the tray");
+    } else {
+        open_splash(&event_loop, &mut app_state, &mut web_contexts);
+        let startup = startup_windows(&app_state.system.lock().unwrap());
+        open_startup_windows(&event_loop, &mut app_state, &mut web_contexts, &startup, main_proxy.clone())?;
+        schedule_prewarm(&app_state);
+    }
 
     event_loop.run(move |event, event_loop, control_flow| {
         *control_flow = ControlFlow::Wait;
+        if let Some(action) = tray.as_ref().and_then(|t| t.action(&event)) {
+            match action {
+                tray::TrayAction::Show => show_app(event_loop, &mut app_state, &mut web_contexts),
+                // Picked items run like jump list tasks
+                tray::TrayAction::Task(task) => {
+                    let _ = app_state.main_proxy.send_event(FrontierEvent::Launch(instance::Launch { tasks: vec![task], ..Default::default() }));
+                }
+                tray::TrayAction::Quit => {
+                    if let Some(tray) = tray.as_mut() { tray.remove(); }
+                    *control_flow = ControlFlow::Exit;
+                }
+            }
+            return;
+        }
+        if let Some((wid, pick)) = app_state.menu.action(&event) {
+            run_menu_pick(&app_state, wid, pick);
+            return;
+        }
         match event {
             Event::UserEvent(FrontierEvent::FileChanged(path)) => {
-                if app_state.debounce.get(&path).map_or(false, |t| t.elapsed() < Duration::from_millis(500)) { return; }
-                app_state.debounce.insert(path.clone(), Instant::now());
-                for webview in app_state.webviews.values() { let _ = webview.evaluate_script("location.reload();"); }
+                // Editors save through temp files and renames, so one save fires several
+                // events; changes are collected and applied together after a short pause
+                if app_state.pending_changes.is_empty() {
+                    let proxy = app_state.main_proxy.clone();
+                    app_state.runtime.spawn(async move {
+                        tokio::time::sleep(Duration::from_millis(RELOAD_COALESCE_MS)).await;
+                        let _ = proxy.send_event(FrontierEvent::FlushChanges);
+                    });
+                }
+                if !app_state.pending_changes.contains(&path) { app_state.pending_changes.push(path); }
             }
-            Event::UserEvent(FrontierEvent::RunCommand(wid, cmd_str)) => {
-                let sys = app_state.system.clone();
+            Event::UserEvent(FrontierEvent::FlushChanges) => {
+                let paths = std::mem::take(&mut app_state.pending_changes);
+                apply_file_changes(&app_state, paths);
+            }
+            Event::UserEvent(FrontierEvent::RunCommand(wid, call)) => {
+                let trigger = call.trigger.clone();
+                let args = call.args_text();
+                let callback = call.callback;
+                let owner = format!("{:?}", wid);
+                // The lock is only held to copy what the job needs, never while it runs
+                let (job, stream) = {
+                    let sys = app_state.system.lock().unwrap();
+                    (system::BackendJob::for_call(&sys, call).map(|job| job.in_window(&inspect::window_label(&owner))), sys.backend.stream)
+                };
                 let proxy = app_state.main_proxy.clone();
-                thread::spawn(move || {
-                    let mut parts = cmd_str.splitn(2, '|');
-                    let trigger = parts.next().unwrap_or("");
-                    let args = parts.next().unwrap_or("");
-                    let res = system::execute_backend(&sys.lock().unwrap(), trigger, args);
-                    let _ = proxy.send_event(FrontierEvent::BackendReply(wid, res));
+                let (job_id, cancel) = app_state.jobs.lock().unwrap().start(&owner, &trigger, callback);
+                let jobs = app_state.jobs.clone();
+                let on_line: Option<system::LineSink> = stream.then(|| {
+                    let proxy = proxy.clone();
+                    let trigger = trigger.clone();
+                    Box::new(move |line: &str| {
+                        // State patches apply as they are printed, and aren't output
+                        let (line, patches) = shared::take_patches(line);
+                        if !patches.is_empty() {
+                            let _ = proxy.send_event(FrontierEvent::StatePatch(patches));
+                            return;
+                        }
+                        let _ = proxy.send_event(FrontierEvent::BackendOutput(wid, trigger.clone(), line, callback));
+                    }) as system::LineSink
                 });
+                app_state.runtime.spawn(async move {
+                    let started = Instant::now();
+                    let res = match job {
+                        Ok(job) => system::run_backend(job, cancel, on_line).await,
+                        Err(res) => res,
+                    };
+                    jobs.lock().unwrap().finish(job_id);
+                    if inspect::tracing_ipc() {
+                        let detail = res.error.as_deref().unwrap_or(&args);
+                        inspect::record_ipc(&inspect::window_label(&owner), "backend", &trigger, detail, Some(started.elapsed().as_millis()), res.error.is_some());
+                    }
+                    if let Some(err) = &res.error {
+                        warn!(target: "ipc", "❌ [IPC] {}", err);
+                        if is_dev { let _ = proxy.send_event(FrontierEvent::DevError(wid, "Backend".into(), err.clone())); }
+                    }
+                    let (reply, patches) = shared::take_patches(&res.reply);
+                    // Streamed patches were applied line by line
+                    if !patches.is_empty() && !stream {
+                        let _ = proxy.send_event(FrontierEvent::StatePatch(patches));
+                    }
+                    let _ = match res.binary {
+                        Some(bytes) => proxy.send_event(FrontierEvent::BackendData(wid, trigger, bytes, callback)),
+                        None => {
+                            let code = res.code();
+                            let error = res.error.or_else(|| res.cancelled.then(|| reply.clone()));
+                            let callback = callback.map(|id| envelope::Callback {
+                                id, error, code, stderr: res.stderr, exit_code: res.exit_code, duration_ms: Some(started.elapsed().as_millis()),
+                            });
+                            proxy.send_event(FrontierEvent::BackendReply(wid, reply, callback))
+                        }
+                    };
+                });
+            }
+            Event::UserEvent(FrontierEvent::SessionStart(wid, id, call)) => {
+                let owner = format!("{:?}", wid);
+                let opened = app_state.jobs.lock().unwrap().open_session(&owner, &id);
+                let proxy = app_state.main_proxy.clone();
+                match opened {
+                    Some((input, kill)) => {
+                        info!(target: "ipc", "💬 [IPC] session {}: {} {}", id, call.trigger, call.args_text());
+                        let job = system::BackendJob::with_args(&app_state.system.lock().unwrap(), &call.trigger, call.args)
+                            .map(|job| job.in_window(&inspect::window_label(&owner)));
+                        let jobs = app_state.jobs.clone();
+                        let on_line: system::LineSink = {
+                            let (proxy, id) = (proxy.clone(), id.clone());
+                            Box::new(move |line: &str| {
+                                let (line, patches) = shared::take_patches(line);
+                                if !patches.is_empty() {
+                                    let _ = proxy.send_event(FrontierEvent::StatePatch(patches));
+                                    return;
+                                }
+                                let _ = proxy.send_event(FrontierEvent::SessionOutput(wid, id.clone(), line));
+                            })
+                        };
+                        app_state.runtime.spawn(async move {
+                            let end = match job {
+                                Ok(job) => system::run_session(job, input, kill.clone(), on_line).await,
+                                Err(res) => Err(res.reply),
+                            };
+                            jobs.lock().unwrap().close_session(&owner, &id, &kill);
+                            let payload = match end {
+                                Ok(code) => serde_json::json!({ "id": id, "exit": code, "error": null }),
+                                Err(e) => {
+                                    warn!(target: "ipc", "❌ [IPC] Session {}: {}", id, e);
+                                    serde_json::json!({ "id": id, "exit": null, "error": e })
+                                }
+                            };
+                            let _ = proxy.send_event(FrontierEvent::SessionEnd(wid, payload));
+                        });
+                    }
+                    None => {
+                        let error = format!("Session '{}' is already running", id);
+                        let _ = proxy.send_event(FrontierEvent::SessionEnd(wid, serde_json::json!({ "id": id, "exit": null, "error": error })));
+                    }
+                }
+            }
+            Event::UserEvent(FrontierEvent::SessionOutput(wid, id, line)) => {
+                if let Some(webview) = app_state.webviews.get(&wid) {
+                    let payload = serde_json::json!({ "id": id, "line": line });
+                    run_script(webview, &format!("if(window.Frontier) window.Frontier.dispatch('session-output', {})", payload), is_dev);
+                }
+            }
+            Event::UserEvent(FrontierEvent::SessionEnd(wid, payload)) => {
+                if let Some(webview) = app_state.webviews.get(&wid) {
+                    run_script(webview, &format!("if(window.Frontier) window.Frontier.dispatch('session-end', {})", payload), is_dev);
+                }
+            }
+            Event::UserEvent(FrontierEvent::BackendOutput(wid, trigger, line, callback)) => {
+                if let Some(webview) = app_state.webviews.get(&wid) {
+                    let mut payload = serde_json::json!({ "trigger": trigger, "line": line });
+                    if let Some(id) = callback { payload["callbackId"] = id.into(); }
+                    let js = format!("if(window.Frontier) window.Frontier.dispatch('output', {})", payload);
+                    run_script(webview, &js, is_dev);
+                }
             }
-            Event::UserEvent(FrontierEvent::BackendReply(wid, msg)) => {
+            Event::UserEvent(FrontierEvent::BackendReply(wid, msg, Some(callback))) => {
                 if let Some(webview) = app_state.webviews.get(&wid) {
+                    if inspect::tracing_ipc() { inspect::record_ipc(&inspect::window_label(&format!("{:?}", wid)), "reply", &format!("callback {}", callback.id), &msg, None, callback.error.is_some()); }
+                    let result = serde_json::json!({
+                        "reply": msg,
+                        "error": callback.error,
+                        "code": callback.code,
+                        "stderr": callback.stderr,
+                        "exitCode": callback.exit_code,
+                        "durationMs": callback.duration_ms,
+                    });
+                    run_script(webview, &format!("if(window.Frontier && window.Frontier.__callback) window.Frontier.__callback({}, {})", callback.id, result), is_dev);
+                }
+            }
+            Event::UserEvent(FrontierEvent::BackendReply(wid, msg, None)) => {
+                if let Some(webview) = app_state.webviews.get(&wid) {
+                    if inspect::tracing_ipc() { inspect::record_ipc(&inspect::window_label(&format!("{:?}", wid)), "reply", "log", &msg, None, false); }
                     let safe = msg.replace('\\', "\\\\").replace('`', "\\`").replace('\'', "\\'");
                     let js = format!("if(window.Frontier) window.Frontier.dispatch('log', `{}`)", safe);
-                    let _ = webview.evaluate_script(&js);
+                    run_script(webview, &js, is_dev);
+                }
+            }
+            Event::UserEvent(FrontierEvent::BackendData(wid, trigger, bytes, callback)) => {
+                if let Some(webview) = app_state.webviews.get(&wid) {
+                    let owner = format!("{:?}", wid);
+                    if inspect::tracing_ipc() { inspect::record_ipc(&inspect::window_label(&owner), "reply", &trigger, &format!("<{} bytes of MessagePack>", bytes.len()), None, false); }
+                    // The page fetches the bytes from __reply/<id> (see payload.rs)
+                    let id = payload::store(&owner, bytes);
+                    let callback = callback.map_or("null".to_string(), |id| id.to_string());
+                    let js = format!("if(window.Frontier && window.Frontier.__reply) window.Frontier.__reply({}, {}, {})", serde_json::to_string(&trigger).unwrap_or_default(), id, callback);
+                    run_script(webview, &js, is_dev);
+                }
+            }
+            Event::UserEvent(FrontierEvent::DevError(wid, source, message)) => {
+                if let Some(webview) = app_state.webviews.get(&wid) {
+                    show_dev_error(webview, &source, &message);
+                }
+            }
+            Event::UserEvent(FrontierEvent::Accessibility(wid, settings)) => {
+                let json = serde_json::to_string(&settings).unwrap_or_default();
+                app_state.system.lock().unwrap().os_accessibility = settings;
+                if let Some(webview) = app_state.webviews.get(&wid) {
+                    run_script(webview, &format!("if(window.Frontier && window.Frontier.__accessibility) window.Frontier.__accessibility({})", json), is_dev);
                 }
             }
-            Event::UserEvent(FrontierEvent::OpenWindow(req)) => {
+            Event::UserEvent(FrontierEvent::State(wid, request)) => match request {
+                StateRequest::Get(id, path) => {
+                    let value = shared::get(&app_state.system.lock().unwrap().shared_state, &path);
+                    if let Some(webview) = app_state.webviews.get(&wid) {
+                        run_script(webview, &format!("if(window.Frontier && window.Frontier.__state) window.Frontier.__state({}, {})", serde_json::to_string(&id).unwrap_or_default(), value), is_dev);
+                    }
+                }
+                StateRequest::Set(path, value) => {
+                    let change = shared::set(&mut app_state.system.lock().unwrap().shared_state, &path, value);
+                    broadcast_state(&app_state, change.into_iter().collect());
+                }
+                StateRequest::Subscribe(true) => { app_state.state_subscribers.insert(wid); }
+                StateRequest::Subscribe(false) => { app_state.state_subscribers.remove(&wid); }
+            },
+            Event::UserEvent(FrontierEvent::StatePatch(patches)) => {
+                let changes = {
+                    let mut sys = app_state.system.lock().unwrap();
+                    patches.into_iter().flat_map(|patch| shared::merge(&mut sys.shared_state, patch)).collect()
+                };
+                broadcast_state(&app_state, changes);
+            }
+            Event::UserEvent(FrontierEvent::Layout(request)) => match request {
+                LayoutRequest::Apply(name) => apply_layout(event_loop, &mut app_state, &mut web_contexts, &name),
+                LayoutRequest::Save(name) => {
+                    let windows = current_layout(event_loop, &app_state);
+                    store_layout(&app_state, &name, windows);
+                }
+                LayoutRequest::Define(name, windows) => store_layout(&app_state, &name, windows),
+            },
+            Event::UserEvent(FrontierEvent::Dialog(wid, request)) => match request {
+                DialogRequest::Open(id, page, data) => {
+                    if let Some((reason, limit)) = open_refusal(&mut app_state, Some(wid)) {
+                        refuse_open(&app_state, Some(wid), &page, reason, limit);
+                        resolve_dialog(&app_state, wid, &id, serde_json::Value::Null);
+                        return;
+                    }
+                    let before = app_state.session.len();
+                    let proxy = main_proxy.clone();
+                    match create_new_window(event_loop, &mut app_state, &mut web_contexts, &page, proxy) {
+                        Ok(()) => {
+                            if let Some(&(dialog, _)) = app_state.session.get(before) {
+                                app_state.dialogs.insert(dialog, DialogWindow { opener: wid, id, data });
+                                // Dialogs end with their opener's request, so sessions leave them out
+                                save_session(&app_state);
+                            }
+                        }
+                        Err(e) => {
+                            warn!(target: "window", "⚠️ [WINDOW] Cannot open dialog {}: {}", page, e);
+                            resolve_dialog(&app_state, wid, &id, serde_json::Value::Null);
+                        }
+                    }
+                }
+                // macOS panels need the main thread
+                DialogRequest::File(id, request) if cfg!(target_os = "macos") => {
+                    let chosen = filedialog::show(&request);
+                    resolve_dialog(&app_state, wid, &id, chosen);
+                }
+                DialogRequest::File(id, request) => {
+                    let proxy = main_proxy.clone();
+                    std::thread::spawn(move || {
+                        let chosen = filedialog::show(&request);
+                        let _ = proxy.send_event(FrontierEvent::Dialog(wid, DialogRequest::Picked(id, chosen)));
+                    });
+                }
+                DialogRequest::Picked(id, chosen) => resolve_dialog(&app_state, wid, &id, chosen),
+                DialogRequest::Data(id) => {
+                    let data = app_state.dialogs.get(&wid).map(|d| d.data.clone()).unwrap_or_default();
+                    resolve_dialog(&app_state, wid, &id, data);
+                }
+                DialogRequest::Close(value) => {
+                    finish_dialog(&mut app_state, wid, value);
+                    close_window(&mut app_state, &mut web_contexts, wid);
+                    if app_state.webviews.is_empty() && !keep_running { *control_flow = ControlFlow::Exit; }
+                }
+            },
+            Event::UserEvent(FrontierEvent::Notify(wid, mut request)) => {
+                let (app, icon) = {
+                    let sys = app_state.system.lock().unwrap();
+                    let icon = request.icon.as_ref().and_then(|icon| frontend_file(&sys.base_dir, sys.is_dev, icon)).filter(|p| p.is_file());
+                    (config::load_config(&sys.base_dir.join("frontier.toml")), icon)
+                };
+                if let Some(path) = request.icon.as_ref().filter(|_| icon.is_none()) {
+                    warn!(target: "app", "⚠️ [NOTIFY] Notification icon not found: {}", path);
+                }
+                request.icon = icon.map(|p| p.to_string_lossy().to_string());
+                if request.click || request.actions.iter().any(|action| action.open.is_none() && action.trigger.is_none()) {
+                    app_state.notifications.insert(request.tag.clone(), wid);
+                }
+                notification::show(app.name.as_deref().unwrap_or("App"), &request);
+            }
+            Event::UserEvent(FrontierEvent::CloseSplash(wid)) => close_splash(&mut app_state, wid),
+            Event::UserEvent(FrontierEvent::CloseGuard(wid, on)) => {
+                if on { app_state.close_guards.insert(wid); } else { app_state.close_guards.remove(&wid); }
+            }
+            Event::UserEvent(FrontierEvent::CloseReply(wid, ask, reply)) => {
+                answer_close(&mut app_state, &mut web_contexts, wid, ask, reply);
+                if app_state.webviews.is_empty() && !keep_running { *control_flow = ControlFlow::Exit; }
+            }
+            Event::UserEvent(FrontierEvent::Monitors(wid, id)) => {
+                let Some(webview) = app_state.webviews.get(&wid) else { return };
+                let list = monitor_list(event_loop, webview.window());
+                resolve_dialog(&app_state, wid, &id, list);
+            }
+            Event::UserEvent(FrontierEvent::Store(wid, id, request)) => {
+                let reply = app_state.store.answer(&inspect::window_label(&format!("{:?}", wid)), &request);
+                resolve_dialog(&app_state, wid, &id, reply);
+            }
+            Event::UserEvent(FrontierEvent::WindowControl(wid, WindowCommand::Close)) => {
+                request_close(&mut app_state, &mut web_contexts, wid, close_to_tray);
+                if app_state.webviews.is_empty() && !keep_running { *control_flow = ControlFlow::Exit; }
+            }
+            Event::UserEvent(FrontierEvent::WindowControl(wid, command)) => {
+                if let Some(webview) = app_state.webviews.get(&wid) {
+                    control_window(webview.window(), command);
+                }
+            }
+            Event::UserEvent(FrontierEvent::Devtools(wid, action)) => {
+                if let Some(webview) = app_state.webviews.get(&wid) {
+                    match action.as_str() {
+                        "open" => webview.open_devtools(),
+                        "close" => webview.close_devtools(),
+                        _ if webview.is_devtools_open() => webview.close_devtools(),
+                        _ => webview.open_devtools(),
+                    }
+                }
+            }
+            Event::UserEvent(FrontierEvent::OpenWindow(source, req)) => {
+                if let Some((reason, limit)) = open_refusal(&mut app_state, source) {
+                    refuse_open(&app_state, source, &req, reason, limit);
+                    return;
+                }
                 let proxy = main_proxy.clone(); 
-                let _ = create_new_window(event_loop, &mut app_state, &mut web_context, &req, proxy);
+                let _ = create_new_window(event_loop, &mut app_state, &mut web_contexts, &req, proxy);
+                schedule_prewarm(&app_state);
+            }
+            Event::UserEvent(FrontierEvent::Prewarm) => {
+                prewarm_window(event_loop, &mut app_state, &mut web_contexts);
+            }
+            // Dock icon clicked with no window open: start over from the startup pages
+            Event::UserEvent(FrontierEvent::Reopen) if app_state.webviews.is_empty() => {
+                show_app(event_loop, &mut app_state, &mut web_contexts);
+            }
+            Event::UserEvent(FrontierEvent::Launch(launch)) => {
+                info!(target: "app", "📂 [APP] Opening {} file(s), {} task(s)", launch.files.len(), launch.tasks.len());
+                if !launch.args.is_empty() || !launch.files.is_empty() {
+                    app_state.launch_events.push(serde_json::json!({ "args": launch.args, "files": launch.files }));
+                }
+                app_state.opened_files.extend(launch.files);
+                app_state.launch_tasks.extend(launch.tasks);
+                // On macOS the app may be running without windows
+                if app_state.webviews.is_empty() {
+                    let startup = startup_windows(&app_state.system.lock().unwrap());
+                    let proxy = app_state.main_proxy.clone();
+                    if let Err(e) = open_startup_windows(event_loop, &mut app_state, &mut web_contexts, &startup, proxy) {
+                        error!(target: "window", "❌ [WINDOW] Cannot open a window for the files: {}", e);
+                    }
+                }
+                deliver_launch(&mut app_state);
+            }
+            Event::UserEvent(FrontierEvent::JumpList(tasks)) => {
+                let sys = app_state.system.lock().unwrap();
+                if sys.is_dev {
+                    // Tasks would start the dev build without --dev
+                    debug!(target: "app", "📌 [APP] Jump list left unchanged in dev mode ({} task(s))", tasks.len());
+                } else {
+                    let recent = config::load_jump_list_config(&sys.base_dir.join("frontier.toml")).and_then(|j| j.recent).unwrap_or(true);
+                    jumplist::set(tasks, recent);
+                }
+            }
+            Event::UserEvent(FrontierEvent::Automation(request)) => {
+                match request.command.as_str() {
+                    "open" => match request.params["page"].as_str().map(str::to_string) {
+                        Some(page) => {
+                            let _ = app_state.main_proxy.send_event(FrontierEvent::OpenWindow(None, page));
+                            request.respond(Ok(serde_json::Value::Bool(true)));
+                        }
+                        None => request.respond(Err("'page' is required".into())),
+                    },
+                    // Runs like a page's trigger, without a page to reply to
+                    "trigger" => {
+                        let Some(trigger) = request.params["trigger"].as_str().map(str::to_string) else {
+                            request.respond(Err("'trigger' is required".into()));
+                            return;
+                        };
+                        let job = system::BackendJob::new(&app_state.system.lock().unwrap(), &trigger, request.params["args"].as_str().unwrap_or(""));
+                        let proxy = app_state.main_proxy.clone();
+                        app_state.runtime.spawn(async move {
+                            let res = match job {
+                                Ok(job) => system::run_backend(job, Arc::new(tokio::sync::Notify::new()), None).await,
+                                Err(res) => res,
+                            };
+                            let (reply, patches) = shared::take_patches(&res.reply);
+                            if !patches.is_empty() { let _ = proxy.send_event(FrontierEvent::StatePatch(patches)); }
+                            request.respond(Ok(serde_json::json!({ "reply": reply, "error": res.error })));
+                        });
+                    }
+                    "quit" => {
+                        request.respond(Ok(serde_json::Value::Bool(true)));
+                        *control_flow = ControlFlow::Exit;
+                    }
+                    _ => match automation_target(&app_state, request.window()) {
+                        Some(webview) => {
+                            if let Some(js) = request.into_page_script() { run_script(webview, &js, false); }
+                        }
+                        None => {
+                            let name = request.window().unwrap_or("(first window)").to_string();
+                            request.respond(Err(format!("No open window '{}'", name)));
+                        }
+                    },
+                }
+            }
+            Event::UserEvent(FrontierEvent::PageReady(wid)) => {
+                app_state.ready.insert(wid);
+                // Pages that remember their view report it; the first one gets the saved view back
+                if app_state.persistence.get(&wid).is_some_and(|p| p.remember_view) {
+                    let view = if app_state.unrestored.remove(&wid) { app_state.views.get(&wid).cloned().unwrap_or_default() } else { window::PageView::default() };
+                    if let Some(webview) = app_state.webviews.get(&wid) {
+                        let json = serde_json::to_string(&view).unwrap_or_else(|_| "{}".into());
+                        run_script(webview, &format!("if(window.Frontier && window.Frontier.__view) window.Frontier.__view({})", json), is_dev);
+                    }
+                }
+                deliver_launch(&mut app_state);
+            }
+            Event::UserEvent(FrontierEvent::Zoom(wid, factor)) => {
+                let accessibility = page_zoom(&app_state.system.lock().unwrap());
+                if let Some(webview) = app_state.webviews.get(&wid) { webview.zoom(factor * accessibility); }
+                if let Some(view) = app_state.views.get_mut(&wid) { view.zoom = Some(factor); }
+            }
+            Event::UserEvent(FrontierEvent::Scroll(wid, position)) => {
+                if let Some(view) = app_state.views.get_mut(&wid) { view.scroll = Some(position); }
+            }
+            Event::LoopDestroyed => {
+                // Quitting from the menu (Cmd+Q) ends the loop without closing each window
+                for wid in app_state.webviews.keys() { save_window_state(wid, &app_state); }
+                if !app_state.webviews.is_empty() { save_session(&app_state); }
+                if let Some(trigger) = &lifecycle.on_exit { run_lifecycle_hook(&app_state, "on_exit", trigger, ""); }
+                services.stop();
+                stop_backends(&app_state);
+                perf::write_trace();
             }
             Event::WindowEvent { event, window_id, .. } => match event {
+                WindowEvent::Moved(_) | WindowEvent::Resized(_) => track_bounds(&mut app_state, window_id),
+                // Closing the splash screen shows the app as it is
+                WindowEvent::CloseRequested if app_state.splash.as_ref().is_some_and(|s| s.webview.window().id() == window_id) => {
+                    close_splash(&mut app_state, None);
+                }
                 WindowEvent::CloseRequested => {
-                    save_window_state(&window_id, &app_state);
-                    app_state.webviews.remove(&window_id);
-                    app_state.persistence.remove(&window_id);
-                    if app_state.webviews.is_empty() { *control_flow = ControlFlow::Exit; }
+                    request_close(&mut app_state, &mut web_contexts, window_id, close_to_tray);
+                    if app_state.webviews.is_empty() && !keep_running { *control_flow = ControlFlow::Exit; }
                 }
                 _ => {}
             },
@@ -180,19 +952,374 @@ fn run_application() -> Result<(), Box<dyn std::error::Error>> {
     });
 }
 
+// Bring the open windows forward, or start over from the startup pages when none is open
+fn show_app(event_loop: &EventLoopWindowTarget<FrontierEvent>, app_state: &mut AppState, web_contexts: &mut WebContexts) {
+    if app_state.webviews.is_empty() {
+        let startup = startup_windows(&app_state.system.lock().unwrap());
+        let proxy = app_state.main_proxy.clone();
+        if let Err(e) = open_startup_windows(event_loop, app_state, web_contexts, &startup, proxy) {
+            error!(target: "window", "❌ [WINDOW] Cannot reopen: {}", e);
+        }
+        schedule_prewarm(app_state);
+        return;
+    }
+    for webview in app_state.webviews.values() {
+        let window = webview.window();
+        window.set_minimized(false);
+        window.set_visible(true);
+        window.set_focus();
+    }
+}
+
+// A close button click (or Frontier.window.close()): [tray] close_to_tray keeps the
+// page running in a hidden window, which the tray shows again; dialogs still close.
+// Pages with onCloseRequested handlers are asked first
+fn request_close(app_state: &mut AppState, web_contexts: &mut WebContexts, window_id: WindowId, close_to_tray: bool) {
+    if close_to_tray && !app_state.dialogs.contains_key(&window_id) {
+        if let Some(webview) = app_state.webviews.get(&window_id) {
+            webview.window().set_visible(false);
+            debug!(target: "window", "📌 [TRAY] Window {:?} hidden in the tray", window_id);
+        }
+        return;
+    }
+    if app_state.close_guards.contains(&window_id) {
+        ask_close(app_state, window_id);
+        return;
+    }
+    // A dialog closed by the user returns null
+    finish_dialog(app_state, window_id, serde_json::Value::Null);
+    close_window(app_state, web_contexts, window_id);
+}
+
+// Ask the page's onCloseRequested handlers whether its window may close. A page that
+// doesn't respond within close_timeout seconds (hung, or navigated away) is closed anyway
+fn ask_close(app_state: &mut AppState, window_id: WindowId) {
+    // Clicking close again while the page decides changes nothing
+    if app_state.closing.contains_key(&window_id) { return; }
+    let Some(webview) = app_state.webviews.get(&window_id) else { return };
+    let (is_dev, timeout) = {
+        let sys = app_state.system.lock().unwrap();
+        (sys.is_dev, sys.window_defaults.close_timeout.unwrap_or(DEFAULT_CLOSE_TIMEOUT))
+    };
+    app_state.close_asks += 1;
+    let ask = app_state.close_asks;
+    app_state.closing.insert(window_id, CloseAsk { id: ask, handling: false });
+    run_script(webview, &format!("if(window.Frontier && window.Frontier.__closeRequested) window.Frontier.__closeRequested({})", ask), is_dev);
+    let proxy = app_state.main_proxy.clone();
+    app_state.runtime.spawn(async move {
+        tokio::time::sleep(Duration::from_secs(timeout)).await;
+        let _ = proxy.send_event(FrontierEvent::CloseReply(window_id, ask, CloseReply::TimedOut));
+    });
+}
+
+fn answer_close(app_state: &mut AppState, web_contexts: &mut WebContexts, window_id: WindowId, ask: u64, reply: CloseReply) {
+    let Some(pending) = app_state.closing.get_mut(&window_id).filter(|pending| pending.id == ask) else { return };
+    match reply {
+        CloseReply::Handling => { pending.handling = true; return; }
+        CloseReply::TimedOut if pending.handling => return,
+        CloseReply::TimedOut => warn!(target: "window", "⚠️ [WINDOW] Page of window {:?} didn't answer the close request; closing", window_id),
+        CloseReply::Stay => debug!(target: "window", "📄 [WINDOW] Window {:?} kept open by its page", window_id),
+        CloseReply::Close => {}
+    }
+    app_state.closing.remove(&window_id);
+    if matches!(reply, CloseReply::Stay) { return; }
+    finish_dialog(app_state, window_id, serde_json::Value::Null);
+    close_window(app_state, web_contexts, window_id);
+}
+
+fn control_window(window: &Window, command: WindowCommand) {
+    match command {
+        WindowCommand::Minimize => window.set_minimized(true),
+        WindowCommand::Maximize => window.set_maximized(true),
+        WindowCommand::Unmaximize => window.set_maximized(false),
+        WindowCommand::ToggleMaximize => window.set_maximized(!window.is_maximized()),
+        WindowCommand::Focus => {
+            window.set_minimized(false);
+            window.set_visible(true);
+            window.set_focus();
+        }
+        // On the monitor the window is on
+        WindowCommand::Center => {
+            let Some(monitor) = window.current_monitor() else { return };
+            let (area, origin, size) = (monitor.size(), monitor.position(), window.outer_size());
+            let x = origin.x + (area.width as i32 - size.width as i32) / 2;
+            let y = origin.y + (area.height as i32 - size.height as i32) / 2;
+            window.set_outer_position(PhysicalPosition::new(x, y));
+        }
+        WindowCommand::SetTitle(title) => window.set_title(&title),
+        WindowCommand::SetSize(width, height) => window.set_inner_size(LogicalSize::new(width, height)),
+        WindowCommand::Close => {} // request_close, which needs the app state
+    }
+}
+
+fn close_window(app_state: &mut AppState, web_contexts: &mut WebContexts, window_id: WindowId) {
+    save_window_state(&window_id, app_state);
+    // Closing the last window ends the session, so it is saved with the window in it
+    if app_state.webviews.len() == 1 { save_session(app_state); }
+    app_state.webviews.remove(&window_id);
+    app_state.bounds.remove(&window_id);
+    app_state.persistence.remove(&window_id);
+    app_state.dependencies.remove(&window_id);
+    web_contexts.forget(window_id);
+    app_state.session.retain(|(wid, _)| *wid != window_id);
+    app_state.ready.remove(&window_id);
+    app_state.state_subscribers.remove(&window_id);
+    app_state.views.remove(&window_id);
+    app_state.unrestored.remove(&window_id);
+    app_state.opens.remove(&window_id);
+    app_state.close_guards.remove(&window_id);
+    app_state.closing.remove(&window_id);
+    app_state.jobs.lock().unwrap().cancel(&format!("{:?}", window_id), None);
+    app_state.jobs.lock().unwrap().kill_sessions(&format!("{:?}", window_id));
+    inspect::window_closed(&format!("{:?}", window_id));
+    inspect::record_event("window-closed", &format!("{:?}", window_id));
+    if !app_state.webviews.is_empty() { save_session(app_state); }
+}
+
+// [window] max_windows and max_opens_per_minute: why a window may not open another
+// one now, and the limit (an open that is allowed counts towards the rate)
+fn open_refusal(app_state: &mut AppState, source: Option<WindowId>) -> Option<(&'static str, usize)> {
+    let (max_windows, max_rate) = {
+        let sys = app_state.system.lock().unwrap();
+        (sys.window_defaults.max_windows, sys.window_defaults.max_opens_per_minute)
+    };
+    if let Some(max) = max_windows.filter(|max| app_state.webviews.len() >= *max) {
+        return Some(("max_windows", max));
+    }
+    if let (Some(max), Some(source)) = (max_rate, source) {
+        let now = Instant::now();
+        let opens = app_state.opens.entry(source).or_default();
+        while opens.front().is_some_and(|t| now.duration_since(*t) >= OPEN_RATE_PERIOD) { opens.pop_front(); }
+        if opens.len() >= max { return Some(("rate", max)); }
+        opens.push_back(now);
+    }
+    None
+}
+
+// Log a refused open and tell the page that asked for it ('open-refused')
+fn refuse_open(app_state: &AppState, source: Option<WindowId>, page: &str, reason: &str, limit: usize) {
+    warn!(target: "window", "🚫 [WINDOW] Not opening {}: {} limit of {} reached", page, reason, limit);
+    let Some(webview) = source.and_then(|wid| app_state.webviews.get(&wid)) else { return };
+    let is_dev = app_state.system.lock().unwrap().is_dev;
+    let detail = serde_json::json!({ "page": page, "reason": reason, "limit": limit });
+    run_script(webview, &format!("if(window.Frontier) window.Frontier.dispatch('open-refused', {})", detail), is_dev);
+}
+
+// Answer a Frontier.openDialog, Frontier.dialogData, Frontier.monitors.list or Frontier.store request of a window
+fn resolve_dialog(app_state: &AppState, wid: WindowId, id: &str, value: serde_json::Value) {
+    let Some(webview) = app_state.webviews.get(&wid) else { return };
+    let is_dev = app_state.system.lock().unwrap().is_dev;
+    run_script(webview, &format!("if(window.Frontier && window.Frontier.__dialog) window.Frontier.__dialog({}, {})", serde_json::to_string(id).unwrap_or_default(), value), is_dev);
+}
+
+// Hand a dialog's result to its opener (once; an opener that has closed gets nothing)
+fn finish_dialog(app_state: &mut AppState, wid: WindowId, result: serde_json::Value) {
+    if let Some(dialog) = app_state.dialogs.remove(&wid) {
+        debug!(target: "window", "📄 [WINDOW] Dialog {:?} closed", wid);
+        resolve_dialog(app_state, dialog.opener, &dialog.id, result);
+    }
+}
+
+// Apply a batch of file changes: config is reloaded once, each changed backend
+// trigger is announced once and every window reloads (or swaps stylesheets) at most once
+fn apply_file_changes(app_state: &AppState, paths: Vec<PathBuf>) {
+    // Stylesheets and images are swapped in place; anything else reloads the page
+    enum Refresh { InPlace(Vec<String>), Reload }
+
+    let (app_dir, frontend_dir, backend_dir, locales_dir, external_frontend) = {
+        let sys = app_state.system.lock().unwrap();
+        let i18n_cfg = config::load_i18n_config(&sys.base_dir.join("frontier.toml"));
+        let app_dir = sys.base_dir.join("app");
+        (
+            app_dir.clone(),
+            app_dir.join("frontend"),
+            app_dir.join("backend"),
+            i18n::locales_dir(&sys.base_dir, &i18n_cfg, true),
+            sys.dev_frontend_url.is_some(),
+        )
+    };
+
+    let mut config_changed = false;
+    let mut locales_changed = false;
+    let mut reload_all = false;
+    let mut backends: Vec<&PathBuf> = Vec::new();
+    let mut refresh: HashMap<WindowId, Refresh> = HashMap::new();
+
+    for path in &paths {
+        inspect::record_event("file-changed", &path.display().to_string());
+        if is_config_file(path) {
+            config_changed = true;
+            continue;
+        }
+        if !path.starts_with(&app_dir) { continue; }
+        // The external dev server does its own HMR for frontend files
+        if external_frontend && path.starts_with(&frontend_dir) { continue; }
+        // Backend scripts don't need a page reload (which would lose state);
+        // pages get a 'backend-changed' event and decide what to refetch
+        if path.starts_with(&backend_dir) {
+            backends.push(path);
+        // Locale files are re-read and every window picks up the new strings
+        } else if path.starts_with(&locales_dir) {
+            locales_changed = true;
+        // Frontend files only affect the windows that loaded them; stylesheets
+        // and images are swapped in place so the page keeps its state
+        } else if let Ok(rel) = path.strip_prefix(&frontend_dir) {
+            let rel = rel.to_string_lossy().replace('\\', "/");
+            let in_place = path.extension().and_then(|e| e.to_str()).is_some_and(|e| {
+                matches!(e.to_ascii_lowercase().as_str(), "css" | "png" | "jpg" | "jpeg" | "gif" | "svg" | "webp" | "avif" | "ico")
+            });
+            for wid in app_state.webviews.keys() {
+                let uses_file = app_state.dependencies.get(wid).is_some_and(|deps| deps.lock().unwrap().contains(&rel));
+                if !uses_file { continue; }
+                match refresh.entry(*wid).or_insert_with(|| Refresh::InPlace(Vec::new())) {
+                    Refresh::InPlace(files) if in_place => { if !files.contains(&rel) { files.push(rel.clone()); } }
+                    entry => *entry = Refresh::Reload,
+                }
+            }
+        } else {
+            reload_all = true;
+        }
+    }
+
+    if config_changed { reload_runtime_config(app_state); }
+    // Several files of one trigger (script + meta) announce the trigger once
+    let mut announced = HashSet::new();
+    for path in backends {
+        let name = path.file_stem().and_then(|s| s.to_str()).unwrap_or("");
+        let trigger = name.split('.').next().unwrap_or(name).to_string();
+        if announced.insert(trigger) { backend_changed(app_state, path); }
+    }
+    if locales_changed {
+        let mut sys = app_state.system.lock().unwrap();
+        sys.i18n = load_i18n(&sys.base_dir, true);
+        reload_all = true;
+    }
+
+    if paths.len() > 1 {
+        debug!(target: "window", "🔄 [WINDOW] {} file changes applied in one refresh", paths.len());
+    }
+    for (wid, webview) in &app_state.webviews {
+        let js = match refresh.get(wid) {
+            _ if reload_all => "location.reload();".to_string(),
+            Some(Refresh::Reload) => "location.reload();".to_string(),
+            Some(Refresh::InPlace(files)) => files.iter().map(|rel| {
+                if rel.to_ascii_lowercase().ends_with(".css") { css_reload_script(rel) } else { image_reload_script(rel) }
+            }).collect::<Vec<_>>().join("\n"),
+            None => continue,
+        };
+        let _ = webview.evaluate_script(&js);
+    }
+}
+
 fn create_new_window(
     event_loop: &EventLoopWindowTarget<FrontierEvent>,
     app_state: &mut AppState,
-    context: &mut WebContext,
+    contexts: &mut WebContexts,
     request: &str,
     proxy: EventLoopProxy<FrontierEvent>,
 ) -> Result<(), Box<dyn std::error::Error>> {
-    let sys = app_state.system.lock().unwrap();
-    let wl_internal_global = sys.allowed_internal.clone();
-    let wl_browser_global = sys.allowed_browser.clone();
-    let sys_is_dev = sys.is_dev;
-    let sys_base = sys.base_dir.clone();
-    let sys_icon = sys.window_icon.clone();
+    let window_started = Instant::now();
+    let pages = PageContext::new(&app_state.system.lock().unwrap());
+    let setup = prepare_window(&pages, request, None, window_started)?;
+    open_window(event_loop, app_state, contexts, setup, proxy)
+}
+
+// Open the startup pages. Their HTML, configs, saved states, icons and
+// whitelists are prepared on worker threads, then the windows are created
+// back to back so every page starts loading without waiting for the others.
+fn open_startup_windows(
+    event_loop: &EventLoopWindowTarget<FrontierEvent>,
+    app_state: &mut AppState,
+    contexts: &mut WebContexts,
+    requests: &[window::SessionWindow],
+    proxy: EventLoopProxy<FrontierEvent>,
+) -> Result<(), FrontierError> {
+    let started = Instant::now();
+    let pages = PageContext::new(&app_state.system.lock().unwrap());
+    let setups: Vec<Result<WindowSetup, String>> = std::thread::scope(|scope| {
+        let handles: Vec<_> = requests.iter()
+            .map(|request| scope.spawn(|| prepare_window(&pages, &request.page, request.state.as_ref(), started)))
+            .collect();
+        handles.into_iter()
+            .map(|h| h.join().unwrap_or_else(|_| Err("window setup panicked".to_string())))
+            .collect()
+    });
+
+    let mut first_error = None;
+    for (request, setup) in requests.iter().zip(setups) {
+        // A page that can't be read is an asset error; a window that can't be created, a webview one
+        let opened = setup.map_err(FrontierError::Asset).and_then(|setup| {
+            open_window(event_loop, app_state, contexts, setup, proxy.clone()).map_err(|e| FrontierError::Webview(e.to_string()))
+        });
+        if let Err(e) = opened {
+            error!(target: "window", "❌ [WINDOW] Cannot open {}: {}", request.page, e);
+            first_error.get_or_insert(e);
+        }
+    }
+    if requests.len() > 1 {
+        info!(target: "window", "📄 [WINDOW] {} startup windows opened in {} ms", app_state.webviews.len(), started.elapsed().as_millis());
+    }
+    match first_error {
+        Some(e) if app_state.webviews.is_empty() => Err(e),
+        _ => Ok(()),
+    }
+}
+
+// The parts of SystemState needed to resolve a page (plain data, so pages can
+// be prepared on several threads at once)
+struct PageContext {
+    is_dev: bool,
+    base_dir: PathBuf,
+    data_dir: PathBuf,
+    scheme: String,
+    dev_frontend_url: Option<String>,
+    window_defaults: config::WindowConfig,
+    window_overrides: HashMap<String, config::WindowOverride>,
+    security: SharedSecurity,
+    headers: HashMap<String, String>,
+    spa_fallback: bool,
+}
+
+impl PageContext {
+    fn new(sys: &system::SystemState) -> Self {
+        PageContext {
+            is_dev: sys.is_dev,
+            base_dir: sys.base_dir.clone(),
+            data_dir: sys.data_dir.clone(),
+            scheme: sys.scheme.clone(),
+            dev_frontend_url: sys.dev_frontend_url.clone(),
+            window_defaults: sys.window_defaults.clone(),
+            window_overrides: sys.window_overrides.clone(),
+            security: sys.security.clone(),
+            headers: sys.network.headers.clone(),
+            spa_fallback: sys.frontend.spa_fallback,
+        }
+    }
+}
+
+// Everything needed to open a page that doesn't touch the event loop
+struct WindowSetup {
+    request: String,
+    target_url: String,
+    config: window::PageConfig,
+    policy: WindowPolicy,
+    headers: header::HeaderMap,
+    save_file: PathBuf,
+    size: (f64, f64),
+    maximized: bool,
+    saved_position: Option<(f64, f64)>,
+    saved_monitor: Option<String>, // Monitor the saved position is on
+    saved_view: window::PageView,  // Zoom and scroll, for frontier-remember-view pages
+    icon: Option<Icon>, // Page icon (frontier-icon); None uses the app icon
+}
+
+// `restored` is the window's geometry from the last session, which wins over
+// its state_<id>.json
+fn prepare_window(pages: &PageContext, request: &str, restored: Option<&window::WindowState>, window_started: Instant) -> Result<WindowSetup, String> {
+    let sys_is_dev = pages.is_dev;
+    let sys_base = &pages.base_dir;
+    // "report.html?item=42": the file is report.html, the query goes on the page's URL
+    let (page, params) = split_page_request(request);
 
     let (target_url, config) = if request.starts_with("spawn://") {
         let raw = request.replace("spawn://", "");
@@ -200,117 +1327,594 @@ fn create_new_window(
         let url = parts.next().unwrap_or("").to_string();
         let config_raw = parts.next().unwrap_or("");
         let manual_cfg = window::create_manual_config(&url, config_raw);
-        if sys_is_dev { eprintln!("📦 [SPAWN] {}", url); }
+        info!(target: "spawn", "📦 [SPAWN] {}", url);
         (url, manual_cfg)
+    } else if let Some(dev_url) = &pages.dev_frontend_url {
+        // Dev server mode: the page comes from the framework dev server, meta tags
+        // are still read from app/frontend when the file exists there
+        info!(target: "window", "📄 [WINDOW] {} (dev server)", request);
+        let html = fs::read_to_string(sys_base.join("app/frontend").join(page)).unwrap_or_default();
+        let url = format!("{}/{}{}", dev_url, page, params);
+        (url, window::parse_html_config(&html, page, &pages.window_defaults))
     } else {
-        if sys_is_dev { eprintln!("📄 [WINDOW] {}", request); }
-        let html = if sys_is_dev {
-            fs::read_to_string(sys_base.join("app/frontend").join(request))?
-        } else {
-            Assets::get(&format!("frontend/{}", request))
-                .map(|f| String::from_utf8_lossy(f.data.as_ref()).to_string())
-                .ok_or("404")?
+        info!(target: "window", "📄 [WINDOW] {}", request);
+        let read_page = |page: &str| -> Result<String, String> {
+            if sys_is_dev {
+                fs::read_to_string(sys_base.join("app/frontend").join(page)).map_err(|e| e.to_string())
+            } else {
+                embedded::file(&format!("frontend/{}", page))
+                    .map(|f| String::from_utf8_lossy(f.data.as_ref()).to_string())
+                    .ok_or_else(|| "404".to_string())
+            }
         };
-        // Use frontier://app/filename.html format (app is a fake host)
-        let url = format!("frontier://app/{}", request);
-        (url, window::parse_html_config(&html, request))
+        let dev_page = if sys_is_dev { inspect::page(page) } else { None };
+        let html = match dev_page {
+            Some(html) => html.to_string(),
+            // A client-side route ("open|settings/profile") loads the app's index.html
+            None => read_page(page).or_else(|e| if pages.spa_fallback && is_spa_route(page) { read_page("index.html") } else { Err(e) })?,
+        };
+        // Use <scheme>://app/filename.html format (app is a fake host)
+        let url = format!("{}://app/{}{}", pages.scheme, page, params);
+        (url, window::parse_html_config(&html, page, &pages.window_defaults))
+    };
+
+    // [windows."page"] sections in frontier.toml override the page's own config
+    let mut config = config;
+    let override_key = if request.starts_with("spawn://") { target_url.as_str() } else { page };
+    if let Some(ov) = pages.window_overrides.get(override_key) {
+        window::apply_window_override(&mut config, ov);
+    }
+
+    // The page's own additions; [security] and [security.windows.<id>] are shared
+    let mut page_internal = config.allowed_internal.clone();
+    if let Some(dev_url) = &pages.dev_frontend_url {
+        page_internal.push(format!("{}/*", dev_url));
+    }
+
+    let save_file = pages.data_dir.join(format!("state_{}.json", config.id));
+    let mut size = (config.width, config.height);
+    let mut maximized = config.maximized;
+    let mut saved_position = None;
+    let mut saved_monitor = None;
+    let mut saved_view = window::PageView::default();
+
+    let saved = match restored {
+        Some(state) => Some(state.clone()),
+        None if config.persistent => fs::read_to_string(&save_file).ok()
+            .and_then(|json| serde_json::from_str::<window::WindowState>(&json).ok()),
+        None => None,
+    };
+    if let Some(saved) = saved {
+        size = (saved.width, saved.height);
+        maximized = saved.maximized;
+        saved_position = Some((saved.x, saved.y));
+        saved_monitor = saved.monitor;
+        if config.remember_view { saved_view = saved.view; }
+    }
+
+    let icon = config.icon_path.as_ref()
+        .and_then(|ipath| frontend_file(sys_base, sys_is_dev, ipath))
+        .and_then(|p| load_icon_from_disk(&p));
+
+    // Whitelists are compiled once here; both routing handlers run on every navigation
+    let policy = WindowPolicy {
+        id: config.id.clone(),
+        initial_url: target_url.clone(),
+        security: pages.security.clone(),
+        ignore_global: config.ignore_global_security,
+        internal: UrlWhitelist::new(&page_internal),
+        browser: UrlWhitelist::new(&config.allowed_browser),
+        started: window_started,
+        painted: false,
     };
 
-    let (mut combined_internal, mut combined_browser) = if config.ignore_global_security {
-        (Vec::new(), Vec::new())
+    Ok(WindowSetup {
+        request: request.to_string(),
+        target_url,
+        config,
+        policy,
+        headers: build_header_map(&pages.headers),
+        save_file,
+        size,
+        maximized,
+        saved_position,
+        saved_monitor,
+        saved_view,
+        icon,
+    })
+}
+
+fn open_window(
+    event_loop: &EventLoopWindowTarget<FrontierEvent>,
+    app_state: &mut AppState,
+    contexts: &mut WebContexts,
+    setup: WindowSetup,
+    proxy: EventLoopProxy<FrontierEvent>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let WindowSetup { request, target_url, config, policy, headers: extra_headers, save_file, size, maximized: win_is_max, mut saved_position, saved_monitor, saved_view, icon } = setup;
+    let _span = info_span!("window", page = %request).entered();
+    let window_started = policy.started;
+    let (win_w, win_h) = size;
+    let (base, app_icon, zoom) = {
+        let sys = app_state.system.lock().unwrap();
+        (webview_base(&sys), sys.window_icon.clone(), page_zoom(&sys))
+    };
+    let current_icon = icon.or(app_icon);
+
+    // A position on a monitor that has been disconnected would put the window off screen
+    if let Some(name) = saved_monitor {
+        if !event_loop.available_monitors().any(|m| m.name().as_deref() == Some(name.as_str())) {
+            debug!(target: "window", "📄 [WINDOW] Monitor '{}' of {} is gone; using the default position", name, config.id);
+            saved_position = None;
+        }
+    }
+    // Formulas are relative to the page's monitor; without them a window sent to another
+    // monitor is centered on it
+    let mut position = None;
+    if !win_is_max {
+        let monitor = config.monitor.and_then(|i| {
+            let found = event_loop.available_monitors().nth(i);
+            if found.is_none() { debug!(target: "window", "📄 [WINDOW] No monitor {} for {}; using the primary one", i, config.id); }
+            found
+        });
+        let placed = config.monitor.is_some() || (config.x.is_some() && config.y.is_some());
+        if let Some((x, y)) = saved_position {
+            position = Some(LogicalPosition::new(x, y));
+        } else if let Some(mon) = monitor.or_else(|| event_loop.primary_monitor()).filter(|_| placed) {
+            let scale = mon.scale_factor();
+            let origin = mon.position().to_logical::<f64>(scale);
+            let s = mon.size().to_logical::<f64>(scale);
+            let monitors = monitor_sizes(event_loop);
+            let eval = |formula: &Option<String>, centered: f64| formula.as_deref()
+                .map_or(centered, |f| window::evaluate_math_expression(f, (s.width, s.height), (win_w, win_h), &monitors));
+            let px = eval(&config.x, (s.width - win_w) / 2.0);
+            let py = eval(&config.y, (s.height - win_h) / 2.0);
+            position = Some(LogicalPosition::new(origin.x + px, origin.y + py));
+        }
+    }
+
+    // A pre-warmed webview is only used when it was built with the current bridge
+    // script (locale or feature changes make it stale) and shares the default partition;
+    // transparency can only be set when a window is built
+    let warm = if config.partition.is_none() && !config.transparent { app_state.warm.take().filter(|w| w.base == base) } else { None };
+    let (webview, dependencies) = if let Some(warm) = warm {
+        let window = warm.webview.window();
+        window.set_title(&config.title);
+        window.set_inner_size(LogicalSize::new(win_w, win_h));
+        window.set_resizable(config.resizable);
+        window.set_minimizable(config.minimizable);
+        window.set_maximizable(config.maximizable);
+        window.set_min_inner_size(config.min_width.zip(config.min_height).map(|(w, h)| LogicalSize::new(w, h)));
+        window.set_max_inner_size(config.max_width.zip(config.max_height).map(|(w, h)| LogicalSize::new(w, h)));
+        window.set_window_icon(current_icon);
+        // Pre-warmed windows get the [[menu]] menus, which the page may hide
+        if !config.menu { window.set_menu(app_state.menu.bar(false)); }
+        window.set_decorations(config.decorations);
+        window.set_always_on_top(config.always_on_top);
+        set_skip_taskbar(window, config.skip_taskbar);
+        if let Some(pos) = position { window.set_outer_position(pos); }
+        window.set_maximized(win_is_max);
+        *warm.policy.lock().unwrap() = policy;
+        warm.webview.load_url_with_headers(&target_url, extra_headers);
+        if app_state.splash.is_none() {
+            window.set_visible(true);
+            window.set_focus();
+        }
+        debug!(target: "window", "🔥 [WINDOW] {} opened in the pre-warmed webview", config.id);
+        (warm.webview, warm.dependencies)
     } else {
-        (wl_internal_global, wl_browser_global)
+        let mut builder = window_builder(app_state.menu.bar(config.menu))
+            .with_title(&config.title)
+            .with_inner_size(LogicalSize::new(win_w, win_h))
+            .with_resizable(config.resizable)
+            .with_minimizable(config.minimizable)
+            .with_maximizable(config.maximizable)
+            .with_maximized(win_is_max)
+            .with_decorations(config.decorations)
+            .with_transparent(config.transparent)
+            .with_always_on_top(config.always_on_top)
+            .with_visible(app_state.splash.is_none())
+            .with_window_icon(current_icon);
+        builder = with_skip_taskbar(builder, config.skip_taskbar);
+
+        // Apply minimum window size constraints if specified
+        if let (Some(w), Some(h)) = (config.min_width, config.min_height) {
+            builder = builder.with_min_inner_size(LogicalSize::new(w, h));
+        }
+
+        // Apply maximum window size constraints if specified
+        if let (Some(w), Some(h)) = (config.max_width, config.max_height) {
+            builder = builder.with_max_inner_size(LogicalSize::new(w, h));
+        }
+
+        if let Some(pos) = position {
+            builder = builder.with_position(pos);
+        }
+
+        let window = builder.build(event_loop)?;
+        // Frontend files served to this window, so dev reloads can skip unrelated windows
+        let dependencies = Arc::new(Mutex::new(HashSet::new()));
+        let policy = Arc::new(Mutex::new(policy));
+        let served = contexts.served(config.partition.as_deref());
+        let webview = build_webview(window, contexts.get(config.partition.as_deref()), served, &base, policy, dependencies.clone(), &target_url, extra_headers, proxy, app_state.jobs.clone(), config.transparent)?;
+        (webview, dependencies)
     };
-    combined_internal.extend(config.allowed_internal.clone());
-    combined_browser.extend(config.allowed_browser.clone());
+    let wid = webview.window().id();
+    if let Some(splash) = app_state.splash.as_mut() { splash.hidden.push(wid); }
+    // [accessibility] text scaling and the page's remembered zoom (page zoom needs macOS 11+)
+    let zoom = zoom * saved_view.zoom.unwrap_or(1.0);
+    if (zoom - 1.0).abs() > f64::EPSILON {
+        webview.zoom(zoom);
+    }
+    if config.remember_view {
+        if saved_view.zoom.is_some() || saved_view.scroll.is_some() { app_state.unrestored.insert(wid); }
+        app_state.views.insert(wid, saved_view);
+    }
+
+    app_state.webviews.insert(wid, webview);
+    app_state.persistence.insert(wid, PersistenceConfig { should_save: config.persistent, save_file, remember_view: config.remember_view });
+    app_state.dependencies.insert(wid, dependencies);
+    app_state.session.push((wid, request));
+    save_session(app_state);
+    inspect::window_opened(format!("{:?}", wid), serde_json::json!({ "id": config.id, "url": target_url, "config": config }));
+    inspect::record_event("window-opened", &format!("{} ({})", config.id, target_url));
+    perf::record("window", &config.id, window_started);
+    Ok(())
+}
 
-    let save_file = sys.data_dir.join(format!("state_{}.json", config.id));
-    let mut win_w = config.width;
-    let mut win_h = config.height;
-    let mut win_is_max = config.maximized;
-    let mut win_x = None;
-    let mut win_y = None;
+// Settings the webview handlers read for the page being shown. A pre-warmed
+// webview is built before its page is known, so they are filled in when it is claimed.
+struct WindowPolicy {
+    id: String,
+    initial_url: String,
+    security: SharedSecurity,
+    ignore_global: bool,   // frontier-ignore-global-security: skip the [security] whitelists
+    internal: UrlWhitelist, // Page additions (meta tags / spawn options)
+    browser: UrlWhitelist,
+    started: Instant,
+    painted: bool,
+}
 
-    if config.persistent {
-        if let Ok(json) = fs::read_to_string(&save_file) {
-            if let Ok(saved) = serde_json::from_str::<window::WindowState>(&json) {
-                win_w = saved.width; win_h = saved.height;
-                win_is_max = saved.maximized;
-                win_x = Some(saved.x); win_y = Some(saved.y);
-            }
+impl WindowPolicy {
+    // Page additions, [security.windows.<id>] and (unless ignored) the global whitelists
+    fn category(&self, url: &str, scheme: &str) -> UrlCategory {
+        let rules = self.security.read().unwrap();
+        let mut internal = vec![&self.internal];
+        let mut browser = vec![&self.browser];
+        if let Some(window) = rules.window(&self.id) {
+            internal.push(&window.internal);
+            browser.push(&window.browser);
         }
+        if !self.ignore_global {
+            internal.push(&rules.internal);
+            browser.push(&rules.browser);
+        }
+        get_url_category(url, scheme, &internal, &browser)
     }
 
-    let mut current_icon = sys_icon;
-    if let Some(ipath) = &config.icon_path {
-        let full_ipath = if sys_is_dev { sys_base.join("app/frontend").join(ipath) } else { sys_base.join("frontend").join(ipath) };
-        if let Some(loaded) = load_icon_from_disk(&full_ipath) { current_icon = Some(loaded); }
+    fn csp(&self) -> Option<String> {
+        self.security.read().unwrap().window(&self.id).and_then(|w| w.csp.clone())
     }
 
-    let mut builder = WindowBuilder::new()
-        .with_title(&config.title)
-        .with_inner_size(LogicalSize::new(win_w, win_h))
-        .with_resizable(config.resizable)
-        .with_minimizable(config.minimizable)
-        .with_maximizable(config.maximizable)
-        .with_maximized(win_is_max)
-        .with_window_icon(current_icon);
+    fn allows_command(&self, cmd: &str) -> bool {
+        let rules = self.security.read().unwrap();
+        match rules.window(&self.id).and_then(|w| w.commands.as_ref()) {
+            Some(allowed) => allowed.iter().any(|c| c == cmd),
+            None => true,
+        }
+    }
 
-    // Apply minimum window size constraints if specified
-    if let (Some(w), Some(h)) = (config.min_width, config.min_height) {
-        builder = builder.with_min_inner_size(LogicalSize::new(w, h));
+    // A window can only put the triggers it may call itself in a jump list or Dock menu
+    fn allowed_tasks(&self, tasks: Vec<config::JumpTask>, menu: &str) -> Vec<config::JumpTask> {
+        tasks.into_iter().filter(|task| match (&task.open, &task.trigger) {
+            (None, Some(trigger)) if !self.allows_command(trigger) => {
+                warn!(target: "security", "🚫 [SECURITY] {} item '{}' dropped: command '{}' not allowed in this window", menu, task.title, trigger);
+                false
+            }
+            _ => true,
+        }).collect()
     }
-    
-    // Apply maximum window size constraints if specified
-    if let (Some(w), Some(h)) = (config.max_width, config.max_height) {
-        builder = builder.with_max_inner_size(LogicalSize::new(w, h));
+}
+
+// One WebContext (cookies, storage, cache) per partition. Windows without a
+// partition share the default one; "temp:<name>" partitions start empty on every run.
+struct WebContexts {
+    data_dir: PathBuf,
+    default: WebContext,
+    partitions: HashMap<String, WebContext>,
+    served: HashMap<String, ServedWindows>, // Per partition, where the scheme handler is shared
+}
+
+// Windows whose <scheme>:// requests a handler answers: its own window, or on
+// WebKitGTK every window of its WebContext (see platform::SHARED_SCHEME_HANDLER)
+type ServedWindows = Arc<Mutex<Vec<ServedWindow>>>;
+
+struct ServedWindow {
+    id: WindowId,
+    policy: Arc<Mutex<WindowPolicy>>,
+    dependencies: Arc<Mutex<HashSet<String>>>,
+}
+
+impl WebContexts {
+    fn new(data_dir: PathBuf) -> Self {
+        WebContexts { default: WebContext::new(Some(data_dir.clone())), data_dir, partitions: HashMap::new(), served: HashMap::new() }
     }
 
-    if !win_is_max {
-        if let (Some(x), Some(y)) = (win_x, win_y) {
-            builder = builder.with_position(LogicalPosition::new(x, y));
-        } else if let (Some(fx), Some(fy)) = (config.x.clone(), config.y.clone()) {
-            if let Some(mon) = event_loop.primary_monitor() {
-                let s = mon.size().to_logical::<f64>(mon.scale_factor());
-                let px = window::evaluate_math_expression(&fx, s.width, s.height, win_w, win_h);
-                let py = window::evaluate_math_expression(&fy, s.width, s.height, win_w, win_h);
-                builder = builder.with_position(LogicalPosition::new(px, py));
+    // Windows the next webview of `partition` will serve
+    fn served(&mut self, partition: Option<&str>) -> ServedWindows {
+        if !platform::SHARED_SCHEME_HANDLER { return ServedWindows::default(); }
+        self.served.entry(partition.unwrap_or_default().to_string()).or_default().clone()
+    }
+
+    fn forget(&self, id: WindowId) {
+        for served in self.served.values() {
+            served.lock().unwrap().retain(|w| w.id != id);
+        }
+    }
+
+    fn get(&mut self, partition: Option<&str>) -> &mut WebContext {
+        let Some(name) = partition else { return &mut self.default };
+        let data_dir = &self.data_dir;
+        self.partitions.entry(name.to_string()).or_insert_with(|| {
+            let (temp, label) = match name.strip_prefix("temp:") {
+                Some(label) => (true, label),
+                None => (false, name),
+            };
+            let folder: String = label.chars()
+                .map(|c| if c.is_ascii_alphanumeric() || c == '-' || c == '_' { c } else { '_' })
+                .collect();
+            let dir = data_dir.join("partitions").join(if temp { format!("temp-{}", folder) } else { folder });
+            if temp { let _ = fs::remove_dir_all(&dir); }
+            debug!(target: "window", "🗂️ [WINDOW] Partition '{}' in {}", name, dir.display());
+            WebContext::new(Some(dir))
+        })
+    }
+}
+
+// Parts of a webview that are the same for every window of the app
+#[derive(PartialEq)]
+struct WebviewBase {
+    scheme: String,
+    is_dev: bool,
+    base_dir: PathBuf,
+    devtools: bool,
+    user_agent: Option<String>,
+    init_script: String,
+    spa_fallback: bool, // [frontend] spa_fallback
+    mime_types: HashMap<String, String>,
+    appdata: Option<appdata::AppData>, // appdata:// ([appdata] folders)
+}
+
+// Hidden webview kept ready for the next window ([window] prewarm)
+struct WarmWindow {
+    webview: WebView,
+    policy: Arc<Mutex<WindowPolicy>>,
+    dependencies: Arc<Mutex<HashSet<String>>>,
+    base: WebviewBase,
+}
+
+// [window] splash: a frameless window shown right away, while the startup windows load
+// hidden; it closes when a page calls Frontier.ready() or after splash_timeout seconds
+fn open_splash(event_loop: &EventLoopWindowTarget<FrontierEvent>, app_state: &mut AppState, contexts: &mut WebContexts) {
+    let (base, defaults, base_dir, is_dev) = {
+        let sys = app_state.system.lock().unwrap();
+        (webview_base(&sys), sys.window_defaults.clone(), sys.base_dir.clone(), sys.is_dev)
+    };
+    let Some(splash) = defaults.splash.filter(|s| !s.is_empty()) else { return };
+    let started = Instant::now();
+    // Images are shown as they are, at their own size
+    let is_page = splash.ends_with(".html") || splash.ends_with(".htm");
+    let (width, height) = if is_page {
+        (defaults.splash_width.unwrap_or(DEFAULT_SPLASH_SIZE.0), defaults.splash_height.unwrap_or(DEFAULT_SPLASH_SIZE.1))
+    } else {
+        match frontend_file(&base_dir, is_dev, &splash).map(image::image_dimensions) {
+            Some(Ok((w, h))) => (w as f64, h as f64),
+            _ => {
+                warn!(target: "window", "⚠️ [WINDOW] Cannot read the splash image {}", splash);
+                return;
             }
         }
+    };
+    let url = format!("{}://app/{}", base.scheme, splash);
+    let policy = Arc::new(Mutex::new(WindowPolicy {
+        id: "splash".to_string(),
+        initial_url: url.clone(),
+        security: app_state.system.lock().unwrap().security.clone(),
+        ignore_global: true,
+        internal: UrlWhitelist::new(&[]),
+        browser: UrlWhitelist::new(&[]),
+        started,
+        painted: true,
+    }));
+
+    let mut builder = window_builder(None)
+        .with_title(defaults.title.as_deref().unwrap_or("App"))
+        .with_inner_size(LogicalSize::new(width, height))
+        .with_decorations(false)
+        .with_resizable(false)
+        .with_always_on_top(true);
+    if let Some(mon) = event_loop.primary_monitor() {
+        let s = mon.size().to_logical::<f64>(mon.scale_factor());
+        builder = builder.with_position(LogicalPosition::new((s.width - width) / 2.0, (s.height - height) / 2.0));
+    }
+    builder = with_skip_taskbar(builder, true);
+    let webview = builder.build(event_loop)
+        .map_err(|e| e.to_string())
+        .and_then(|window| {
+            let served = contexts.served(None);
+            build_webview(window, contexts.get(None), served, &base, policy, Arc::new(Mutex::new(HashSet::new())), &url, header::HeaderMap::new(), app_state.main_proxy.clone(), app_state.jobs.clone(), false)
+                .map_err(|e| e.to_string())
+        });
+    match webview {
+        Ok(webview) => {
+            app_state.splash = Some(Splash { webview, hidden: Vec::new() });
+            perf::record("splash", &splash, started);
+            let proxy = app_state.main_proxy.clone();
+            let timeout = defaults.splash_timeout.unwrap_or(DEFAULT_SPLASH_TIMEOUT);
+            app_state.runtime.spawn(async move {
+                tokio::time::sleep(Duration::from_secs(timeout)).await;
+                let _ = proxy.send_event(FrontierEvent::CloseSplash(None));
+            });
+        }
+        Err(e) => warn!(target: "window", "⚠️ [WINDOW] Cannot show the splash screen: {}", e),
+    }
+}
+
+// Close the splash screen and show the windows that loaded behind it
+fn close_splash(app_state: &mut AppState, ready: Option<WindowId>) {
+    // The splash page itself doesn't count
+    if ready.is_some_and(|wid| !app_state.webviews.contains_key(&wid)) { return; }
+    let Some(splash) = app_state.splash.take() else { return };
+    match ready {
+        Some(_) => debug!(target: "window", "📄 [WINDOW] Splash screen closed (Frontier.ready)"),
+        None => warn!(target: "window", "⚠️ [WINDOW] Splash screen closed before any page called Frontier.ready()"),
+    }
+    for wid in &splash.hidden {
+        if let Some(webview) = app_state.webviews.get(wid) { webview.window().set_visible(true); }
+    }
+    let focus = ready.filter(|wid| splash.hidden.contains(wid)).or_else(|| splash.hidden.first().copied());
+    if let Some(webview) = focus.and_then(|wid| app_state.webviews.get(&wid)) { webview.window().set_focus(); }
+}
+
+// Ask for a hidden webview once the app is idle, if [window] prewarm is on
+fn schedule_prewarm(app: &AppState) {
+    if app.warm.is_some() || !app.system.lock().unwrap().window_defaults.prewarm.unwrap_or(false) { return; }
+    let proxy = app.main_proxy.clone();
+    app.runtime.spawn(async move {
+        tokio::time::sleep(Duration::from_millis(PREWARM_DELAY_MS)).await;
+        let _ = proxy.send_event(FrontierEvent::Prewarm);
+    });
+}
+
+// Build a hidden, blank webview so the next window skips webview initialization
+fn prewarm_window(event_loop: &EventLoopWindowTarget<FrontierEvent>, app: &mut AppState, contexts: &mut WebContexts) {
+    if app.warm.is_some() { return; }
+    let started = Instant::now();
+    let base = webview_base(&app.system.lock().unwrap());
+    let policy = Arc::new(Mutex::new(WindowPolicy {
+        id: "prewarm".to_string(),
+        initial_url: "about:blank".to_string(),
+        security: app.system.lock().unwrap().security.clone(),
+        ignore_global: true,
+        internal: UrlWhitelist::new(&[]),
+        browser: UrlWhitelist::new(&[]),
+        started,
+        painted: true,
+    }));
+    let dependencies = Arc::new(Mutex::new(HashSet::new()));
+
+    let webview = window_builder(app.menu.bar(true))
+        .with_visible(false)
+        .build(event_loop)
+        .map_err(|e| e.to_string())
+        .and_then(|window| {
+            let served = contexts.served(None);
+            build_webview(window, contexts.get(None), served, &base, policy.clone(), dependencies.clone(), "about:blank", header::HeaderMap::new(), app.main_proxy.clone(), app.jobs.clone(), false)
+                .map_err(|e| e.to_string())
+        });
+    match webview {
+        Ok(webview) => {
+            app.warm = Some(WarmWindow { webview, policy, dependencies, base });
+            perf::record("prewarm", "", started);
+        }
+        Err(e) => warn!(target: "window", "⚠️ [WINDOW] Cannot prepare a hidden webview: {}", e),
+    }
+}
+
+fn webview_base(sys: &system::SystemState) -> WebviewBase {
+    WebviewBase {
+        scheme: sys.scheme.clone(),
+        is_dev: sys.is_dev,
+        base_dir: sys.base_dir.clone(),
+        devtools: sys.devtools,
+        user_agent: sys.network.user_agent.clone(),
+        spa_fallback: sys.frontend.spa_fallback,
+        mime_types: sys.mime_types.clone(),
+        appdata: appdata::AppData::new(&sys.data_dir, &sys.appdata, &sys.mime_types, &sys.scheme, sys.dev_frontend_url.as_deref()),
+        init_script: bridge::init_script(&bridge::BridgeConfig {
+            i18n: &sys.i18n,
+            features: &sys.features,
+            devtools: sys.devtools,
+            is_dev: sys.is_dev,
+            scheme: &sys.scheme,
+            accessibility: &serde_json::to_value(&sys.os_accessibility).unwrap_or_default(),
+            high_contrast: match sys.accessibility.high_contrast.as_deref() {
+                Some("always") => true,
+                Some("off") => false,
+                _ => sys.os_accessibility.high_contrast,
+            },
+            high_contrast_css: sys.accessibility.high_contrast_css.as_deref(),
+            sync_title: sys.accessibility.sync_title.unwrap_or(false),
+            shortcuts: sys.keyboard.shortcuts.as_deref().unwrap_or("physical"),
+            composition_guard: sys.keyboard.composition_guard.unwrap_or(false),
+            launch: &serde_json::json!({ "args": sys.launch_args, "files": sys.launch_files }),
+        }),
     }
+}
 
-    let window = builder.build(event_loop)?;
+#[allow(clippy::too_many_arguments)]
+fn build_webview(
+    window: wry::application::window::Window,
+    context: &mut WebContext,
+    served: ServedWindows,
+    base: &WebviewBase,
+    policy: Arc<Mutex<WindowPolicy>>,
+    dependencies: Arc<Mutex<HashSet<String>>>,
+    url: &str,
+    headers: header::HeaderMap,
+    proxy: EventLoopProxy<FrontierEvent>,
+    jobs: Arc<Mutex<system::RunningJobs>>,
+    transparent: bool, // frontier-transparent: the page's background shows through
+) -> wry::Result<WebView> {
     let wid = window.id();
-    
+    let sys_is_dev = base.is_dev;
+    let sys_base = base.base_dir.clone();
+    let devtools = base.devtools;
+    let spa_fallback = base.spa_fallback;
+    let mime_types = base.mime_types.clone();
+
     // --- ROUTING LOGIC WITH DEDUPLICATION ---
     // This system prevents duplicate window opens by routing external URLs through a single handler
     // and using atomic locks to prevent race conditions between navigation_handler and new_window_req_handler
-    let w_int_nav = combined_internal.clone();
-    let w_bro_nav = combined_browser.clone();
-    let w_int_req = combined_internal.clone();
-    let w_bro_req = combined_browser.clone();
-    let initial_url = target_url.clone();
+    served.lock().unwrap().push(ServedWindow { id: wid, policy: policy.clone(), dependencies });
+    let nav_policy = policy.clone();
+    let req_policy = policy.clone();
+    let ipc_policy = policy;
     let nav_proxy = proxy.clone();
-    let ipc_proxy = proxy.clone();
+    let asset_proxy = proxy.clone();
+    let scheme_nav = base.scheme.clone();
+    let scheme_req = base.scheme.clone();
+    let ipc_proxy = proxy;
+
+    let mut webview_builder = WebViewBuilder::new(window)?.with_transparent(transparent);
+    if let Some(ua) = &base.user_agent {
+        webview_builder = webview_builder.with_user_agent(ua);
+    }
+    if let Some(appdata) = base.appdata.clone() {
+        webview_builder = webview_builder.with_custom_protocol(appdata::SCHEME.into(), move |req| appdata.handle(req));
+    }
 
-    let webview = WebViewBuilder::new(window)?
+    webview_builder
         .with_web_context(context)
+        .with_initialization_script(&base.init_script)
+        .with_devtools(devtools)
         .with_navigation_handler(move |url| {
+            let policy = nav_policy.lock().unwrap();
             // Rule 1: Always allow initial URL load to prevent blocking the first page
-            if url == initial_url { return true; }
+            if url == policy.initial_url { return true; }
 
-            let cat = get_url_category(&url, &w_int_nav, &w_bro_nav);
+            let cat = policy.category(&url, &scheme_nav);
             match cat {
                 // Frontier and internally-whitelisted URLs load within the window
                 UrlCategory::Frontier | UrlCategory::Internal => true,
                 // External browser URLs are routed to the system browser with deduplication
                 UrlCategory::Browser => {
-                    route_to_browser(&url, sys_is_dev);
+                    route_to_browser(&url);
                     false // Block window load to prevent internal opening
                 },
                 // Security-blocked URLs are rejected
                 UrlCategory::Blocked => {
-                    if sys_is_dev { eprintln!("🚫 [SECURITY] Blocked access to: {}", url); }
+                    warn!(target: "security", "🚫 [SECURITY] Blocked access to: {}", url);
                     false
                 }
             }
@@ -318,12 +1922,12 @@ fn create_new_window(
         .with_new_window_req_handler(move |url| {
             // Handles new window requests (e.g., target="_blank" links, window.open() calls)
             // Routes based on URL category without duplicating browser opens
-            let cat = get_url_category(&url, &w_int_req, &w_bro_req);
+            let cat = req_policy.lock().unwrap().category(&url, &scheme_req);
             match cat {
                 // Frontier protocol URLs spawn a new Frontier window
                 UrlCategory::Frontier => {
-                    let path = url.replace("https://frontier.", "").replace("frontier://", "");
-                    let _ = nav_proxy.send_event(FrontierEvent::OpenWindow(path));
+                    let path = page_from_app_url(&url, &scheme_req);
+                    let _ = nav_proxy.send_event(FrontierEvent::OpenWindow(Some(wid), path));
                     false
                 },
                 // Internal URLs open as browser popups within the Edge WebView
@@ -335,63 +1939,991 @@ fn create_new_window(
                 UrlCategory::Blocked => false
             }
         })
-        .with_custom_protocol("frontier".into(), move |req| {
-            // frontier://app/filename.html -> extract /filename.html
+        .with_custom_protocol(base.scheme.clone(), move |req| {
+            // <scheme>://app/filename.html -> extract /filename.html
             let path = req.uri().path();
             let clean_path = percent_encoding::percent_decode_str(path).decode_utf8_lossy().to_string();
             let mut resource = clean_path.trim_start_matches('/').to_string();
             if resource.is_empty() { resource = "index.html".to_string(); }
-            
+        
             // Ignore favicon requests (browsers automatically request this)
             if resource == "favicon.ico" {
                 return Response::builder().status(404).body(Cow::Owned(b"404".to_vec())).map_err(|_| wry::Error::InitScriptError);
             }
-            
-            let fp = if sys_is_dev { sys_base.join("app/frontend").join(&resource) } else { sys_base.join("frontend").join(&resource) };
-            let mime = mime_guess::from_path(&fp).first_or_octet_stream().to_string();
-            match fs::read(&fp) {
-                Ok(b) => {
-                    if sys_is_dev { eprintln!("📦 [ASSET] {} ({})", resource, mime); }
-                    Response::builder().header(header::CONTENT_TYPE, mime).header("Access-Control-Allow-Origin", "*").body(Cow::Owned(b)).map_err(|_| wry::Error::InitScriptError)
+        
+            // Dev-only runtime inspection (see inspect.rs)
+            if sys_is_dev && resource == inspect::STATE_PATH {
+                let body = serde_json::to_vec_pretty(&inspect::snapshot()).unwrap_or_default();
+                return Response::builder().header(header::CONTENT_TYPE, "application/json").body(Cow::Owned(body)).map_err(|_| wry::Error::InitScriptError);
+            }
+            if sys_is_dev && resource == inspect::IPC_PATH {
+                let since = req.uri().query()
+                    .and_then(|q| q.split('&').find_map(|p| p.strip_prefix("since=")))
+                    .and_then(|v| v.parse().ok())
+                    .unwrap_or(0);
+                let body = serde_json::to_vec(&inspect::ipc_since(since)).unwrap_or_default();
+                return Response::builder().header(header::CONTENT_TYPE, "application/json").body(Cow::Owned(body)).map_err(|_| wry::Error::InitScriptError);
+            }
+            if let Some(page) = inspect::page(&resource).filter(|_| sys_is_dev) {
+                return Response::builder().header(header::CONTENT_TYPE, "text/html").body(Cow::Borrowed(page.as_bytes())).map_err(|_| wry::Error::InitScriptError);
+            }
+
+            // MessagePack replies, fetched once by the window that ran the trigger
+            if let Some(id) = resource.strip_prefix(payload::REPLY_PATH) {
+                let owners: Vec<String> = served.lock().unwrap().iter().map(|w| format!("{:?}", w.id)).collect();
+                let response = match payload::take(&owners, id) {
+                    Some(bytes) => Response::builder()
+                        .header(header::CONTENT_TYPE, "application/msgpack")
+                        .header("Access-Control-Allow-Origin", "*")
+                        .header(header::CACHE_CONTROL, "no-store")
+                        .body(Cow::Owned(bytes)),
+                    None => Response::builder().status(404).body(Cow::Owned(b"404".to_vec())),
+                };
+                return response.map_err(|_| wry::Error::InitScriptError);
+            }
+
+            let load = |resource: &str| {
+                let fp = if sys_is_dev { sys_base.join("app/frontend").join(resource) } else { sys_base.join("frontend").join(resource) };
+                let asset = if sys_is_dev {
+                    serve::read(&fp)
+                } else {
+                    serve::embedded(&format!("frontend/{}", resource)).ok_or_else(|| std::io::Error::from(std::io::ErrorKind::NotFound))
+                };
+                (fp, asset)
+            };
+            let (mut fp, mut asset) = load(&resource);
+            // [frontend] spa_fallback: paths of client-side routers get index.html, real assets win
+            let accepts_html = req.headers().get(header::ACCEPT).and_then(|a| a.to_str().ok()).is_some_and(|a| a.contains("text/html"));
+            if asset.is_err() && spa_fallback && (accepts_html || is_spa_route(&resource)) {
+                debug!(target: "asset", "📦 [ASSET] {} -> index.html (SPA route)", resource);
+                resource = "index.html".to_string();
+                (fp, asset) = load(&resource);
+            }
+            if sys_is_dev {
+                for w in served.lock().unwrap().iter() { w.dependencies.lock().unwrap().insert(resource.clone()); }
+            }
+            let mime = serve::content_type(&fp, &mime_types);
+            match asset {
+                Ok(asset) => {
+                    let mut response = Response::builder()
+                        .header(header::CONTENT_TYPE, mime.as_str())
+                        .header("Access-Control-Allow-Origin", "*")
+                        .header(header::CACHE_CONTROL, "no-cache")
+                        .header(header::ACCEPT_RANGES, "bytes")
+                        .header(header::ETAG, asset.etag.as_str())
+                        .header(header::LAST_MODIFIED, asset.last_modified.as_str());
+                    // A shared handler sends the policy of every window it serves; pages enforce all of them
+                    let mut policies: Vec<String> = served.lock().unwrap().iter().filter_map(|w| w.policy.lock().unwrap().csp()).collect();
+                    policies.sort();
+                    policies.dedup();
+                    for csp in policies {
+                        response = response.header(header::CONTENT_SECURITY_POLICY, csp.as_str());
+                    }
+                    if serve::is_not_modified(req.headers(), &asset) {
+                        debug!(target: "asset", "📦 [ASSET] {} (not modified)", resource);
+                        return response.status(304).body(Cow::Owned(Vec::new())).map_err(|_| wry::Error::InitScriptError);
+                    }
+                    let range = match serve::requested_range(req.headers(), &asset) {
+                        Ok(range) => range,
+                        Err(()) => {
+                            return response.status(416)
+                                .header(header::CONTENT_RANGE, format!("bytes */{}", asset.len))
+                                .body(Cow::Owned(Vec::new())).map_err(|_| wry::Error::InitScriptError);
+                        }
+                    };
+                    let body = match asset.bytes(&fp, range) {
+                        Ok(body) => body,
+                        Err(e) => {
+                            error!(target: "asset", "❌ [ASSET] Cannot read {}: {}", resource, e);
+                            return Response::builder().status(500).body(Cow::Owned(b"500".to_vec())).map_err(|_| wry::Error::InitScriptError);
+                        }
+                    };
+                    if let Some((start, end)) = range {
+                        debug!(target: "asset", "📦 [ASSET] {} ({}, bytes {}-{}/{})", resource, mime, start, end, asset.len);
+                        response = response.status(206).header(header::CONTENT_RANGE, format!("bytes {}-{}/{}", start, end, asset.len));
+                    } else {
+                        debug!(target: "asset", "📦 [ASSET] {} ({})", resource, mime);
+                    }
+                    response.body(body).map_err(|_| wry::Error::InitScriptError)
                 },
                 Err(_) => {
-                    if sys_is_dev { eprintln!("❌ [ASSET] Not found: {}", resource); }
+                    warn!(target: "asset", "❌ [ASSET] Not found: {}", resource);
+                    if sys_is_dev {
+                        for w in served.lock().unwrap().iter() {
+                            let _ = asset_proxy.send_event(FrontierEvent::DevError(w.id, "Asset".into(), format!("Not found: {}", resource)));
+                        }
+                    }
                     Response::builder().status(404).body(Cow::Owned(b"404".to_vec())).map_err(|_| wry::Error::InitScriptError)
                 }
             }
         })
-        .with_url(&target_url)?
-        .with_ipc_handler(move |_, req| {
+        .with_url_and_headers(url, headers)?
+        .with_ipc_handler(move |window, req| {
+            let call_backend = |call: envelope::BackendCall| {
+                if !ipc_policy.lock().unwrap().allows_command(&call.trigger) {
+                    warn!(target: "security", "🚫 [SECURITY] Command '{}' not allowed in this window", call.trigger);
+                    let message = format!("Command '{}' not allowed", call.trigger);
+                    let callback = call.callback.map(|id| envelope::Callback { id, error: Some(message.clone()), ..Default::default() });
+                    let _ = ipc_proxy.send_event(FrontierEvent::BackendReply(wid, message, callback));
+                    return;
+                }
+                let args = call.args_text();
+                info!(target: "ipc", "💬 [IPC] exec: {} {}", call.trigger, if args.is_empty() { "(no args)" } else { &args });
+                let _ = ipc_proxy.send_event(FrontierEvent::RunCommand(wid, call));
+            };
+            // JSON envelopes call backends only; the runtime's own commands keep the `cmd|...` form
+            if req.starts_with('{') {
+                let label = ipc_policy.lock().unwrap().id.clone();
+                match envelope::BackendCall::from_envelope(&req) {
+                    Ok(call) => {
+                        if inspect::tracing_ipc() { inspect::record_ipc(&label, "message", &call.trigger, &call.args_text(), None, false); }
+                        if call.trigger.is_empty() || call.trigger.starts_with("__") || call.trigger == "open" || call.trigger == "spawn" {
+                            let message = format!("'{}' can't be called with a JSON envelope", call.trigger);
+                            warn!(target: "ipc", "⚠️ [IPC] {}", message);
+                            let callback = call.callback.map(|id| envelope::Callback { id, error: Some(message.clone()), ..Default::default() });
+                            let _ = ipc_proxy.send_event(FrontierEvent::BackendReply(wid, message, callback));
+                        } else {
+                            call_backend(call);
+                        }
+                    }
+                    Err(e) => warn!(target: "ipc", "⚠️ [IPC] {}", e),
+                }
+                return;
+            }
             let mut parts = req.splitn(3, '|');
             let cmd = parts.next().unwrap_or("");
+            let label = ipc_policy.lock().unwrap().id.clone();
+            if inspect::tracing_ipc() {
+                inspect::record_ipc(&label, "message", cmd, req.get(cmd.len() + 1..).unwrap_or(""), None, false);
+            }
             match cmd {
                 "open" => { 
-                    let file = parts.next().unwrap_or("").to_string();
-                    if sys_is_dev { eprintln!("💬 [IPC] open: {}", file); }
-                    let _ = ipc_proxy.send_event(FrontierEvent::OpenWindow(file)); 
+                    // The whole rest, so query strings may contain '|'
+                    let file = req.get(cmd.len() + 1..).unwrap_or("").to_string();
+                    info!(target: "ipc", "💬 [IPC] open: {}", file);
+                    let _ = ipc_proxy.send_event(FrontierEvent::OpenWindow(Some(wid), file)); 
+                },
+                "__console" => {
+                    if sys_is_dev {
+                        let level = parts.next().unwrap_or("log");
+                        let text = parts.next().unwrap_or("");
+                        match level {
+                            "error" => error!(target: "console", "🖥️ [CONSOLE:{}] {}", label, text),
+                            "warn" => warn!(target: "console", "🖥️ [CONSOLE:{}] {}", label, text),
+                            "debug" => debug!(target: "console", "🖥️ [CONSOLE:{}] {}", label, text),
+                            _ => info!(target: "console", "🖥️ [CONSOLE:{}] {}", label, text),
+                        }
+                    }
+                },
+                "__ready" => { let _ = ipc_proxy.send_event(FrontierEvent::CloseSplash(Some(wid))); },
+                "__close_guard" => {
+                    let _ = ipc_proxy.send_event(FrontierEvent::CloseGuard(wid, parts.next() == Some("on")));
+                },
+                "__close_reply" => {
+                    let Some(ask) = parts.next().and_then(|id| id.parse().ok()) else { return };
+                    let reply = match parts.next() {
+                        Some("handling") => CloseReply::Handling,
+                        Some("stay") => CloseReply::Stay,
+                        _ => CloseReply::Close,
+                    };
+                    let _ = ipc_proxy.send_event(FrontierEvent::CloseReply(wid, ask, reply));
+                },
+                "__monitors" => {
+                    let _ = ipc_proxy.send_event(FrontierEvent::Monitors(wid, parts.next().unwrap_or("").to_string()));
+                },
+                "__store" => {
+                    let id = parts.next().unwrap_or("").to_string();
+                    let _ = ipc_proxy.send_event(FrontierEvent::Store(wid, id, parts.next().unwrap_or("").to_string()));
+                },
+                "__paint" => {
+                    let mut policy = ipc_policy.lock().unwrap();
+                    if !policy.painted {
+                        policy.painted = true;
+                        perf::first_paint(&policy.id, policy.started);
+                    }
+                    let _ = ipc_proxy.send_event(FrontierEvent::PageReady(wid));
+                },
+                "__cancel" => {
+                    let trigger = parts.next().filter(|t| !t.is_empty());
+                    let cancelled = jobs.lock().unwrap().cancel(&format!("{:?}", wid), trigger);
+                    debug!(target: "ipc", "⏹️ [IPC] cancel {}: {} job(s)", trigger.unwrap_or("*"), cancelled);
+                },
+                // A single Frontier.run call, by its callbackId (its AbortSignal fired)
+                "__cancel_call" => {
+                    let Some(call) = parts.next().and_then(|id| id.parse().ok()) else { return };
+                    let cancelled = jobs.lock().unwrap().cancel_call(&format!("{:?}", wid), call);
+                    debug!(target: "ipc", "⏹️ [IPC] cancel call {}: {}", call, if cancelled { "cancelled" } else { "not running" });
+                },
+                // __session_start|<id>|<JSON envelope without callbackId>
+                "__session_start" => {
+                    let id = parts.next().unwrap_or("").to_string();
+                    let error = match envelope::BackendCall::from_envelope(parts.next().unwrap_or("")) {
+                        Ok(call) if ipc_policy.lock().unwrap().allows_command(&call.trigger) => {
+                            let _ = ipc_proxy.send_event(FrontierEvent::SessionStart(wid, id.clone(), call));
+                            None
+                        }
+                        Ok(call) => {
+                            warn!(target: "security", "🚫 [SECURITY] Command '{}' not allowed in this window", call.trigger);
+                            Some(format!("Command '{}' not allowed", call.trigger))
+                        }
+                        Err(e) => Some(e),
+                    };
+                    if let Some(error) = error {
+                        let _ = ipc_proxy.send_event(FrontierEvent::SessionEnd(wid, serde_json::json!({ "id": id, "exit": null, "error": error })));
+                    }
+                },
+                "__session_send" => {
+                    let id = parts.next().unwrap_or("");
+                    let data = format!("{}\n", parts.next().unwrap_or(""));
+                    if !jobs.lock().unwrap().send(&format!("{:?}", wid), id, data) {
+                        debug!(target: "ipc", "💬 [IPC] Session {} is not running", id);
+                    }
+                },
+                "__session_kill" => {
+                    let id = parts.next().unwrap_or("");
+                    jobs.lock().unwrap().kill_session(&format!("{:?}", wid), id);
+                },
+                "__devtools" => {
+                    if devtools {
+                        let action = parts.next().unwrap_or("toggle").to_string();
+                        let _ = ipc_proxy.send_event(FrontierEvent::Devtools(wid, action));
+                    }
+                },
+                "__title" => {
+                    let title = parts.next().unwrap_or("").trim();
+                    if !title.is_empty() { window.set_title(title); }
+                },
+                // __window|<action>[|<argument>]
+                "__window" => {
+                    let action = parts.next().unwrap_or("");
+                    let argument = parts.next().unwrap_or("");
+                    // Moves with the mouse button that is down now, so it can't wait for the event loop
+                    if action == "startDrag" {
+                        if let Err(e) = window.drag_window() { debug!(target: "ipc", "💬 [IPC] Cannot drag the window: {:?}", e); }
+                        return;
+                    }
+                    let command = match action {
+                        "minimize" => WindowCommand::Minimize,
+                        "maximize" => WindowCommand::Maximize,
+                        "unmaximize" => WindowCommand::Unmaximize,
+                        "toggleMaximize" => WindowCommand::ToggleMaximize,
+                        "close" => WindowCommand::Close,
+                        "focus" => WindowCommand::Focus,
+                        "center" => WindowCommand::Center,
+                        "setTitle" => WindowCommand::SetTitle(argument.to_string()),
+                        "setSize" => match serde_json::from_str::<[f64; 2]>(argument) {
+                            Ok([width, height]) if width > 0.0 && height > 0.0 => WindowCommand::SetSize(width, height),
+                            _ => { warn!(target: "ipc", "⚠️ [IPC] Invalid window size: {}", argument); return; }
+                        },
+                        _ => { warn!(target: "ipc", "⚠️ [IPC] Unknown window command '{}'", action); return; }
+                    };
+                    let _ = ipc_proxy.send_event(FrontierEvent::WindowControl(wid, command));
+                },
+                "__jumplist" => {
+                    let tasks = match serde_json::from_str::<Vec<config::JumpTask>>(req.get(cmd.len() + 1..).unwrap_or("[]")) {
+                        Ok(tasks) => tasks,
+                        Err(e) => { warn!(target: "ipc", "⚠️ [IPC] Invalid jump list tasks: {}", e); return; }
+                    };
+                    let tasks = ipc_policy.lock().unwrap().allowed_tasks(tasks, "Jump list");
+                    let _ = ipc_proxy.send_event(FrontierEvent::JumpList(tasks));
+                },
+                "__dock_badge" => platform::set_dock_badge(req.get(cmd.len() + 1..).unwrap_or("")),
+                "__dock_menu" => {
+                    let items = match serde_json::from_str::<Vec<config::JumpTask>>(req.get(cmd.len() + 1..).unwrap_or("[]")) {
+                        Ok(items) => items,
+                        Err(e) => { warn!(target: "ipc", "⚠️ [IPC] Invalid Dock menu items: {}", e); return; }
+                    };
+                    platform::set_dock_menu(dock_menu_items(&ipc_policy.lock().unwrap().allowed_tasks(items, "Dock menu")));
+                },
+                "__recent" => {
+                    let path = req.get(cmd.len() + 1..).unwrap_or("");
+                    if !path.is_empty() { jumplist::add_recent(path); }
+                },
+                "__state_get" => {
+                    let id = parts.next().unwrap_or("").to_string();
+                    let _ = ipc_proxy.send_event(FrontierEvent::State(wid, StateRequest::Get(id, parts.next().unwrap_or("").to_string())));
+                },
+                "__state_set" => {
+                    let path = parts.next().unwrap_or("").to_string();
+                    match serde_json::from_str(parts.next().unwrap_or("null")) {
+                        Ok(value) => { let _ = ipc_proxy.send_event(FrontierEvent::State(wid, StateRequest::Set(path, value))); }
+                        Err(e) => warn!(target: "ipc", "⚠️ [IPC] Invalid value for state '{}': {}", path, e),
+                    }
+                },
+                "__state_subscribe" => {
+                    let _ = ipc_proxy.send_event(FrontierEvent::State(wid, StateRequest::Subscribe(parts.next() == Some("on"))));
+                },
+                "__dialog_open" => {
+                    // { page, data }, as JSON so the page may contain '|'
+                    let id = parts.next().unwrap_or("").to_string();
+                    let request: serde_json::Value = serde_json::from_str(parts.next().unwrap_or("")).unwrap_or_default();
+                    let Some(page) = request["page"].as_str().filter(|p| !p.is_empty()) else {
+                        warn!(target: "ipc", "⚠️ [IPC] openDialog needs a page");
+                        return;
+                    };
+                    info!(target: "ipc", "💬 [IPC] dialog: {}", page);
+                    let _ = ipc_proxy.send_event(FrontierEvent::Dialog(wid, DialogRequest::Open(id, page.to_string(), request["data"].clone())));
+                },
+                "__file_dialog" => {
+                    // { kind, title, directory, filename, filters, multiple }
+                    let id = parts.next().unwrap_or("").to_string();
+                    let request = match serde_json::from_str::<filedialog::Request>(parts.next().unwrap_or("")) {
+                        Ok(request) => DialogRequest::File(id, request),
+                        Err(e) => {
+                            warn!(target: "ipc", "⚠️ [IPC] Invalid file dialog request: {}", e);
+                            DialogRequest::Picked(id, serde_json::Value::Null)
+                        }
+                    };
+                    let _ = ipc_proxy.send_event(FrontierEvent::Dialog(wid, request));
+                },
+                "__dialog_data" => {
+                    let _ = ipc_proxy.send_event(FrontierEvent::Dialog(wid, DialogRequest::Data(parts.next().unwrap_or("").to_string())));
+                },
+                "__dialog_close" => {
+                    let value = req.get(cmd.len() + 1..).and_then(|json| serde_json::from_str(json).ok()).unwrap_or_default();
+                    let _ = ipc_proxy.send_event(FrontierEvent::Dialog(wid, DialogRequest::Close(value)));
+                },
+                "__notify" => {
+                    let mut request = match serde_json::from_str::<notification::Notification>(req.get(cmd.len() + 1..).unwrap_or("")) {
+                        Ok(request) => request,
+                        Err(e) => { warn!(target: "ipc", "⚠️ [IPC] Invalid notification: {}", e); return; }
+                    };
+                    if !notification::valid_id(&request.tag) {
+                        warn!(target: "ipc", "⚠️ [IPC] Invalid notification tag '{}'", request.tag);
+                        return;
+                    }
+                    // Buttons run like jump list tasks, so their triggers must be allowed here
+                    let policy = ipc_policy.lock().unwrap();
+                    request.actions.retain(|action| {
+                        if action.open.is_none() && action.trigger.is_none() && !notification::valid_id(&action.id) {
+                            warn!(target: "ipc", "⚠️ [IPC] Notification action '{}' needs an id without ':'", action.title);
+                            return false;
+                        }
+                        !policy.allowed_tasks(vec![action.as_task()], "Notification").is_empty()
+                    });
+                    drop(policy);
+                    let _ = ipc_proxy.send_event(FrontierEvent::Notify(wid, request));
+                },
+                "__layout_apply" | "__layout_save" | "__layout_define" => {
+                    let name = parts.next().unwrap_or("").to_string();
+                    if name.is_empty() { return; }
+                    let request = match cmd {
+                        "__layout_apply" => LayoutRequest::Apply(name),
+                        "__layout_save" => LayoutRequest::Save(name),
+                        _ => match serde_json::from_str(parts.next().unwrap_or("[]")) {
+                            Ok(windows) => LayoutRequest::Define(name, windows),
+                            Err(e) => { warn!(target: "ipc", "⚠️ [IPC] Invalid layout '{}': {}", name, e); return; }
+                        },
+                    };
+                    let _ = ipc_proxy.send_event(FrontierEvent::Layout(request));
+                },
+                "__zoom" => {
+                    match parts.next().and_then(|f| f.parse::<f64>().ok()).filter(|f| *f > 0.0) {
+                        Some(factor) => { let _ = ipc_proxy.send_event(FrontierEvent::Zoom(wid, factor)); }
+                        None => warn!(target: "ipc", "⚠️ [IPC] Invalid zoom factor"),
+                    }
+                },
+                "__scroll" => {
+                    let mut coordinate = || parts.next().and_then(|v| v.parse::<f64>().ok());
+                    if let (Some(x), Some(y)) = (coordinate(), coordinate()) {
+                        let _ = ipc_proxy.send_event(FrontierEvent::Scroll(wid, [x, y]));
+                    }
+                },
+                "__accessibility" => {
+                    // gsettings is slow enough to stall the event loop
+                    let proxy = ipc_proxy.clone();
+                    std::thread::spawn(move || { let _ = proxy.send_event(FrontierEvent::Accessibility(wid, platform::accessibility_settings())); });
+                },
+                "__automation" => {
+                    let id = parts.next().unwrap_or("");
+                    automation::resolve(id, parts.next().unwrap_or(""));
                 },
                 "spawn" => {
                     let u = parts.next().unwrap_or("").to_string();
                     let c = parts.next().unwrap_or("").to_string();
-                    if sys_is_dev { eprintln!("💬 [IPC] spawn: {}", u); }
-                    let _ = ipc_proxy.send_event(FrontierEvent::OpenWindow(format!("spawn://{}?{}", u, c)));
+                    info!(target: "ipc", "💬 [IPC] spawn: {}", u);
+                    let _ = ipc_proxy.send_event(FrontierEvent::OpenWindow(Some(wid), format!("spawn://{}?{}", u, c)));
                 },
-                _ => {
-                    let arg = parts.next().unwrap_or("").to_string();
-                    if sys_is_dev { eprintln!("💬 [IPC] exec: {} {}", cmd, if arg.is_empty() { "(no args)" } else { &arg }); }
-                    let _ = ipc_proxy.send_event(FrontierEvent::RunCommand(wid, format!("{}|{}", cmd, arg)));
-                }
+                _ => call_backend(envelope::BackendCall::from_message(cmd, parts.next().unwrap_or(""))),
             }
         })
-        .build()?;
-
-    app_state.webviews.insert(wid, webview);
-    app_state.persistence.insert(wid, PersistenceConfig { should_save: config.persistent, save_file });
-    Ok(())
+        .build()
 }
 
 // --- HELPERS ---
 
+// Paths a client-side router may own: the last segment has no file extension
+fn is_spa_route(path: &str) -> bool {
+    !path.rsplit('/').next().unwrap_or("").contains('.')
+}
+
+// [network] headers as an http::HeaderMap (invalid names/values are skipped)
+fn build_header_map(headers: &HashMap<String, String>) -> header::HeaderMap {
+    let mut map = header::HeaderMap::new();
+    for (name, value) in headers {
+        match (header::HeaderName::from_bytes(name.as_bytes()), header::HeaderValue::from_str(value)) {
+            (Ok(n), Ok(v)) => { map.insert(n, v); }
+            _ => warn!(target: "config", "⚠️ [CONFIG] Ignoring invalid header: {}", name),
+        }
+    }
+    map
+}
+
+// Re-fetch the <link> stylesheets pointing at a changed CSS file (cache-busted)
+//
+// Pages that don't link the file directly (e.g. it is pulled in with @import)
+// fall back to a full reload.
+fn css_reload_script(rel_path: &str) -> String {
+    format!(
+        r#"(function() {{
+    var target = '/' + {path};
+    var found = false;
+    document.querySelectorAll('link[rel="stylesheet"]').forEach(function(link) {{
+        var url = new URL(link.href, location.href);
+        if (decodeURIComponent(url.pathname).endsWith(target)) {{
+            url.searchParams.set('frontier-reload', Date.now());
+            link.href = url.toString();
+            found = true;
+        }}
+    }});
+    if (!found && document.querySelector('link[rel="stylesheet"], style')) location.reload();
+}})();"#,
+        path = serde_json::to_string(rel_path).unwrap_or_default()
+    )
+}
+
+// Re-fetch the <img> elements and icons showing a changed image (cache-busted)
+//
+// Images used some other way (CSS backgrounds, canvas) fall back to a full reload.
+fn image_reload_script(rel_path: &str) -> String {
+    format!(
+        r#"(function() {{
+    var target = '/' + {path};
+    var found = false;
+    document.querySelectorAll('img[src], link[rel~="icon"]').forEach(function(el) {{
+        var attr = el.tagName === 'LINK' ? 'href' : 'src';
+        var url = new URL(el.getAttribute(attr), location.href);
+        if (decodeURIComponent(url.pathname).endsWith(target)) {{
+            url.searchParams.set('frontier-reload', Date.now());
+            el.setAttribute(attr, url.toString());
+            found = true;
+        }}
+    }});
+    if (!found) location.reload();
+}})();"#,
+        path = serde_json::to_string(rel_path).unwrap_or_default()
+    )
+}
+
+// Make the terminal usable for the log (dev mode, --console) and the REPL
+//
+// The runtime is a GUI-subsystem binary on Windows, so it attaches to the
+// console it was started from, or opens its own when launched from Explorer.
+// Linux and macOS processes already inherit the terminal's stdin/stdout/stderr.
+#[cfg(target_os = "windows")]
+fn attach_console() {
+    use winapi::um::consoleapi::AllocConsole;
+    use winapi::um::wincon::{AttachConsole, ATTACH_PARENT_PROCESS};
+    unsafe {
+        if AttachConsole(ATTACH_PARENT_PROCESS) == 0 {
+            AllocConsole();
+        }
+    }
+}
+
+#[cfg(not(target_os = "windows"))]
+fn attach_console() {}
+
+// frontier-skip-taskbar; macOS windows always show in the Dock's window list
+fn with_skip_taskbar(builder: WindowBuilder, skip: bool) -> WindowBuilder {
+    #[cfg(windows)]
+    use wry::application::platform::windows::WindowBuilderExtWindows;
+    #[cfg(all(unix, not(target_os = "macos")))]
+    use wry::application::platform::unix::WindowBuilderExtUnix;
+    #[cfg(not(target_os = "macos"))]
+    {
+        builder.with_skip_taskbar(skip)
+    }
+    #[cfg(target_os = "macos")]
+    {
+        let _ = skip;
+        builder
+    }
+}
+
+fn set_skip_taskbar(window: &Window, skip: bool) {
+    #[cfg(windows)]
+    use wry::application::platform::windows::WindowExtWindows;
+    #[cfg(all(unix, not(target_os = "macos")))]
+    use wry::application::platform::unix::WindowExtUnix;
+    #[cfg(not(target_os = "macos"))]
+    window.set_skip_taskbar(skip);
+    #[cfg(target_os = "macos")]
+    let _ = (window, skip);
+}
+
+// New window; on macOS with the standard menu bar, without which WKWebView
+// ignores Cmd+C/V/X/A/Z and there is no Cmd+Q, Cmd+W, Cmd+M or Cmd+H
+fn window_builder(menu: Option<MenuBar>) -> WindowBuilder {
+    match menu {
+        Some(menu) => WindowBuilder::new().with_menu(menu),
+        None => WindowBuilder::new(),
+    }
+}
+
+// [macos] activation policy and Dock menu, Dock icon clicks as FrontierEvent::Reopen
+// and Dock menu picks as FrontierEvent::Launch
+#[cfg(target_os = "macos")]
+fn setup_macos(mut event_loop: EventLoop<FrontierEvent>, macos: &config::MacosConfig, proxy: EventLoopProxy<FrontierEvent>) -> EventLoop<FrontierEvent> {
+    use wry::application::platform::macos::{ActivationPolicy, EventLoopExtMacOS};
+    event_loop.set_activation_policy(match macos.activation_policy.as_deref() {
+        Some("accessory") => ActivationPolicy::Accessory,
+        Some("prohibited") => ActivationPolicy::Prohibited,
+        _ => ActivationPolicy::Regular,
+    });
+    let reopen_proxy = proxy.clone();
+    platform::on_dock_reopen(move || { let _ = reopen_proxy.send_event(FrontierEvent::Reopen); });
+    // Picked items run like jump list tasks
+    platform::set_dock_menu(dock_menu_items(&macos.dock_menu));
+    platform::on_dock_menu(move |task| { let _ = proxy.send_event(FrontierEvent::Launch(instance::Launch { tasks: vec![task], ..Default::default() })); });
+    event_loop
+}
+
+// (title, --task value) of each Dock menu item
+fn dock_menu_items(items: &[config::JumpTask]) -> Vec<(String, String)> {
+    items.iter().filter_map(|item| match jumplist::task_argument(item) {
+        Some(task) => Some((item.title.clone(), task)),
+        None => { warn!(target: "config", "⚠️ [CONFIG] Dock menu item '{}' has neither open nor trigger", item.title); None }
+    }).collect()
+}
+
+// Dispatch 'app:launch' for each later launch and 'open-file' (with the file's
+// absolute path) for each file waiting for a page, and run waiting jump list
+// tasks, in the focused window or else the oldest one, and bring it forward
+fn deliver_launch(app_state: &mut AppState) {
+    if app_state.opened_files.is_empty() && app_state.launch_tasks.is_empty() && app_state.launch_events.is_empty() { return; }
+    let ready = |wid: &WindowId| app_state.ready.contains(wid);
+    let target = app_state.webviews.iter()
+        .find(|(wid, webview)| ready(wid) && webview.window().is_focused())
+        .map(|(wid, _)| *wid)
+        .or_else(|| app_state.session.iter().map(|(wid, _)| *wid).find(|wid| ready(wid)));
+    let Some(webview) = target.and_then(|wid| app_state.webviews.get(&wid)) else { return };
+
+    let is_dev = app_state.system.lock().unwrap().is_dev;
+    for launch in std::mem::take(&mut app_state.launch_events) {
+        run_script(webview, &format!("if(window.Frontier) window.Frontier.dispatch('app:launch', {})", launch), is_dev);
+    }
+    for path in std::mem::take(&mut app_state.opened_files) {
+        let js = format!("if(window.Frontier) window.Frontier.dispatch('open-file', {})", serde_json::to_string(&path).unwrap_or_default());
+        run_script(webview, &js, is_dev);
+    }
+    for task in std::mem::take(&mut app_state.launch_tasks) {
+        match jumplist::parse_task(&task) {
+            Some(jumplist::TaskAction::Open(page)) => { let _ = app_state.main_proxy.send_event(FrontierEvent::OpenWindow(None, page)); }
+            // Sent as the page's own IPC message, so its [security.windows] commands apply
+            Some(jumplist::TaskAction::Trigger(trigger, args)) => {
+                let message = serde_json::to_string(&format!("{}|{}", trigger, args)).unwrap_or_default();
+                run_script(webview, &format!("if(window.ipc) window.ipc.postMessage({})", message), is_dev);
+            }
+            // Sent to the window that showed the notification while it's open
+            // A click on the notification itself has no action id
+            Some(jumplist::TaskAction::Notification(tag, action, _)) if action.is_empty() => {
+                let shown_by = app_state.notifications.remove(&tag).and_then(|wid| app_state.webviews.get(&wid));
+                let js = format!("if(window.Frontier && window.Frontier.__notificationClick) window.Frontier.__notificationClick({})",
+                    serde_json::to_string(&tag).unwrap_or_default());
+                run_script(shown_by.unwrap_or(webview), &js, is_dev);
+            }
+            Some(jumplist::TaskAction::Notification(tag, action, reply)) => {
+                let shown_by = app_state.notifications.remove(&tag).and_then(|wid| app_state.webviews.get(&wid));
+                let js = format!("if(window.Frontier) window.Frontier.dispatch('notification-action', {})",
+                    serde_json::json!({ "tag": tag, "action": action, "reply": reply }));
+                run_script(shown_by.unwrap_or(webview), &js, is_dev);
+            }
+            None => warn!(target: "app", "⚠️ [APP] Unknown task '{}'", task),
+        }
+    }
+    webview.window().set_focus();
+}
+
+// Send a picked [[menu]] item to the page of the window it was picked in (the
+// focused one when the OS doesn't say) and run its open or trigger there, like
+// a jump list task
+fn run_menu_pick(app_state: &AppState, wid: Option<WindowId>, pick: menu::MenuPick) {
+    let target = wid.filter(|wid| app_state.webviews.contains_key(wid))
+        .or_else(|| app_state.webviews.iter().find(|(_, webview)| webview.window().is_focused()).map(|(wid, _)| *wid));
+    let webview = target.and_then(|wid| app_state.webviews.get(&wid));
+    let is_dev = app_state.system.lock().unwrap().is_dev;
+    if let (Some(webview), Some(id)) = (webview, &pick.id) {
+        let js = format!("if(window.Frontier) window.Frontier.dispatch('menu', {})", serde_json::to_string(id).unwrap_or_default());
+        run_script(webview, &js, is_dev);
+    }
+    match pick.task.as_deref().and_then(jumplist::parse_task) {
+        Some(jumplist::TaskAction::Open(page)) => { let _ = app_state.main_proxy.send_event(FrontierEvent::OpenWindow(target, page)); }
+        // Sent as the page's own IPC message, so its [security.windows] commands apply
+        Some(jumplist::TaskAction::Trigger(trigger, args)) => match webview {
+            Some(webview) => {
+                let message = serde_json::to_string(&format!("{}|{}", trigger, args)).unwrap_or_default();
+                run_script(webview, &format!("if(window.ipc) window.ipc.postMessage({})", message), is_dev);
+            }
+            None => warn!(target: "window", "⚠️ [MENU] No window to run '{}' in", trigger),
+        },
+        _ => {}
+    }
+}
+
+// Open and arrange the windows of a layout; open windows of the same page take
+// its places first, in the order they were opened
+fn apply_layout(event_loop: &EventLoopWindowTarget<FrontierEvent>, app_state: &mut AppState, contexts: &mut WebContexts, name: &str) {
+    let found = {
+        let sys = app_state.system.lock().unwrap();
+        layout::find(&sys.base_dir, &sys.data_dir, name)
+    };
+    let Some(windows) = found else {
+        warn!(target: "window", "⚠️ [WINDOW] Unknown layout '{}'", name);
+        return;
+    };
+    let mut placed = HashSet::new();
+    let mut missing = Vec::new();
+    for entry in &windows {
+        let open = app_state.session.iter().map(|(wid, page)| (*wid, page)).find(|(wid, page)| **page == entry.page && !placed.contains(wid));
+        match open.and_then(|(wid, _)| app_state.webviews.get(&wid).map(|wv| (wid, wv))) {
+            Some((wid, webview)) => {
+                placed.insert(wid);
+                arrange_window(event_loop, webview.window(), entry);
+            }
+            None => missing.push(entry),
+        }
+    }
+    if !missing.is_empty() {
+        let before = app_state.session.len();
+        let requests: Vec<window::SessionWindow> = missing.iter().map(|entry| window::SessionWindow::page(&entry.page)).collect();
+        let proxy = app_state.main_proxy.clone();
+        if let Err(e) = open_startup_windows(event_loop, app_state, contexts, &requests, proxy) {
+            error!(target: "window", "❌ [WINDOW] Cannot open layout '{}': {}", name, e);
+        }
+        let mut opened: Vec<(WindowId, String)> = app_state.session[before..].to_vec();
+        for entry in missing {
+            let Some(i) = opened.iter().position(|(_, page)| *page == entry.page) else { continue };
+            let (wid, _) = opened.remove(i);
+            if let Some(webview) = app_state.webviews.get(&wid) { arrange_window(event_loop, webview.window(), entry); }
+        }
+    }
+    info!(target: "window", "🪟 [WINDOW] Layout '{}' applied ({} window(s))", name, windows.len());
+}
+
+// Logical size of each monitor, in the OS's order (the position formulas' screen_w_<i>/screen_h_<i>)
+fn monitor_sizes(event_loop: &EventLoopWindowTarget<FrontierEvent>) -> Vec<(f64, f64)> {
+    event_loop.available_monitors().map(|m| {
+        let size = m.size().to_logical::<f64>(m.scale_factor());
+        (size.width, size.height)
+    }).collect()
+}
+
+// Every monitor for Frontier.monitors.list(), in the OS's order (the index of frontier-monitor
+// and of layouts); positions and sizes are logical, like window sizes
+fn monitor_list(event_loop: &EventLoopWindowTarget<FrontierEvent>, window: &Window) -> serde_json::Value {
+    let primary = event_loop.primary_monitor();
+    let current = window.current_monitor();
+    event_loop.available_monitors().enumerate().map(|(index, monitor)| {
+        let scale = monitor.scale_factor();
+        let position = monitor.position().to_logical::<f64>(scale);
+        let size = monitor.size().to_logical::<f64>(scale);
+        serde_json::json!({
+            "index": index,
+            "name": monitor.name(),
+            "x": position.x,
+            "y": position.y,
+            "width": size.width,
+            "height": size.height,
+            "scale": scale,
+            "primary": primary.as_ref() == Some(&monitor),
+            "current": current.as_ref() == Some(&monitor),
+        })
+    }).collect()
+}
+
+// Move and resize a window to its place in a layout
+fn arrange_window(event_loop: &EventLoopWindowTarget<FrontierEvent>, win: &Window, entry: &config::LayoutWindow) {
+    win.set_maximized(false);
+    let current = win.inner_size().to_logical::<f64>(win.scale_factor());
+    let size = (entry.width.unwrap_or(current.width), entry.height.unwrap_or(current.height));
+    win.set_inner_size(LogicalSize::new(size.0, size.1));
+    let monitor = entry.monitor.and_then(|i| event_loop.available_monitors().nth(i)).or_else(|| event_loop.primary_monitor());
+    if let Some(monitor) = monitor {
+        let scale = monitor.scale_factor();
+        let origin = monitor.position().to_logical::<f64>(scale);
+        let screen = monitor.size().to_logical::<f64>(scale);
+        if let Some((x, y)) = layout::place(entry, (screen.width, screen.height), size, &monitor_sizes(event_loop)) {
+            win.set_outer_position(LogicalPosition::new(origin.x + x, origin.y + y));
+        }
+    }
+    if entry.maximized.unwrap_or(false) { win.set_maximized(true); }
+}
+
+// The open windows as a layout, each relative to the monitor it's on
+fn current_layout(event_loop: &EventLoopWindowTarget<FrontierEvent>, app_state: &AppState) -> Vec<config::LayoutWindow> {
+    let monitors: Vec<_> = event_loop.available_monitors().collect();
+    app_state.session.iter().filter_map(|(wid, page)| {
+        let win = app_state.webviews.get(wid)?.window();
+        let bounds = normal_bounds(app_state, wid, win);
+        let on = win.current_monitor().and_then(|m| {
+            let index = monitors.iter().position(|other| *other == m)?;
+            Some((index, m.position().to_logical::<f64>(m.scale_factor())))
+        });
+        let (origin_x, origin_y) = on.as_ref().map(|(_, origin)| (origin.x, origin.y)).unwrap_or((0.0, 0.0));
+        Some(config::LayoutWindow {
+            page: page.clone(),
+            monitor: on.map(|(index, _)| index),
+            x: Some((bounds.x - origin_x).round().to_string()),
+            y: Some((bounds.y - origin_y).round().to_string()),
+            width: Some(bounds.width),
+            height: Some(bounds.height),
+            maximized: Some(win.is_maximized()),
+        })
+    }).collect()
+}
+
+fn store_layout(app_state: &AppState, name: &str, windows: Vec<config::LayoutWindow>) {
+    let data_dir = app_state.system.lock().unwrap().data_dir.clone();
+    let count = windows.len();
+    match layout::store(&data_dir, name, windows) {
+        Ok(()) => info!(target: "window", "🪟 [WINDOW] Layout '{}' saved ({} window(s))", name, count),
+        Err(e) => warn!(target: "window", "⚠️ [WINDOW] Cannot save layout '{}': {}", name, e),
+    }
+}
+
+// Send shared state changes to the windows that subscribed to them
+fn broadcast_state(app_state: &AppState, changes: Vec<shared::Change>) {
+    if changes.is_empty() { return; }
+    let is_dev = app_state.system.lock().unwrap().is_dev;
+    let js = format!("if(window.Frontier && window.Frontier.__stateChanged) window.Frontier.__stateChanged({})", serde_json::to_string(&changes).unwrap_or_default());
+    for wid in &app_state.state_subscribers {
+        if let Some(webview) = app_state.webviews.get(wid) { run_script(webview, &js, is_dev); }
+    }
+}
+
+// Window an automation command targets: page id or window key, or else the oldest window
+fn automation_target<'a>(app_state: &'a AppState, window: Option<&str>) -> Option<&'a WebView> {
+    let wid = match window {
+        Some(name) => app_state.webviews.keys().find(|wid| {
+            let key = format!("{:?}", wid);
+            key == name || inspect::window_label(&key) == name
+        }).copied(),
+        None => app_state.session.first().map(|(wid, _)| *wid),
+    };
+    wid.and_then(|wid| app_state.webviews.get(&wid))
+}
+
+// Evaluate a script in a page; in dev, exceptions and failures show up in the error overlay
+fn run_script(webview: &WebView, js: &str, is_dev: bool) {
+    let script = if is_dev {
+        format!("try {{ {} }} catch (e) {{ if(window.Frontier && window.Frontier.__overlay) window.Frontier.__overlay('Script', String(e && e.stack || e)); }}", js)
+    } else {
+        js.to_string()
+    };
+    if let Err(e) = webview.evaluate_script(&script) {
+        error!(target: "window", "❌ [WINDOW] Script failed: {}", e);
+    }
+}
+
+// Dev-only overlay listing problems in the affected window
+fn show_dev_error(webview: &WebView, source: &str, message: &str) {
+    let js = format!(
+        "if(window.Frontier && window.Frontier.__overlay) window.Frontier.__overlay({}, {})",
+        serde_json::to_string(source).unwrap_or_default(),
+        serde_json::to_string(message).unwrap_or_default()
+    );
+    let _ = webview.evaluate_script(&js);
+}
+
+// [i18n] locale bundle for Frontier.i18n
+fn load_i18n(base_dir: &Path, is_dev: bool) -> serde_json::Value {
+    let cfg = config::load_i18n_config(&base_dir.join("frontier.toml"));
+    i18n::load_bundle(&i18n::locales_dir(base_dir, &cfg, is_dev), &cfg)
+}
+
+// [dev] frontend_url, normalized without a trailing slash
+fn dev_server_url(config_path: &Path) -> Option<String> {
+    config::load_dev_config(config_path)
+        .frontend_url
+        .map(|u| u.trim_end_matches('/').to_string())
+        .filter(|u| !u.is_empty())
+}
+
+fn is_config_file(path: &Path) -> bool {
+    let name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+    name.starts_with("frontier") && name.ends_with(".toml")
+}
+
+// Re-read frontier.toml in dev and apply the settings that can change live
+//
+// Whitelists, window defaults and per-page overrides are used by every window
+// opened afterwards; open windows are notified with a 'config-changed' event.
+fn reload_runtime_config(app: &AppState) {
+    let mut sys = app.system.lock().unwrap();
+    let config_path = sys.base_dir.join("frontier.toml");
+    let Some(content) = config::read_config(&config_path) else {
+        error!(target: "config", "❌ [CONFIG] Reload skipped, keeping the previous settings");
+        return;
+    };
+    if let Err(e) = toml::from_str::<config::FrontierToml>(&content) {
+        error!(target: "config", "❌ [CONFIG] Reload skipped, keeping the previous settings:\n{}", e);
+        return;
+    }
+
+    // Open windows read the shared rules on every navigation, so they follow the new whitelists
+    *sys.security.write().unwrap() = SecurityRules::new(config::load_security_config(&config_path));
+    sys.window_defaults = load_window_defaults(&config_path);
+    sys.window_overrides = config::load_window_overrides(&config_path);
+    sys.dev_frontend_url = dev_server_url(&config_path);
+    sys.network = config::load_network_config(&config_path);
+    let backend = config::load_backend_config(&config_path);
+    // New limits apply to calls made from now on; running calls keep their slots
+    if (backend.max_concurrent, backend.max_queue) != (sys.backend.max_concurrent, sys.backend.max_queue) {
+        sys.scheduler = Arc::new(system::Scheduler::new(backend.max_concurrent, backend.max_queue));
+    }
+    sys.backend = backend;
+    sys.workers.set_size(sys.backend.pool.unwrap_or(0));
+    sys.i18n = load_i18n(&sys.base_dir, true);
+    sys.features = config::load_features(&config_path);
+    sys.accessibility = config::load_accessibility_config(&config_path);
+    sys.keyboard = config::load_keyboard_config(&config_path);
+    sys.frontend = config::load_frontend_config(&config_path);
+    sys.mime_types = config::load_mime_types(&config_path);
+    info!(target: "config", "🔄 [CONFIG] Reloaded {}", config_path.display());
+    inspect::record_event("config-reloaded", &config_path.display().to_string());
+    publish_runtime_state(&sys);
+
+    for webview in app.webviews.values() {
+        run_script(webview, "if(window.Frontier) window.Frontier.dispatch('config-changed', 'frontier.toml')", true);
+    }
+}
+
+// A file in app/backend changed: re-register triggers (files may have been
+// added or renamed) and dispatch 'backend-changed' with the trigger name
+fn backend_changed(app: &AppState, path: &Path) {
+    let trigger = path.file_stem().map(|s| s.to_string_lossy().to_string()).unwrap_or_default();
+    {
+        let mut sys = app.system.lock().unwrap();
+        let (commands, _modules_map) = scan_environment(&sys.base_dir, Path::new(""), true);
+        sys.commands = commands;
+        #[cfg(debug_assertions)]
+        { sys.modules_map = _modules_map; }
+        publish_runtime_state(&sys);
+        // Warm workers would keep running the old modules the script imports
+        sys.workers.recycle();
+        warm_worker_pool(&sys, &app.runtime);
+    }
+    info!(target: "backend", "🔄 [BACKEND] {} changed", trigger);
+    inspect::record_event("backend-changed", &trigger);
+
+    let js = format!(
+        "if(window.Frontier) window.Frontier.dispatch('backend-changed', {})",
+        serde_json::to_string(&trigger).unwrap_or_default()
+    );
+    for webview in app.webviews.values() {
+        run_script(webview, &js, true);
+    }
+}
+
+// Quitting: the pool's idle workers go, running backends get [backend] shutdown_timeout to stop
+fn stop_backends(app_state: &AppState) {
+    let (workers, children, grace) = {
+        let sys = app_state.system.lock().unwrap();
+        (sys.workers.clone(), sys.children.clone(), sys.backend.shutdown_timeout.unwrap_or(DEFAULT_SHUTDOWN_TIMEOUT))
+    };
+    workers.shutdown();
+    children.shutdown(Duration::from_secs(grace));
+}
+
+// The version that ran before, when [app] version changed since the last start
+// (the first start isn't an update)
+fn updated_from(base_dir: &Path, data_dir: &Path) -> Option<String> {
+    let current = config::load_config(&base_dir.join("frontier.toml")).version?;
+    let file = data_dir.join(VERSION_FILE);
+    let previous = fs::read_to_string(&file).ok().map(|v| v.trim().to_string());
+    if previous.as_deref() != Some(current.as_str()) {
+        let _ = fs::write(&file, &current);
+    }
+    previous.filter(|p| *p != current)
+}
+
+// Run a [lifecycle] trigger and wait for it (the [backend] timeout applies); state
+// patches it prints are applied, and a failure is logged without stopping the app
+fn run_lifecycle_hook(app_state: &AppState, hook: &str, trigger: &str, args: &str) {
+    info!(target: "backend", "🔁 [LIFECYCLE] {}: {}", hook, trigger);
+    let job = system::BackendJob::new(&app_state.system.lock().unwrap(), trigger, args);
+    let res = match job {
+        Ok(job) => app_state.runtime.block_on(system::run_backend(job, Arc::new(tokio::sync::Notify::new()), None)),
+        Err(res) => res,
+    };
+    let (reply, patches) = shared::take_patches(&res.reply);
+    if !patches.is_empty() {
+        let mut sys = app_state.system.lock().unwrap();
+        for patch in patches { shared::merge(&mut sys.shared_state, patch); }
+    }
+    match res.error {
+        Some(err) => warn!(target: "backend", "⚠️ [LIFECYCLE] {} hook '{}' failed: {}", hook, trigger, err),
+        None if !reply.trim().is_empty() => info!(target: "backend", "🔁 [LIFECYCLE] {} hook '{}': {}", hook, trigger, reply.trim()),
+        None => {}
+    }
+}
+
+// Start the [backend] pool workers for every interpreter used by a `worker` module
+fn warm_worker_pool(sys: &system::SystemState, runtime: &tokio::runtime::Runtime) {
+    if !sys.workers.enabled() { return; }
+    let mut interpreters: Vec<(String, String)> = sys.commands.values()
+        .filter_map(|m| Some((m.worker.clone()?, m.interpreter.clone()?)))
+        .collect();
+    interpreters.sort();
+    interpreters.dedup();
+    for (kind, interpreter) in interpreters {
+        let pool = sys.workers.clone();
+        runtime.spawn(async move { pool.warm(&kind, &interpreter).await });
+    }
+}
+
+// [window] with the app name as the fallback title, so windows of untitled
+// pages still have a name in the taskbar and for screen readers
+fn load_window_defaults(config_path: &Path) -> config::WindowConfig {
+    let mut defaults = config::load_window_config(config_path).unwrap_or_default();
+    if defaults.title.is_none() {
+        defaults.title = config::load_config(config_path).name;
+    }
+    defaults
+}
+
+// Page zoom from [accessibility]: the OS text size (follow_text_scale), never below min_font_scale
+fn page_zoom(sys: &system::SystemState) -> f64 {
+    let text_scale = if sys.accessibility.follow_text_scale.unwrap_or(false) { sys.os_accessibility.text_scale } else { 1.0 };
+    text_scale.max(sys.accessibility.min_font_scale.unwrap_or(1.0))
+}
+
+// Hand the settings currently in effect to the dev state endpoint (__state)
+fn publish_runtime_state(sys: &system::SystemState) {
+    let triggers: serde_json::Map<String, serde_json::Value> = sys.commands.iter()
+        .map(|(trigger, meta)| (trigger.clone(), serde_json::json!({ "file": meta.filename, "interpreter": meta.interpreter })))
+        .collect();
+    let security = sys.security.read().unwrap();
+    let mut secured_windows: Vec<&String> = security.config.windows.keys().collect();
+    secured_windows.sort();
+    inspect::set_runtime(serde_json::json!({
+        "scheme": sys.scheme,
+        "base_dir": sys.base_dir,
+        "data_dir": sys.data_dir,
+        "config_dir": sys.config_dir,
+        "cache_dir": sys.cache_dir,
+        "dev_frontend_url": sys.dev_frontend_url,
+        "devtools": sys.devtools,
+        "locale": sys.i18n["locale"],
+        "features": sys.features,
+        "allowed_internal": security.config.allowed_internal,
+        "allowed_browser": security.config.allowed_browser,
+        "secured_windows": secured_windows,
+        "triggers": triggers,
+    }));
+}
+
 // Routes URLs to the system browser with atomic deduplication to prevent duplicate opens
 // 
 // This function prevents the same URL from being opened multiple times within a short timeframe,
@@ -406,7 +2938,7 @@ fn create_new_window(
 // 
 // # Arguments
 // * `url` - The full URL to open in the system browser
-fn route_to_browser(url: &str, is_dev: bool) {
+fn route_to_browser(url: &str) {
     let mut lock = BROWSER_LOCK.lock().unwrap();
     let now = Instant::now();
     
@@ -423,7 +2955,7 @@ fn route_to_browser(url: &str, is_dev: bool) {
     // If the same base URL was opened within the last 2 seconds, ignore this request
     // This prevents duplicate tabs when redirect chains or multiple handlers fire for the same URL
     if lock.0 == base_url && now.duration_since(lock.1) < Duration::from_millis(2000) {
-        if is_dev { eprintln!("⏱️ [BROWSER] Deduped (within 2s): {}", base_url); }
+        debug!(target: "browser", "⏱️ [BROWSER] Deduped (within 2s): {}", base_url);
         return;
     }
     
@@ -431,72 +2963,101 @@ fn route_to_browser(url: &str, is_dev: bool) {
     lock.0 = base_url.to_string();
     lock.1 = now;
     
-    if is_dev { eprintln!("🌐 [BROWSER] Opening: {}", url); }
+    info!(target: "browser", "🌐 [BROWSER] Opening: {}", url);
     let _ = webbrowser::open(url);
 }
 
-fn get_url_category(url: &str, internal: &[String], browser: &[String]) -> UrlCategory {
-    if url.starts_with("frontier://") || url.starts_with("https://frontier.") || url == "about:blank" {
-        eprintln!("📍 [ROUTING] Frontier: {}", url);
+// App page URL for the custom protocol: <scheme>://app/... (https://<scheme>.app/... on Windows)
+fn is_app_url(url: &str, scheme: &str) -> bool {
+    url.starts_with(&format!("{}://", scheme)) || url.starts_with(&format!("https://{}.", scheme))
+}
+
+// Page file of a request and the query string and fragment that follow it
+fn split_page_request(request: &str) -> (&str, &str) {
+    request.split_at(request.find(['?', '#']).unwrap_or(request.len()))
+}
+
+// <scheme>://app/popup.html -> popup.html
+fn page_from_app_url(url: &str, scheme: &str) -> String {
+    let rest = url
+        .strip_prefix(&format!("{}://", scheme))
+        .or_else(|| url.strip_prefix(&format!("https://{}.", scheme)))
+        .unwrap_or(url);
+    rest.strip_prefix("app/").unwrap_or(rest).to_string()
+}
+
+fn get_url_category(url: &str, scheme: &str, internal: &[&UrlWhitelist], browser: &[&UrlWhitelist]) -> UrlCategory {
+    if is_app_url(url, scheme) || url == "about:blank" {
+        debug!(target: "routing", "📍 [ROUTING] Frontier: {}", url);
         return UrlCategory::Frontier;
     }
-    if is_url_allowed(url, internal) { 
-        eprintln!("📍 [ROUTING] Internal (whitelisted): {}", url);
+    if internal.iter().any(|w| w.matches(url)) { 
+        debug!(target: "routing", "📍 [ROUTING] Internal (whitelisted): {}", url);
         return UrlCategory::Internal; 
     }
-    if is_url_allowed(url, browser) { 
-        eprintln!("📍 [ROUTING] Browser (whitelisted): {}", url);
+    if browser.iter().any(|w| w.matches(url)) { 
+        debug!(target: "routing", "📍 [ROUTING] Browser (whitelisted): {}", url);
         return UrlCategory::Browser; 
     }
-    eprintln!("📍 [ROUTING] Blocked: {}", url);
+    debug!(target: "routing", "📍 [ROUTING] Blocked: {}", url);
     UrlCategory::Blocked
 }
 
-fn is_url_allowed(url: &str, whitelist: &[String]) -> bool {
-    let base_url = url.split('?').next().unwrap_or(url).split('#').next().unwrap_or(url);
-    let clean_url = base_url.trim_end_matches('/');
-    
-    for pattern in whitelist {
-        let has_wildcard = pattern.ends_with('*');
-        let base_pattern = pattern.trim_end_matches('*').trim_end_matches('/');
-        let regex_pattern = base_pattern.replace(".", "\\.").replace("/", "\\/");
-        
-        // If pattern has wildcard: allow base path and any subpaths (e.g., https://kaiohsg.dev/*)
-        // If pattern has no wildcard: allow only exact URL (e.g., https://kaiohsg.dev)
-        let final_regex = if has_wildcard {
-            format!(r"^{}(/.*)?\/?$", regex_pattern)
-        } else {
-            format!(r"^{}\/?$", regex_pattern)
-        };
-        
-        if let Ok(re) = regex::Regex::new(&final_regex) {
-            if re.is_match(base_url) || re.is_match(clean_url) { return true; }
-        }
-    }
-    false
-}
-
-fn setup_paths(is_dev: bool) -> Result<(PathBuf, PathBuf, PathBuf), Box<dyn std::error::Error>> {
+// App folder (the project in dev, the extracted runtime folder in release) and
+// the app's data, config and cache folders
+fn setup_paths(is_dev: bool) -> Result<(PathBuf, platform::ProjectDirs), Box<dyn std::error::Error>> {
     let root = std::env::current_dir()?;
-    if is_dev {
-        let data = root.join(".frontier").join("target").join("dev_profile");
-        let cache = root.join(".frontier").join("target").join("dev_cache");
-        let _ = fs::create_dir_all(&data);
-        let _ = fs::create_dir_all(&cache);
-        Ok((root, data, cache))
+    let (base, dirs) = if is_dev {
+        let target = root.join(".frontier").join("target");
+        let dirs = platform::ProjectDirs { data: target.join("dev_profile"), config: target.join("dev_config"), cache: target.join("dev_cache") };
+        (root, dirs)
     } else {
-        let base = std::env::temp_dir().join("frontier_rt_v1");
+        // Named after [app] qualifier/organization/name, so apps never share folders
+        let bundled = embedded::bundled_config();
+        let mut dirs = platform::ProjectDirs::new(
+            &bundled.as_ref().and_then(|c| c.app.clone()).unwrap_or_default(),
+            &bundled.as_ref().and_then(|c| c.macos.clone()).unwrap_or_default(),
+        );
+        let base = dirs.cache.join("runtime");
         let _ = fs::create_dir_all(&base);
-        let local = std::env::var("LOCALAPPDATA").unwrap_or_else(|_| ".".into());
-        let data = Path::new(&local).join("FrontierData").join("App");
-        let _ = fs::create_dir_all(&data);
-        for file in Assets::iter() {
-            let dest = base.join(file.as_ref());
-            if let Some(p) = dest.parent() { let _ = fs::create_dir_all(p); }
-            if let Some(c) = Assets::get(file.as_ref()) { let _ = fs::write(&dest, c.data.as_ref()); }
-        }
-        Ok((base, data, PathBuf::new()))
+        // Frontend files are served from memory and backends are written on first use
+        let started = Instant::now();
+        embedded::extract_startup(&base);
+        perf::record("extract-assets", &base.display().to_string(), started);
+        if let Some(data) = resolve_data_dir(&base.join("frontier.toml")) { dirs.data = data; }
+        (base, dirs)
+    };
+    for dir in [&dirs.data, &dirs.config, &dirs.cache] {
+        let _ = fs::create_dir_all(dir);
     }
+    Ok((base, dirs))
+}
+
+// Release data directory from [storage] data_dir, if set
+fn resolve_data_dir(config_path: &Path) -> Option<PathBuf> {
+    let storage = config::load_storage_config(config_path);
+    let template = storage.data_dir?;
+
+    let app_name = config::load_config(config_path).name.unwrap_or_else(|| "App".into());
+    let exe_dir = std::env::current_exe().ok()
+        .and_then(|p| p.parent().map(|d| d.to_path_buf()))
+        .unwrap_or_else(|| PathBuf::from("."));
+    let home = platform::home_dir().to_string_lossy().to_string();
+
+    let re = regex::Regex::new(r"%([A-Za-z_][A-Za-z0-9_]*)%").unwrap();
+    let expanded = re.replace_all(&template, |caps: &regex::Captures| {
+        match &caps[1] {
+            "APPNAME" => app_name.clone(),
+            "EXEDIR" => exe_dir.to_string_lossy().to_string(),
+            "HOME" => home.clone(),
+            // Windows-only variables map to the per-user data folder elsewhere
+            var @ ("APPDATA" | "LOCALAPPDATA") => std::env::var(var).unwrap_or_else(|_| platform::data_home().to_string_lossy().to_string()),
+            var => std::env::var(var).unwrap_or_default(),
+        }
+    }).to_string();
+
+    let path = PathBuf::from(expanded);
+    Some(if path.is_absolute() { path } else { exe_dir.join(path) })
 }
 
 fn scan_environment(base: &Path, _cache: &Path, is_dev: bool) -> (HashMap<String, system::RuntimeMeta>, HashMap<String, system::ModuleManifest>) {
@@ -530,24 +3091,19 @@ fn scan_environment(base: &Path, _cache: &Path, is_dev: bool) -> (HashMap<String
                             trigger: stem, 
                             filename: p.to_string_lossy().to_string(), 
                             interpreter: m.interpreter.clone(), 
-                            suppress_window: m.suppress_window 
+                            suppress_window: m.suppress_window,
+                            worker: m.worker.clone(),
+                            timeout_ms: m.timeout_ms,
+                            args_mode: m.args_mode.clone(),
+                            serial: m.serial,
                         });
                     }
                 }
             }
         }
     } else {
-        if let Ok(entries) = fs::read_dir(base) {
-            for entry in entries.flatten() {
-                if entry.path().to_string_lossy().ends_with(".meta.json") {
-                    if let Ok(c) = fs::read_to_string(entry.path()) {
-                        if let Ok(m) = serde_json::from_str::<system::RuntimeMeta>(&c) {
-                            cmds.insert(m.trigger.clone(), m);
-                        }
-                    }
-                }
-            }
-        }
+        // Release: the command table generated by `frontier build`, read from memory
+        cmds = embedded::commands();
     }
     (cmds, mods)
 }
@@ -556,32 +3112,124 @@ fn save_window_state(wid: &WindowId, app: &AppState) {
     if let (Some(p), Some(wv)) = (app.persistence.get(wid), app.webviews.get(wid)) {
         if !p.should_save { return; }
         let win = wv.window();
-        let scale = win.scale_factor();
         let is_max = win.is_maximized();
 
-        let mut final_x = 0.0; let mut final_y = 0.0;
-        let mut final_w = 800.0; let mut final_h = 600.0;
-
+        let mut state = window::WindowState { x: 0.0, y: 0.0, width: 800.0, height: 600.0, maximized: is_max, monitor: None, view: window::PageView::default() };
         if is_max {
-            if let Ok(old_json) = fs::read_to_string(&p.save_file) {
+            if let Some(bounds) = app.bounds.get(wid) {
+                state = bounds.clone();
+            } else if let Ok(old_json) = fs::read_to_string(&p.save_file) {
                 if let Ok(old) = serde_json::from_str::<window::WindowState>(&old_json) {
-                    final_x = old.x; final_y = old.y; final_w = old.width; final_h = old.height;
+                    state = old;
                 }
             }
+            state.maximized = true;
         } else {
-            let pos = win.outer_position().unwrap_or_default().to_logical::<f64>(scale);
-            let size = win.inner_size().to_logical::<f64>(scale);
-            final_x = pos.x; final_y = pos.y; final_w = size.width; final_h = size.height;
+            state = current_bounds(win);
         }
+        state.monitor = win.current_monitor().and_then(|m| m.name());
+        state.view = app.views.get(wid).cloned().unwrap_or_default();
 
-        let state = window::WindowState { x: final_x, y: final_y, width: final_w, height: final_h, maximized: is_max };
         if let Ok(j) = serde_json::to_string(&state) { let _ = fs::write(&p.save_file, j); }
     }
 }
 
-fn load_application_icon(base: &Path) -> Option<Icon> {
-    let p = base.join("assets").join("app_icon.png");
-    if p.exists() { load_icon_from_disk(&p) } else { None }
+// Position and size of a window, in logical pixels
+fn current_bounds(win: &Window) -> window::WindowState {
+    let scale = win.scale_factor();
+    let pos = win.outer_position().unwrap_or_default().to_logical::<f64>(scale);
+    let size = win.inner_size().to_logical::<f64>(scale);
+    window::WindowState { x: pos.x, y: pos.y, width: size.width, height: size.height, maximized: false, monitor: None, view: window::PageView::default() }
+}
+
+// Where a window is, or was before it was maximized or minimized
+fn normal_bounds(app: &AppState, wid: &WindowId, win: &Window) -> window::WindowState {
+    match app.bounds.get(wid) {
+        Some(bounds) if win.is_maximized() || win.is_minimized() => bounds.clone(),
+        _ => current_bounds(win),
+    }
+}
+
+// Remember where a window is while it's neither maximized nor minimized, the
+// bounds it gets back when restored maximized
+fn track_bounds(app: &mut AppState, wid: WindowId) {
+    let Some(win) = app.webviews.get(&wid).map(|wv| wv.window()) else { return };
+    if win.is_maximized() || win.is_minimized() { return; }
+    app.bounds.insert(wid, current_bounds(win));
+}
+
+// Windows opened at launch: the windows open when the app last closed (with
+// [window] restore_session), else the pages of [window] startup, else index.html
+fn startup_windows(sys: &system::SystemState) -> Vec<window::SessionWindow> {
+    if sys.window_defaults.restore_session.unwrap_or(false) {
+        let json = fs::read_to_string(sys.data_dir.join(SESSION_FILE)).unwrap_or_default();
+        // Sessions saved before geometry was kept are plain lists of pages
+        let saved = serde_json::from_str::<Vec<window::SessionWindow>>(&json)
+            .or_else(|_| serde_json::from_str::<Vec<String>>(&json).map(|pages| pages.iter().map(|p| window::SessionWindow::page(p)).collect()))
+            .unwrap_or_default();
+        if !saved.is_empty() { return saved; }
+    }
+    match &sys.window_defaults.startup {
+        Some(pages) if !pages.is_empty() => pages.iter().map(|p| window::SessionWindow::page(p)).collect(),
+        _ => vec![window::SessionWindow::page("index.html")],
+    }
+}
+
+// Remember the open windows, with their position, size and monitor, for the
+// next launch ([window] restore_session)
+fn save_session(app: &AppState) {
+    let file = {
+        let sys = app.system.lock().unwrap();
+        if !sys.window_defaults.restore_session.unwrap_or(false) { return; }
+        sys.data_dir.join(SESSION_FILE)
+    };
+    let windows: Vec<window::SessionWindow> = app.session.iter().filter(|(wid, _)| !app.dialogs.contains_key(wid)).map(|(wid, page)| {
+        let state = app.webviews.get(wid).map(|wv| {
+            let win = wv.window();
+            let mut state = normal_bounds(app, wid, win);
+            state.maximized = win.is_maximized();
+            state.monitor = win.current_monitor().and_then(|m| m.name());
+            state.view = app.views.get(wid).cloned().unwrap_or_default();
+            state
+        });
+        window::SessionWindow { page: page.clone(), state }
+    }).collect();
+    if let Ok(json) = serde_json::to_string(&windows) { let _ = fs::write(file, json); }
+}
+
+// Default window icon: [window] icon from frontier.toml in dev, the bundled app_icon in release
+fn load_application_icon(base: &Path, is_dev: bool, window_defaults: &config::WindowConfig) -> Option<Icon> {
+    if is_dev {
+        if let Some(icon) = &window_defaults.icon {
+            return load_icon_from_disk(&base.join(icon));
+        }
+    }
+    ["app_icon.png", "app_icon.ico"]
+        .iter()
+        .map(|name| base.join(name))
+        .find(|p| p.exists())
+        .and_then(|p| load_icon_from_disk(&p))
+}
+
+// [tray] icon: from frontier.toml in dev, the bundled tray_icon in release (None: the app icon)
+fn load_tray_icon(base: &Path, is_dev: bool, tray: &config::TrayConfig) -> Option<Icon> {
+    if is_dev {
+        return tray.icon.as_ref().and_then(|icon| load_icon_from_disk(&base.join(icon)));
+    }
+    ["tray_icon.png", "tray_icon.ico"]
+        .iter()
+        .map(|name| base.join(name))
+        .find(|p| p.exists())
+        .and_then(|p| load_icon_from_disk(&p))
+}
+
+// A file of app/frontend: read in place in dev, extracted from the bundle in release
+fn frontend_file(base: &Path, is_dev: bool, path: &str) -> Option<PathBuf> {
+    if is_dev {
+        Some(base.join("app/frontend").join(path))
+    } else {
+        embedded::extract(base, &format!("frontend/{}", path)).ok()
+    }
 }
 
 fn load_icon_from_disk(path: &Path) -> Option<Icon> {
@@ -0,0 +1,123 @@
+// Copyright (c) 2026 The Frontier Framework Authors
+// SPDX-License-Identifier: Apache-2.0 WITH LLVM-exception OR MIT
+
+// Crash Reporting Module
+//
+// This module installs a panic hook that writes a crash report (message,
+// location, backtrace, open windows and the last log lines) to
+// <data_dir>/crashes and offers to open it. Fatal startup errors go through
+// the same path, so users always have a file to attach to a bug report.
+// The dialog is in the app's language (see i18n.rs); the report stays in English.
+// Panics on other threads (a backend task) are contained and the app keeps
+// running, so they only get the report, unless panics abort the process; macOS
+// can't show dialogs off the main thread at all.
+
+use native_dialog::{MessageDialog, MessageType};
+use std::backtrace::Backtrace;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::i18n;
+use crate::inspect;
+use crate::logging;
+
+static CRASH_DIR: Mutex<Option<PathBuf>> = Mutex::new(None);
+
+// Install the panic hook; reports go to <data_dir>/crashes
+pub fn install(data_dir: &Path) {
+    if let Ok(mut dir) = CRASH_DIR.lock() {
+        *dir = Some(data_dir.join("crashes"));
+    }
+
+    let previous = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        let message = if let Some(s) = info.payload().downcast_ref::<&str>() {
+            s.to_string()
+        } else if let Some(s) = info.payload().downcast_ref::<String>() {
+            s.clone()
+        } else {
+            "Unknown panic".to_string()
+        };
+        let location = info.location().map(|l| format!("{}:{}", l.file(), l.line())).unwrap_or_default();
+        let thread = std::thread::current().name().unwrap_or("unnamed").to_string();
+
+        tracing::error!(target: "app", "💥 [CRASH] {} ({})", message, location);
+        previous(info);
+        let details = format!("Panic: {}\nLocation: {}\nThread: {}", message, location, thread);
+        let main_thread = thread == "main";
+        let fatal = main_thread || cfg!(panic = "abort");
+        if fatal && (main_thread || !cfg!(target_os = "macos")) {
+            report(&i18n::message("crashed", &[]), &details, Some(Backtrace::force_capture()));
+        } else if let Some(path) = write_report(&details, Some(Backtrace::force_capture())) {
+            tracing::error!(target: "app", "💥 [CRASH] Report saved to {}", path.display());
+        }
+    }));
+}
+
+// Write a crash report and tell the user where it is
+pub fn report(title: &str, details: &str, backtrace: Option<Backtrace>) {
+    let path = write_report(details, backtrace);
+
+    let text = match &path {
+        Some(p) => format!("{}.\n\n{}\n\n{}", title, first_line(details), i18n::message("report_saved", &[("path", &p.display().to_string())])),
+        None => format!("{}.\n\n{}", title, details),
+    };
+
+    let open = MessageDialog::new()
+        .set_type(MessageType::Error)
+        .set_title(&i18n::message("error_title", &[]))
+        .set_text(&text)
+        .show_confirm()
+        .unwrap_or(false);
+
+    if let (true, Some(p)) = (open, path) {
+        open_file(&p);
+    }
+}
+
+fn write_report(details: &str, backtrace: Option<Backtrace>) -> Option<PathBuf> {
+    let dir = CRASH_DIR.lock().ok()?.clone().unwrap_or_else(|| std::env::temp_dir().join("frontier_crashes"));
+    fs::create_dir_all(&dir).ok()?;
+
+    let timestamp = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0);
+    let path = dir.join(format!("crash-{}.txt", timestamp));
+
+    let windows = inspect::window_summaries()
+        .iter()
+        .map(|w| format!("  - {}", w))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let mut report = format!(
+        "Frontier crash report\nTime (unix): {}\nVersion: {}\nOS: {} ({})\n\n{}\n",
+        timestamp,
+        env!("CARGO_PKG_VERSION"),
+        std::env::consts::OS,
+        std::env::consts::ARCH,
+        details
+    );
+    report.push_str(&format!("\nOpen windows:\n{}\n", if windows.is_empty() { "  (none)" } else { &windows }));
+    if let Some(bt) = backtrace {
+        report.push_str(&format!("\nBacktrace:\n{}\n", bt));
+    }
+    report.push_str(&format!("\nLast log lines:\n{}\n", logging::recent_lines().join("\n")));
+
+    fs::write(&path, report).ok()?;
+    Some(path)
+}
+
+fn first_line(text: &str) -> &str {
+    text.lines().next().unwrap_or("")
+}
+
+fn open_file(path: &Path) {
+    #[cfg(target_os = "windows")]
+    let _ = Command::new("cmd").args(["/C", "start", ""]).arg(path).spawn();
+    #[cfg(target_os = "macos")]
+    let _ = Command::new("open").arg(path).spawn();
+    #[cfg(not(any(target_os = "windows", target_os = "macos")))]
+    let _ = Command::new("xdg-open").arg(path).spawn();
+}
@@ -0,0 +1,280 @@
+// Copyright (c) 2026 The Frontier Framework Authors
+// SPDX-License-Identifier: Apache-2.0 WITH LLVM-exception OR MIT
+
+// Doctor Module
+//
+// This module diagnoses the development environment (toolchain, WebView2 or
+// WebKitGTK, module interpreters/compilers, frontier.toml and asset paths) and prints
+// an actionable fix for every problem it finds.
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::backend;
+use crate::config;
+
+// Result of a single diagnostic check
+enum Status {
+    Ok(String),
+    Warn(String, String),
+    Fail(String, String),
+}
+
+// Run every check, print the report and return the number of failures
+pub fn run_doctor(app_dir: &Path, modules_dir: &Path, config_path: &Path) -> usize {
+    println!("🩺 [DOCTOR] Checking environment...\n");
+
+    let mut checks = vec![
+        check_tool("cargo", &["--version"], "Install Rust from https://rust-lang.org/tools/install/"),
+        check_tool("rustc", &["--version"], "Install Rust from https://rust-lang.org/tools/install/"),
+        check_webview2(),
+        check_webkitgtk(),
+    ];
+    checks.extend(check_build_target(config_path));
+    checks.extend(check_config(config_path));
+    checks.extend(check_modules(modules_dir));
+    checks.extend(check_assets(app_dir, config_path));
+
+    let mut failures = 0;
+    let mut warnings = 0;
+    for check in &checks {
+        match check {
+            Status::Ok(msg) => println!("✅ {}", msg),
+            Status::Warn(msg, fix) => {
+                warnings += 1;
+                println!("⚠️  {}\n   ↳ Fix: {}", msg, fix);
+            }
+            Status::Fail(msg, fix) => {
+                failures += 1;
+                println!("❌ {}\n   ↳ Fix: {}", msg, fix);
+            }
+        }
+    }
+
+    println!("\n🩺 [DOCTOR] {} check(s), {} warning(s), {} failure(s)", checks.len(), warnings, failures);
+    failures
+}
+
+// Check that a tool runs and report its version
+fn check_tool(name: &str, args: &[&str], fix: &str) -> Status {
+    match Command::new(name).args(args).output() {
+        Ok(out) if out.status.success() => {
+            let version = String::from_utf8_lossy(&out.stdout).trim().to_string();
+            Status::Ok(format!("{} found ({})", name, version))
+        }
+        _ => Status::Fail(format!("'{}' is not available", name), fix.to_string()),
+    }
+}
+
+// Check that the Evergreen WebView2 runtime is registered (Windows only)
+fn check_webview2() -> Status {
+    if !cfg!(windows) {
+        return Status::Ok("WebView2 not required on this platform".into());
+    }
+
+    // Per-machine (32/64-bit) and per-user install locations of the Evergreen runtime
+    let keys = [
+        r"HKLM\SOFTWARE\WOW6432Node\Microsoft\EdgeUpdate\Clients\{F3017226-FE2A-4295-8BDF-00C3A9A7E4C5}",
+        r"HKLM\SOFTWARE\Microsoft\EdgeUpdate\Clients\{F3017226-FE2A-4295-8BDF-00C3A9A7E4C5}",
+        r"HKCU\Software\Microsoft\EdgeUpdate\Clients\{F3017226-FE2A-4295-8BDF-00C3A9A7E4C5}",
+    ];
+
+    for key in keys {
+        if let Ok(out) = Command::new("reg").args(["query", key, "/v", "pv"]).output() {
+            let text = String::from_utf8_lossy(&out.stdout);
+            if out.status.success() {
+                let version = text.split_whitespace().last().unwrap_or("unknown").to_string();
+                if version != "0.0.0.0" {
+                    return Status::Ok(format!("WebView2 runtime found ({})", version));
+                }
+            }
+        }
+    }
+
+    Status::Fail(
+        "WebView2 runtime not found".into(),
+        "Install the Evergreen runtime from https://developer.microsoft.com/microsoft-edge/webview2/".into(),
+    )
+}
+
+// Check the WebKitGTK development files the core needs to build (Linux only)
+fn check_webkitgtk() -> Status {
+    if !cfg!(target_os = "linux") {
+        return Status::Ok("WebKitGTK not required on this platform".into());
+    }
+
+    let fix = "Install the WebKitGTK 4.0 development package (e.g. 'sudo apt install libwebkit2gtk-4.0-dev' or 'sudo dnf install webkit2gtk4.0-devel')";
+    let Ok(out) = Command::new("pkg-config").args(["--modversion", "webkit2gtk-4.0"]).output() else {
+        return Status::Fail("'pkg-config' is not available".into(), "Install pkg-config with your package manager".into());
+    };
+    if !out.status.success() {
+        return Status::Fail("WebKitGTK (webkit2gtk-4.0) not found".into(), fix.into());
+    }
+
+    let version = String::from_utf8_lossy(&out.stdout).trim().to_string();
+    let mut parts = version.split('.').map(|p| p.parse::<u32>().unwrap_or(0));
+    let (major, minor) = (parts.next().unwrap_or(0), parts.next().unwrap_or(0));
+    if (major, minor) < (2, 36) {
+        return Status::Fail(format!("WebKitGTK {} is too old (2.36+ needed)", version), fix.into());
+    }
+    Status::Ok(format!("WebKitGTK found ({})", version))
+}
+
+// Check that the rustup target of [build] target is installed
+fn check_build_target(config_path: &Path) -> Option<Status> {
+    let target = config::load_build_settings(config_path).target?;
+    let installed = Command::new("rustup").args(["target", "list", "--installed"]).output().ok()?;
+    let installed = String::from_utf8_lossy(&installed.stdout);
+    if installed.lines().any(|t| t.trim() == target) {
+        return Some(Status::Ok(format!("Rust target '{}' installed", target)));
+    }
+
+    let mut fix = format!("Run 'rustup target add {}'", target);
+    if target.starts_with("aarch64") && target.ends_with("windows-msvc") {
+        fix.push_str(" and install the 'MSVC ARM64 build tools' component of Build Tools for Visual Studio");
+    }
+    Some(Status::Fail(format!("Rust target '{}' is not installed", target), fix))
+}
+
+// Check that frontier.toml (and any overlay) exists and parses strictly
+fn check_config(config_path: &Path) -> Vec<Status> {
+    if !config_path.exists() {
+        return vec![Status::Warn(
+            format!("{} not found, defaults will be used", config_path.display()),
+            "Create frontier.toml with an [app] section (see MANUAL.md)".into(),
+        )];
+    }
+
+    config::check_config_files(config_path)
+        .into_iter()
+        .map(|(file, outcome)| match outcome {
+            Ok(_) => Status::Ok(format!("{} is valid", file.display())),
+            Err(e) => Status::Fail(
+                format!("{} is invalid: {}", file.display(), e.trim()),
+                "Correct the reported line (run `.\\frontier check` for details)".into(),
+            ),
+        })
+        .collect()
+}
+
+// Check that every module's interpreter and build command can be found
+fn check_modules(modules_dir: &Path) -> Vec<Status> {
+    let mut results = Vec::new();
+    let modules = backend::load_modules(modules_dir);
+
+    if modules.is_empty() {
+        results.push(Status::Warn(
+            format!("No modules found in '{}'", modules_dir.display()),
+            "Add a modules/<name>/manifest.toml for each backend language you use".into(),
+        ));
+        return results;
+    }
+
+    let mut extensions: Vec<&String> = modules.keys().collect();
+    extensions.sort();
+
+    for ext in extensions {
+        let module = &modules[ext];
+        let mut programs = Vec::new();
+        if let Some(interp) = &module.interpreter {
+            programs.push(("interpreter", interp.clone()));
+        }
+        if let Some(rule) = &module.build {
+            programs.push(("compiler", rule.command.clone()));
+        }
+
+        for (kind, command) in programs {
+            let program = backend::split_shell_args(&command).into_iter().next().unwrap_or_default();
+            if program.is_empty() {
+                results.push(Status::Fail(
+                    format!("Module '.{}' has an empty {} command", ext, kind),
+                    format!("Set a valid {} in the manifest for '.{}'", kind, ext),
+                ));
+            } else if find_in_path(&program).is_some() {
+                results.push(Status::Ok(format!("Module '.{}' {} '{}' found", ext, kind, program)));
+            } else {
+                results.push(Status::Fail(
+                    format!("Module '.{}' {} '{}' not found in PATH", ext, kind, program),
+                    format!("Install '{}' or add its folder to PATH", program),
+                ));
+            }
+        }
+    }
+
+    results
+}
+
+// Check the frontend folder, entry page and configured icon
+fn check_assets(app_dir: &Path, config_path: &Path) -> Vec<Status> {
+    let mut results = Vec::new();
+    let frontend = app_dir.join("frontend");
+
+    if !frontend.is_dir() {
+        results.push(Status::Fail(
+            format!("Frontend folder '{}' not found", frontend.display()),
+            format!("Create '{}' and add an index.html", frontend.display()),
+        ));
+        return results;
+    }
+
+    if frontend.join("index.html").is_file() {
+        results.push(Status::Ok("Entry page 'index.html' found".into()));
+    } else {
+        results.push(Status::Fail(
+            "Entry page 'index.html' not found".into(),
+            format!("Create '{}'", frontend.join("index.html").display()),
+        ));
+    }
+
+    if let Some(window_cfg) = config::load_window_config(config_path) {
+        if let Some(icon) = window_cfg.icon {
+            if Path::new(&icon).is_file() {
+                results.push(Status::Ok(format!("Window icon '{}' found", icon)));
+            } else {
+                results.push(Status::Fail(
+                    format!("Window icon '{}' not found", icon),
+                    "Fix [window] icon in frontier.toml (path is relative to the project root)".into(),
+                ));
+            }
+        }
+    }
+
+    if cfg!(windows) && !frontend.join("icon.ico").is_file() {
+        results.push(Status::Warn(
+            "No 'icon.ico' in the frontend folder, the executable will use the default icon".into(),
+            format!("Add a valid .ico file at '{}'", frontend.join("icon.ico").display()),
+        ));
+    }
+
+    results
+}
+
+// Resolve a program name against PATH (and PATHEXT on Windows)
+fn find_in_path(program: &str) -> Option<PathBuf> {
+    let direct = Path::new(program);
+    if direct.components().count() > 1 {
+        return if direct.exists() { Some(direct.to_path_buf()) } else { None };
+    }
+
+    let exts: Vec<String> = if cfg!(windows) {
+        std::env::var("PATHEXT")
+            .unwrap_or_else(|_| ".EXE;.BAT;.CMD".into())
+            .split(';')
+            .map(|e| e.to_lowercase())
+            .chain(std::iter::once(String::new()))
+            .collect()
+    } else {
+        vec![String::new()]
+    };
+
+    let paths = std::env::var_os("PATH")?;
+    for dir in std::env::split_paths(&paths) {
+        for ext in &exts {
+            let candidate = dir.join(format!("{}{}", program, ext));
+            if candidate.is_file() {
+                return Some(candidate);
+            }
+        }
+    }
+    None
+}
@@ -0,0 +1,162 @@
+// Copyright (c) 2026 The Frontier Framework Authors
+// SPDX-License-Identifier: Apache-2.0 WITH LLVM-exception OR MIT
+
+// Embedded Assets Module
+//
+// Release executables carry the whole app (frontier.toml, frontend, backends,
+// locales) inside the binary. The command table (commands.json) is read
+// straight from memory, and only the small files needed before the first
+// window are written to the runtime folder at startup; frontend files are
+// served straight from memory and backend files are written the first time a
+// trigger runs, so large apps don't pay for extraction up front. They are
+// written all at once, since a backend may import or run its neighbours.
+// With [frontend] archive the frontend is bundled as one zip instead of a
+// folder; each file is inflated from it in memory when it's requested, except
+// stored (uncompressed) ones such as media, which are read in place.
+
+use rust_embed::RustEmbed;
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::io::{self, Cursor, Read};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use tracing::error;
+use zip::{CompressionMethod, ZipArchive};
+
+use crate::config::{self, FrontierToml};
+use crate::platform;
+use crate::system::RuntimeMeta;
+
+#[derive(RustEmbed)]
+#[folder = "assets/"]
+pub struct Assets;
+
+// Bundled in place of the frontend/ folder by `frontier build` with [frontend] archive
+pub const FRONTEND_ARCHIVE: &str = "frontend.zip";
+
+type Archive = ZipArchive<Cursor<Cow<'static, [u8]>>>;
+
+lazy_static::lazy_static! {
+    // Files already checked/written by this process
+    static ref EXTRACTED: Mutex<HashSet<String>> = Mutex::new(HashSet::new());
+    // Whether every backend file has been written (held while they're written)
+    static ref BACKENDS: Mutex<bool> = Mutex::new(false);
+    // The frontend archive, read in place from the executable's memory
+    static ref ARCHIVE: Option<Mutex<Archive>> = Assets::get(FRONTEND_ARCHIVE)
+        .and_then(|file| match ZipArchive::new(Cursor::new(file.data)) {
+            Ok(archive) => Some(Mutex::new(archive)),
+            Err(e) => {
+                error!(target: "asset", "❌ [ASSET] Invalid {}: {}", FRONTEND_ARCHIVE, e);
+                None
+            }
+        });
+}
+
+// A bundled file
+pub struct BundledFile {
+    pub data: Cow<'static, [u8]>,
+    pub hash: String,                // Changes with the content (for ETags)
+    pub last_modified: Option<u64>,  // Seconds since the epoch
+}
+
+// A bundled file by its path ("frontend/index.html"), from the frontend archive
+// when the frontend was bundled as one
+pub fn file(name: &str) -> Option<BundledFile> {
+    if let Some(file) = Assets::get(name) {
+        return Some(BundledFile {
+            hash: file.metadata.sha256_hash()[..12].iter().map(|b| format!("{:02x}", b)).collect(),
+            last_modified: file.metadata.last_modified(),
+            data: file.data,
+        });
+    }
+    let entry = name.strip_prefix("frontend/")?;
+    let mut archive = ARCHIVE.as_ref()?.lock().ok()?;
+    let mut zipped = archive.by_name(entry).ok()?;
+    let hash = format!("{:08x}{:x}", zipped.crc32(), zipped.size());
+    let last_modified = Assets::get(FRONTEND_ARCHIVE).and_then(|f| f.metadata.last_modified());
+    // Stored entries are a slice of the executable's memory, no copy needed
+    if zipped.compression() == CompressionMethod::Stored {
+        if let Some(Cow::Borrowed(bytes)) = Assets::get(FRONTEND_ARCHIVE).map(|f| f.data) {
+            let start = zipped.data_start() as usize;
+            if let Some(data) = bytes.get(start..start + zipped.size() as usize) {
+                return Some(BundledFile { data: Cow::Borrowed(data), hash, last_modified });
+            }
+        }
+    }
+    let mut data = Vec::with_capacity(zipped.size() as usize);
+    if let Err(e) = zipped.read_to_end(&mut data) {
+        error!(target: "asset", "❌ [ASSET] Cannot inflate {} from {}: {}", entry, FRONTEND_ARCHIVE, e);
+        return None;
+    }
+    Some(BundledFile { data: Cow::Owned(data), hash, last_modified })
+}
+
+// Write the files needed at startup: config, trigger metadata, locales and the app icon
+pub fn extract_startup(base: &Path) {
+    for name in Assets::iter() {
+        if needed_at_startup(&name) {
+            let _ = extract(base, &name);
+        }
+    }
+}
+
+// Make sure `name` exists in the runtime folder (written once per process, and
+// only when missing or different from the embedded copy)
+pub fn extract(base: &Path, name: &str) -> io::Result<PathBuf> {
+    let dest = base.join(name);
+    let name = name.replace('\\', "/");
+    if EXTRACTED.lock().map(|e| e.contains(&name)).unwrap_or(false) {
+        return Ok(dest);
+    }
+
+    let file = self::file(&name).ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, format!("'{}' is not bundled", name)))?;
+    let up_to_date = fs::read(&dest).map(|current| current == file.data.as_ref()).unwrap_or(false);
+    if !up_to_date {
+        if let Some(parent) = dest.parent() { fs::create_dir_all(parent)?; }
+        fs::write(&dest, file.data.as_ref())?;
+    }
+    platform::mark_executable(&dest, &file.data);
+    if let Ok(mut extracted) = EXTRACTED.lock() {
+        extracted.insert(name);
+    }
+    Ok(dest)
+}
+
+// Write every bundled backend file of the command table (once per process)
+pub fn extract_backends(base: &Path) -> io::Result<()> {
+    let mut done = BACKENDS.lock().unwrap_or_else(|e| e.into_inner());
+    if *done { return Ok(()); }
+    for meta in commands().values().filter(|meta| Path::new(&meta.filename).is_relative()) {
+        extract(base, &meta.filename)?;
+    }
+    *done = true;
+    Ok(())
+}
+
+// Triggers generated by `frontier build` (no directory scan or extraction)
+pub fn commands() -> HashMap<String, RuntimeMeta> {
+    let Some(file) = Assets::get("commands.json") else { return HashMap::new() };
+    match serde_json::from_slice::<Vec<RuntimeMeta>>(&file.data) {
+        Ok(commands) => commands.into_iter().map(|m| (m.trigger.clone(), m)).collect(),
+        Err(e) => {
+            error!(target: "backend", "❌ [BACKEND] Invalid command table: {}", e);
+            HashMap::new()
+        }
+    }
+}
+
+// The bundled frontier.toml, read from memory before anything is extracted
+// (its [app] section names the folder files are extracted to)
+pub fn bundled_config() -> Option<FrontierToml> {
+    let file = Assets::get("frontier.toml")?;
+    let (content, _, _) = config::expand_config_text(std::str::from_utf8(&file.data).ok()?.to_string());
+    toml::from_str(&content).ok()
+}
+
+fn needed_at_startup(name: &str) -> bool {
+    name == "frontier.toml"
+        || name.starts_with("locales/")
+        || name.starts_with("app_icon.")
+        || name.starts_with("tray_icon.")
+}
@@ -0,0 +1,135 @@
+// Copyright (c) 2026 The Frontier Framework Authors
+// SPDX-License-Identifier: Apache-2.0 WITH LLVM-exception OR MIT
+
+// IPC Envelope Module
+//
+// Pages call backends with `trigger|args` (args split on whitespace) or with a
+// JSON envelope, which keeps pipes, newlines and spaces inside arguments and
+// lets the page match the reply to its call:
+//
+//   {"cmd": "convert", "args": ["my file.csv", {"sep": ";"}], "callbackId": 7}
+//
+// "args" is an array (one argument per item), a string (one argument) or any
+// other value (one argument, its JSON text). Modules with another args_mode get
+// the payload as a whole instead: as one argument ("single") or as JSON on
+// stdin ("stdin-json"). With a callbackId the reply goes
+// to Frontier.__callback(7, { reply, error }) instead of the 'log' event, and
+// streamed 'output' lines carry the callbackId.
+
+use serde::Deserialize;
+use serde_json::Value;
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Envelope {
+    cmd: String,
+    #[serde(default)]
+    args: Value,
+    callback_id: Option<u64>,
+}
+
+// A backend call from a page
+pub struct BackendCall {
+    pub trigger: String,
+    pub args: Vec<String>,
+    pub payload: Value,        // The arguments as sent: the text of `trigger|args`, or the envelope's "args"
+    pub callback: Option<u64>, // callbackId of a JSON call
+}
+
+impl BackendCall {
+    // `trigger|args`
+    pub fn from_message(trigger: &str, args: &str) -> Self {
+        BackendCall {
+            trigger: trigger.to_string(),
+            args: args.split_whitespace().map(str::to_string).collect(),
+            payload: if args.is_empty() { Value::Null } else { Value::String(args.to_string()) },
+            callback: None,
+        }
+    }
+
+    // A JSON envelope
+    pub fn from_envelope(text: &str) -> Result<Self, String> {
+        let envelope: Envelope = serde_json::from_str(text).map_err(|e| format!("Invalid IPC envelope: {}", e))?;
+        let args = match envelope.args.clone() {
+            Value::Null => Vec::new(),
+            Value::Array(items) => items.into_iter().map(argument).collect(),
+            value => vec![argument(value)],
+        };
+        Ok(BackendCall { trigger: envelope.cmd, args, payload: envelope.args, callback: envelope.callback_id })
+    }
+
+    // args_mode = "single": the payload as one argument (none without arguments)
+    pub fn single_argument(&self) -> Vec<String> {
+        match &self.payload {
+            Value::Null => Vec::new(),
+            value => vec![argument(value.clone())],
+        }
+    }
+
+    // For logs
+    pub fn args_text(&self) -> String {
+        self.args.join(" ")
+    }
+}
+
+// Strings are passed as they are, other values as JSON
+fn argument(value: Value) -> String {
+    match value {
+        Value::String(text) => text,
+        value => value.to_string(),
+    }
+}
+
+// Where the reply to a JSON call goes, with the error kept apart from the output
+#[derive(Default)]
+pub struct Callback {
+    pub id: u64,
+    pub error: Option<String>,
+    pub code: Option<&'static str>, // "timeout", "cancelled" or "busy", for Frontier.run's error.code
+    pub stderr: String,
+    pub exit_code: Option<i32>,
+    pub duration_ms: Option<u128>, // None when the backend didn't run
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn array_items_are_arguments() {
+        let call = BackendCall::from_envelope(r#"{"cmd": "convert", "args": ["my file.csv", {"sep": ";"}, 3], "callbackId": 7}"#).unwrap();
+        assert_eq!(call.trigger, "convert");
+        assert_eq!(call.args, vec!["my file.csv", r#"{"sep":";"}"#, "3"]);
+        assert_eq!(call.callback, Some(7));
+    }
+
+    #[test]
+    fn other_values_are_one_argument() {
+        assert_eq!(BackendCall::from_envelope(r#"{"cmd": "echo", "args": "a | b"}"#).unwrap().args, vec!["a | b"]);
+        assert_eq!(BackendCall::from_envelope(r#"{"cmd": "echo", "args": {"n": 1}}"#).unwrap().args, vec![r#"{"n":1}"#]);
+        let call = BackendCall::from_envelope(r#"{"cmd": "ping"}"#).unwrap();
+        assert!(call.args.is_empty());
+        assert!(call.single_argument().is_empty());
+        assert_eq!(call.callback, None);
+    }
+
+    #[test]
+    fn single_argument_keeps_the_payload_whole() {
+        let call = BackendCall::from_envelope(r#"{"cmd": "sum", "args": [1, 2]}"#).unwrap();
+        assert_eq!(call.single_argument(), vec!["[1,2]"]);
+    }
+
+    #[test]
+    fn invalid_envelopes_are_refused() {
+        assert!(BackendCall::from_envelope("not json").is_err());
+        assert!(BackendCall::from_envelope(r#"{"args": []}"#).is_err());
+    }
+
+    #[test]
+    fn plain_messages_split_on_whitespace() {
+        let call = BackendCall::from_message("sum", "1  2");
+        assert_eq!(call.args, vec!["1", "2"]);
+        assert_eq!(call.payload, Value::String("1  2".into()));
+        assert_eq!(BackendCall::from_message("ping", "").payload, Value::Null);
+    }
+}
@@ -0,0 +1,51 @@
+// Copyright (c) 2026 The Frontier Framework Authors
+// SPDX-License-Identifier: Apache-2.0 WITH LLVM-exception OR MIT
+
+// Runtime Errors Module
+//
+// Why the app couldn't start (or had to stop). Each kind ends the process with
+// its own exit code, so wrappers, installers and CI can tell them apart:
+//
+// 0  the app ran and quit normally (or handed its files to a running instance)
+// 2  config:  frontier.toml or the app's folders can't be used
+// 3  asset:   a startup page or bundled file is missing or unreadable
+// 4  webview: no webview could be created (e.g. the WebView2 runtime is missing)
+// 5  backend: the backend runtime can't start
+//
+// A crash (panic) aborts the process instead, with the OS's abort code.
+
+use std::fmt;
+
+pub enum FrontierError {
+    Config(String),
+    Asset(String),
+    Webview(String),
+    Backend(String),
+}
+
+impl FrontierError {
+    pub fn kind(&self) -> &'static str {
+        match self {
+            FrontierError::Config(_) => "config",
+            FrontierError::Asset(_) => "asset",
+            FrontierError::Webview(_) => "webview",
+            FrontierError::Backend(_) => "backend",
+        }
+    }
+
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            FrontierError::Config(_) => 2,
+            FrontierError::Asset(_) => 3,
+            FrontierError::Webview(_) => 4,
+            FrontierError::Backend(_) => 5,
+        }
+    }
+}
+
+impl fmt::Display for FrontierError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (FrontierError::Config(message) | FrontierError::Asset(message) | FrontierError::Webview(message) | FrontierError::Backend(message)) = self;
+        f.write_str(message)
+    }
+}
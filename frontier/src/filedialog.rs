@@ -0,0 +1,72 @@
+// Copyright (c) 2026 The Frontier Framework Authors
+// SPDX-License-Identifier: Apache-2.0 WITH LLVM-exception OR MIT
+
+// File Dialog Module
+//
+// The OS's open, save and folder pickers (Frontier.dialog.open/save/folder).
+// They resolve with the chosen path, an array of paths for open with
+// `multiple`, or null when the user cancels. macOS only shows them on the main
+// thread, where they block the event loop while open like any modal panel;
+// elsewhere they run on their own thread.
+
+use std::path::PathBuf;
+
+use native_dialog::FileDialog;
+use serde::Deserialize;
+use serde_json::Value;
+use tracing::warn;
+
+#[derive(Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Kind {
+    Open,
+    Save,
+    Folder,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Request {
+    pub kind: Kind,
+    pub title: Option<String>,
+    pub directory: Option<String>, // Folder shown first
+    pub filename: Option<String>,  // Suggested name (save)
+    #[serde(default)]
+    pub filters: Vec<Filter>,
+    #[serde(default)]
+    pub multiple: bool, // Open several files
+}
+
+// e.g. { name: "Images", extensions: ["png", "jpg"] }
+#[derive(Deserialize)]
+pub struct Filter {
+    pub name: String,
+    pub extensions: Vec<String>,
+}
+
+// The chosen path(s), or null
+pub fn show(request: &Request) -> Value {
+    let extensions: Vec<Vec<&str>> = request.filters.iter()
+        .map(|filter| filter.extensions.iter().map(|ext| ext.trim_start_matches('.')).collect())
+        .collect();
+    let mut dialog = FileDialog::new();
+    if let Some(title) = &request.title { dialog = dialog.set_title(title); }
+    if let Some(directory) = &request.directory { dialog = dialog.set_location(directory); }
+    if let Some(filename) = &request.filename { dialog = dialog.set_filename(filename); }
+    for (filter, extensions) in request.filters.iter().zip(&extensions) {
+        dialog = dialog.add_filter(&filter.name, extensions);
+    }
+
+    let path = |path: PathBuf| Value::String(path.to_string_lossy().into_owned());
+    let chosen = match request.kind {
+        Kind::Open if request.multiple => dialog.show_open_multiple_file()
+            .map(|paths| if paths.is_empty() { Value::Null } else { paths.into_iter().map(path).collect() }),
+        Kind::Open => dialog.show_open_single_file().map(|chosen| chosen.map_or(Value::Null, path)),
+        Kind::Save => dialog.show_save_single_file().map(|chosen| chosen.map_or(Value::Null, path)),
+        Kind::Folder => dialog.show_open_single_dir().map(|chosen| chosen.map_or(Value::Null, path)),
+    };
+    chosen.unwrap_or_else(|e| {
+        warn!(target: "ipc", "⚠️ [DIALOG] Cannot show the file dialog: {}", e);
+        Value::Null
+    })
+}
@@ -0,0 +1,230 @@
+// Copyright (c) 2026 The Frontier Framework Authors
+// SPDX-License-Identifier: Apache-2.0 WITH LLVM-exception OR MIT
+
+// Internationalization Module
+//
+// This module loads the locale files configured in [i18n] (one <locale>.json or
+// <locale>.toml per language), picks the active locale from the config or the
+// OS language, and hands the messages to the page bridge as `Frontier.i18n`.
+// The runtime's own messages (crash and WebView2 dialogs) are translated too:
+// built-in tables for a few languages, which a "frontier" table in the app's
+// locale files overrides or extends to other languages.
+
+use serde_json::{json, Value};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use tracing::{error, info};
+
+use crate::config::I18nConfig;
+
+pub const DEFAULT_DIR: &str = "app/locales";
+
+// Language of the runtime's messages and the app's "frontier" table for it;
+// until the app's locales are loaded, the OS language
+struct RuntimeLocale {
+    language: String,
+    overrides: Value,
+}
+
+static RUNTIME_LOCALE: Mutex<Option<RuntimeLocale>> = Mutex::new(None);
+
+// Locale folder: the configured dir in dev, the bundled "locales" folder in release
+pub fn locales_dir(base_dir: &Path, cfg: &I18nConfig, is_dev: bool) -> PathBuf {
+    if is_dev {
+        base_dir.join(cfg.dir.as_deref().unwrap_or(DEFAULT_DIR))
+    } else {
+        base_dir.join("locales")
+    }
+}
+
+// Build the { locale, messages, fallback } bundle exposed to pages
+pub fn load_bundle(dir: &Path, cfg: &I18nConfig) -> Value {
+    let available = available_locales(dir);
+    let fallback = cfg.fallback.clone().unwrap_or_else(|| "en".into());
+    let requested = match cfg.locale.as_deref() {
+        Some(l) if !l.is_empty() && l != "auto" => l.to_string(),
+        _ => system_locale().unwrap_or_else(|| fallback.clone()),
+    };
+
+    let locale = match_locale(&requested, &available).unwrap_or_else(|| fallback.clone());
+    if !available.is_empty() {
+        info!(target: "i18n", "🌍 [I18N] Locale '{}' (requested '{}')", locale, requested);
+    }
+
+    let messages = load_locale(dir, &locale);
+    let fallback_messages = if locale == fallback { json!({}) } else { load_locale(dir, &fallback) };
+
+    // Runtime messages follow the app's locale; apps without locale files get the requested one
+    let runtime_locale = if available.is_empty() { &requested } else { &locale };
+    if let Ok(mut runtime) = RUNTIME_LOCALE.lock() {
+        *runtime = Some(RuntimeLocale { language: language_of(runtime_locale), overrides: messages["frontier"].clone() });
+    }
+
+    json!({
+        "locale": locale,
+        "available": available,
+        "messages": messages,
+        "fallback": fallback_messages,
+    })
+}
+
+// Locale names found in the folder (file stems of .json and .toml files)
+fn available_locales(dir: &Path) -> Vec<String> {
+    let mut locales: Vec<String> = fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .flatten()
+                .map(|e| e.path())
+                .filter(|p| matches!(p.extension().and_then(|e| e.to_str()), Some("json") | Some("toml")))
+                .filter_map(|p| p.file_stem().and_then(|s| s.to_str()).map(String::from))
+                .collect()
+        })
+        .unwrap_or_default();
+    locales.sort();
+    locales.dedup();
+    locales
+}
+
+// Exact match first ("pt-BR"), then the language alone ("pt"), then any region of it ("pt-PT")
+fn match_locale(requested: &str, available: &[String]) -> Option<String> {
+    let requested = requested.replace('_', "-");
+    let language = requested.split('-').next().unwrap_or("").to_lowercase();
+
+    available.iter().find(|l| l.eq_ignore_ascii_case(&requested))
+        .or_else(|| available.iter().find(|l| l.eq_ignore_ascii_case(&language)))
+        .or_else(|| available.iter().find(|l| l.split('-').next().unwrap_or("").eq_ignore_ascii_case(&language)))
+        .cloned()
+}
+
+fn load_locale(dir: &Path, locale: &str) -> Value {
+    let json_path = dir.join(format!("{}.json", locale));
+    let toml_path = dir.join(format!("{}.toml", locale));
+
+    let parsed: Result<Value, String> = if let Ok(content) = fs::read_to_string(&json_path) {
+        serde_json::from_str(&content).map_err(|e| e.to_string())
+    } else if let Ok(content) = fs::read_to_string(&toml_path) {
+        toml::from_str::<toml::Value>(&content)
+            .map_err(|e| e.to_string())
+            .and_then(|v| serde_json::to_value(v).map_err(|e| e.to_string()))
+    } else {
+        return json!({});
+    };
+
+    parsed.unwrap_or_else(|e| {
+        error!(target: "i18n", "❌ [I18N] Failed to parse locale '{}': {}", locale, e);
+        json!({})
+    })
+}
+
+// OS language as a BCP 47 tag (e.g. "pt-BR")
+#[cfg(target_os = "windows")]
+fn system_locale() -> Option<String> {
+    use winapi::um::winnls::GetUserDefaultLocaleName;
+    let mut buf = [0u16; 85];
+    let len = unsafe { GetUserDefaultLocaleName(buf.as_mut_ptr(), buf.len() as i32) };
+    if len <= 1 { return None; }
+    Some(String::from_utf16_lossy(&buf[..(len - 1) as usize]))
+}
+
+#[cfg(not(target_os = "windows"))]
+fn system_locale() -> Option<String> {
+    ["LC_ALL", "LC_MESSAGES", "LANG"]
+        .iter()
+        .filter_map(|var| std::env::var(var).ok())
+        .find(|v| !v.is_empty() && v != "C" && v != "POSIX")
+        .map(|v| v.split('.').next().unwrap_or("").replace('_', "-"))
+        // Apps opened from Finder get no LANG; ask the system preferences instead
+        .or_else(|| if cfg!(target_os = "macos") { apple_locale() } else { None })
+}
+
+// macOS preferred locale, e.g. "en_US" or "pt_BR@rg=brzzzz"
+#[cfg(not(target_os = "windows"))]
+fn apple_locale() -> Option<String> {
+    let out = std::process::Command::new("defaults").args(["read", "-g", "AppleLocale"]).output().ok()?;
+    if !out.status.success() { return None; }
+    let locale = String::from_utf8_lossy(&out.stdout).trim().split('@').next().unwrap_or("").replace('_', "-");
+    if locale.is_empty() { None } else { Some(locale) }
+}
+
+fn language_of(locale: &str) -> String {
+    locale.replace('_', "-").split('-').next().unwrap_or("").to_lowercase()
+}
+
+// A runtime message in the current language, with its {name} placeholders filled in
+pub fn message(key: &str, vars: &[(&str, &str)]) -> String {
+    let (language, custom) = match RUNTIME_LOCALE.lock().ok().as_deref().and_then(Option::as_ref) {
+        Some(runtime) => (runtime.language.clone(), runtime.overrides[key].as_str().map(String::from)),
+        None => (language_of(&system_locale().unwrap_or_default()), None),
+    };
+    let builtin = |language: &str| RUNTIME_MESSAGES.iter()
+        .find(|(l, _)| *l == language)
+        .and_then(|(_, table)| table.iter().find(|(k, _)| *k == key))
+        .map(|(_, text)| text.to_string());
+    let text = custom.or_else(|| builtin(&language)).or_else(|| builtin("en")).unwrap_or_else(|| key.to_string());
+    vars.iter().fold(text, |text, (name, value)| text.replace(&format!("{{{}}}", name), value))
+}
+
+// Built-in runtime messages by language
+const RUNTIME_MESSAGES: &[(&str, &[(&str, &str)])] = &[
+    ("en", &[
+        ("error_title", "Frontier Runtime Error"),
+        ("crashed", "The application crashed"),
+        ("stopped", "The application stopped with an error"),
+        ("report_saved", "A crash report was saved to:\n{path}\n\nOpen it now?"),
+        ("webview2_required", "WebView2 Runtime Required"),
+        ("webview2_not_installed", "The Microsoft Edge WebView2 Runtime is not installed"),
+        ("webview2_outdated", "The installed WebView2 Runtime ({version}) is older than version {min}"),
+        ("webview2_download", "{problem}.\n\nThis app needs it to show its windows. Download it now?\n\nStart the app again once the setup finishes."),
+        ("webview2_missing", "WebView2 Runtime Missing"),
+        ("webview2_fixed_broken", "The WebView2 runtime shipped with this app was not found or can't be used:\n{folder}\n\nReinstall the app to restore it."),
+    ]),
+    ("pt", &[
+        ("error_title", "Erro de execução do Frontier"),
+        ("crashed", "O aplicativo travou"),
+        ("stopped", "O aplicativo parou com um erro"),
+        ("report_saved", "Um relatório de falha foi salvo em:\n{path}\n\nAbrir agora?"),
+        ("webview2_required", "WebView2 Runtime necessário"),
+        ("webview2_not_installed", "O Microsoft Edge WebView2 Runtime não está instalado"),
+        ("webview2_outdated", "O WebView2 Runtime instalado ({version}) é anterior à versão {min}"),
+        ("webview2_download", "{problem}.\n\nEste aplicativo precisa dele para mostrar suas janelas. Baixar agora?\n\nAbra o aplicativo novamente quando a instalação terminar."),
+        ("webview2_missing", "WebView2 Runtime ausente"),
+        ("webview2_fixed_broken", "O WebView2 Runtime incluído neste aplicativo não foi encontrado ou não pode ser usado:\n{folder}\n\nReinstale o aplicativo para restaurá-lo."),
+    ]),
+    ("es", &[
+        ("error_title", "Error de ejecución de Frontier"),
+        ("crashed", "La aplicación se cerró inesperadamente"),
+        ("stopped", "La aplicación se detuvo por un error"),
+        ("report_saved", "Se guardó un informe de error en:\n{path}\n\n¿Abrirlo ahora?"),
+        ("webview2_required", "Se necesita WebView2 Runtime"),
+        ("webview2_not_installed", "Microsoft Edge WebView2 Runtime no está instalado"),
+        ("webview2_outdated", "La versión instalada de WebView2 Runtime ({version}) es anterior a la {min}"),
+        ("webview2_download", "{problem}.\n\nEsta aplicación lo necesita para mostrar sus ventanas. ¿Descargarlo ahora?\n\nVuelva a abrir la aplicación cuando termine la instalación."),
+        ("webview2_missing", "Falta WebView2 Runtime"),
+        ("webview2_fixed_broken", "No se encontró o no se puede usar el WebView2 Runtime incluido con esta aplicación:\n{folder}\n\nReinstale la aplicación para restaurarlo."),
+    ]),
+    ("fr", &[
+        ("error_title", "Erreur d'exécution Frontier"),
+        ("crashed", "L'application a planté"),
+        ("stopped", "L'application s'est arrêtée à cause d'une erreur"),
+        ("report_saved", "Un rapport de plantage a été enregistré dans :\n{path}\n\nL'ouvrir maintenant ?"),
+        ("webview2_required", "WebView2 Runtime requis"),
+        ("webview2_not_installed", "Microsoft Edge WebView2 Runtime n'est pas installé"),
+        ("webview2_outdated", "La version installée de WebView2 Runtime ({version}) est antérieure à la version {min}"),
+        ("webview2_download", "{problem}.\n\nCette application en a besoin pour afficher ses fenêtres. Le télécharger maintenant ?\n\nRelancez l'application une fois l'installation terminée."),
+        ("webview2_missing", "WebView2 Runtime introuvable"),
+        ("webview2_fixed_broken", "Le WebView2 Runtime fourni avec cette application est introuvable ou inutilisable :\n{folder}\n\nRéinstallez l'application pour le restaurer."),
+    ]),
+    ("de", &[
+        ("error_title", "Frontier-Laufzeitfehler"),
+        ("crashed", "Die Anwendung ist abgestürzt"),
+        ("stopped", "Die Anwendung wurde wegen eines Fehlers beendet"),
+        ("report_saved", "Ein Absturzbericht wurde gespeichert unter:\n{path}\n\nJetzt öffnen?"),
+        ("webview2_required", "WebView2 Runtime erforderlich"),
+        ("webview2_not_installed", "Die Microsoft Edge WebView2 Runtime ist nicht installiert"),
+        ("webview2_outdated", "Die installierte WebView2 Runtime ({version}) ist älter als Version {min}"),
+        ("webview2_download", "{problem}.\n\nDiese Anwendung benötigt sie, um ihre Fenster anzuzeigen. Jetzt herunterladen?\n\nStarten Sie die Anwendung erneut, sobald die Installation abgeschlossen ist."),
+        ("webview2_missing", "WebView2 Runtime fehlt"),
+        ("webview2_fixed_broken", "Die mit dieser Anwendung ausgelieferte WebView2 Runtime wurde nicht gefunden oder ist nicht verwendbar:\n{folder}\n\nInstallieren Sie die Anwendung neu, um sie wiederherzustellen."),
+    ]),
+];
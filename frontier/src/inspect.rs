@@ -0,0 +1,263 @@
+// Copyright (c) 2026 The Frontier Framework Authors
+// SPDX-License-Identifier: Apache-2.0 WITH LLVM-exception OR MIT
+
+// Runtime Inspection Module
+//
+// This module keeps a snapshot of what the runtime actually loaded (open
+// windows and their configs, registered triggers, whitelists) plus a short
+// history of recent events. In dev it is served as JSON at
+// <scheme>://app/__state with a small viewer at <scheme>://app/__state.html.
+// Crash reports use the same window list.
+//
+// It also keeps a trace of the IPC traffic (page messages, backend runs and
+// replies), tailed live by <scheme>://app/__ipc.html through __ipc?since=<seq>.
+
+use serde_json::{json, Value};
+use std::collections::{BTreeMap, VecDeque};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::perf;
+
+const MAX_EVENTS: usize = 100;
+const MAX_IPC: usize = 500;
+const PREVIEW_CHARS: usize = 300;
+
+pub const STATE_PATH: &str = "__state";
+pub const VIEWER_PAGE: &str = "__state.html";
+pub const IPC_PATH: &str = "__ipc";
+pub const IPC_PAGE: &str = "__ipc.html";
+
+static WINDOWS: Mutex<BTreeMap<String, Value>> = Mutex::new(BTreeMap::new());
+static EVENTS: Mutex<VecDeque<Value>> = Mutex::new(VecDeque::new());
+static RUNTIME: Mutex<Option<Value>> = Mutex::new(None);
+static IPC_TRACE: Mutex<VecDeque<Value>> = Mutex::new(VecDeque::new());
+static IPC_SEQ: AtomicU64 = AtomicU64::new(0);
+// The IPC trace is kept in dev and while the automation socket is open
+static IPC_TRACING: AtomicBool = AtomicBool::new(false);
+
+pub fn window_opened(key: String, info: Value) {
+    if let Ok(mut windows) = WINDOWS.lock() {
+        windows.insert(key, info);
+    }
+}
+
+pub fn window_closed(key: &str) {
+    if let Ok(mut windows) = WINDOWS.lock() {
+        windows.remove(key);
+    }
+}
+
+// One line per open window ("id (url)"), for crash reports
+pub fn window_summaries() -> Vec<String> {
+    WINDOWS.lock()
+        .map(|w| {
+            w.values()
+                .map(|info| format!("{} ({})", info["id"].as_str().unwrap_or("?"), info["url"].as_str().unwrap_or("?")))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+// Window id from frontier's config ("index_html"), for trace labels
+pub fn window_label(key: &str) -> String {
+    WINDOWS.lock().ok()
+        .and_then(|w| w.get(key).and_then(|info| info["id"].as_str().map(String::from)))
+        .unwrap_or_else(|| key.to_string())
+}
+
+// Triggers, whitelists and other settings currently in effect
+pub fn set_runtime(info: Value) {
+    if let Ok(mut runtime) = RUNTIME.lock() {
+        *runtime = Some(info);
+    }
+}
+
+pub fn record_event(kind: &str, detail: &str) {
+    if let Ok(mut events) = EVENTS.lock() {
+        if events.len() == MAX_EVENTS { events.pop_front(); }
+        events.push_back(json!({ "time_ms": now_ms(), "kind": kind, "detail": detail }));
+    }
+}
+
+pub fn trace_ipc(enabled: bool) {
+    IPC_TRACING.store(enabled, Ordering::Relaxed);
+}
+
+pub fn tracing_ipc() -> bool {
+    IPC_TRACING.load(Ordering::Relaxed)
+}
+
+// Add an entry to the IPC trace
+//
+// `kind` is "message" (page -> core), "backend" (a trigger run) or "reply"
+// (core -> page); payloads are cut to a short preview.
+pub fn record_ipc(window: &str, kind: &str, name: &str, payload: &str, duration_ms: Option<u128>, failed: bool) {
+    let preview: String = if payload.chars().count() > PREVIEW_CHARS {
+        payload.chars().take(PREVIEW_CHARS).chain("…".chars()).collect()
+    } else {
+        payload.to_string()
+    };
+    let seq = IPC_SEQ.fetch_add(1, Ordering::Relaxed) + 1;
+    if let Ok(mut trace) = IPC_TRACE.lock() {
+        if trace.len() == MAX_IPC { trace.pop_front(); }
+        trace.push_back(json!({
+            "seq": seq,
+            "time_ms": now_ms(),
+            "window": window,
+            "kind": kind,
+            "name": name,
+            "payload": preview,
+            "bytes": payload.len(),
+            "duration_ms": duration_ms,
+            "failed": failed,
+        }));
+    }
+}
+
+// IPC trace entries newer than `seq`
+pub fn ipc_since(seq: u64) -> Value {
+    IPC_TRACE.lock()
+        .map(|t| t.iter().filter(|e| e["seq"].as_u64().unwrap_or(0) > seq).cloned().collect::<Vec<_>>())
+        .map(Value::from)
+        .unwrap_or_else(|_| json!([]))
+}
+
+// Built-in dev pages served by the protocol handler
+pub fn page(resource: &str) -> Option<&'static str> {
+    match resource {
+        VIEWER_PAGE => Some(VIEWER_HTML),
+        IPC_PAGE => Some(IPC_HTML),
+        _ => None,
+    }
+}
+
+pub fn snapshot() -> Value {
+    json!({
+        "runtime": RUNTIME.lock().ok().and_then(|r| r.clone()).unwrap_or(Value::Null),
+        "windows": WINDOWS.lock().map(|w| w.values().cloned().collect::<Vec<_>>()).unwrap_or_default(),
+        "events": EVENTS.lock().map(|e| e.iter().cloned().collect::<Vec<_>>()).unwrap_or_default(),
+        "timings": perf::timings(),
+    })
+}
+
+fn now_ms() -> u128 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_millis()).unwrap_or(0)
+}
+
+// Live tail of __ipc: polls for new entries twice a second
+const IPC_HTML: &str = r#"<!DOCTYPE html>
+<html>
+<head>
+    <title>Frontier IPC Trace</title>
+    <meta name="frontier-width" content="1000">
+    <meta name="frontier-height" content="600">
+    <style>
+        body { font: 12px/1.5 Consolas, monospace; background: #1e1e1e; color: #ddd; margin: 0; }
+        header { position: sticky; top: 0; background: #252526; padding: 8px 12px; border-bottom: 1px solid #333; }
+        input, button, label { font: inherit; color: #ddd; background: #333; border: 1px solid #444; padding: 2px 6px; }
+        label { border: 0; background: none; }
+        table { border-collapse: collapse; width: 100%; }
+        td, th { border-bottom: 1px solid #333; padding: 2px 8px; text-align: left; vertical-align: top; }
+        th { color: #9cdcfe; position: sticky; top: 37px; background: #1e1e1e; }
+        td.payload { white-space: pre-wrap; word-break: break-all; color: #ce9178; }
+        tr.message td.kind { color: #4fc1ff; }
+        tr.backend td.kind { color: #dcdcaa; }
+        tr.reply td.kind { color: #b5cea8; }
+        tr.failed td { color: #f48771; }
+    </style>
+</head>
+<body>
+    <header>
+        <input id="filter" placeholder="Filter (window, trigger, payload)" size="40">
+        <label><input type="checkbox" id="pause"> Pause</label>
+        <button id="clear">Clear</button>
+        <span id="status"></span>
+    </header>
+    <table>
+        <thead><tr><th>Time</th><th>Window</th><th>Kind</th><th>Name</th><th>Payload</th><th>ms</th></tr></thead>
+        <tbody id="rows"></tbody>
+    </table>
+    <script>
+        var last = 0, rows = document.getElementById('rows'), filter = document.getElementById('filter');
+        function cell(text, cls) { var td = document.createElement('td'); td.textContent = text; if (cls) td.className = cls; return td; }
+        function matches(tr) { var q = filter.value.toLowerCase(); return !q || tr.textContent.toLowerCase().indexOf(q) >= 0; }
+        function add(e) {
+            var tr = document.createElement('tr');
+            tr.className = e.kind + (e.failed ? ' failed' : '');
+            [cell(new Date(e.time_ms).toLocaleTimeString()), cell(e.window), cell(e.kind, 'kind'), cell(e.name),
+             cell(e.payload + (e.bytes > e.payload.length ? ' (' + e.bytes + ' bytes)' : ''), 'payload'),
+             cell(e.duration_ms == null ? '' : e.duration_ms)].forEach(function(td) { tr.appendChild(td); });
+            tr.hidden = !matches(tr);
+            rows.appendChild(tr);
+        }
+        function poll() {
+            if (document.getElementById('pause').checked) return;
+            fetch('__ipc?since=' + last).then(function(r) { return r.json(); }).then(function(entries) {
+                var atBottom = innerHeight + scrollY >= document.body.scrollHeight - 4;
+                entries.forEach(function(e) { add(e); last = e.seq; });
+                if (entries.length && atBottom) scrollTo(0, document.body.scrollHeight);
+                document.getElementById('status').textContent = rows.children.length + ' entries';
+            }).catch(function(e) { document.getElementById('status').textContent = 'Failed: ' + e; });
+        }
+        filter.oninput = function() { Array.prototype.forEach.call(rows.children, function(tr) { tr.hidden = !matches(tr); }); };
+        document.getElementById('clear').onclick = function() { rows.innerHTML = ''; };
+        poll();
+        setInterval(poll, 500);
+    </script>
+</body>
+</html>
+"#;
+
+// Viewer for __state: fetches the JSON and renders it, refreshing every 2 seconds
+const VIEWER_HTML: &str = r#"<!DOCTYPE html>
+<html>
+<head>
+    <title>Frontier State</title>
+    <meta name="frontier-width" content="900">
+    <meta name="frontier-height" content="700">
+    <style>
+        body { font: 13px/1.5 Consolas, monospace; background: #1e1e1e; color: #ddd; margin: 0; padding: 16px; }
+        h2 { color: #4fc1ff; font-size: 14px; margin: 20px 0 6px; }
+        table { border-collapse: collapse; width: 100%; }
+        td, th { border-bottom: 1px solid #333; padding: 3px 8px; text-align: left; vertical-align: top; }
+        th { color: #9cdcfe; }
+        pre { margin: 0; white-space: pre-wrap; }
+    </style>
+</head>
+<body>
+    <div id="root">Loading...</div>
+    <script>
+        function esc(v) { return String(v).replace(/[&<>]/g, function(c) { return { '&': '&amp;', '<': '&lt;', '>': '&gt;' }[c]; }); }
+        function table(rows, cols) {
+            if (!rows.length) return '<p>(none)</p>';
+            return '<table><tr>' + cols.map(function(c) { return '<th>' + c + '</th>'; }).join('') + '</tr>' +
+                rows.map(function(r) {
+                    return '<tr>' + cols.map(function(c) {
+                        var v = r[c];
+                        return '<td>' + (typeof v === 'object' ? '<pre>' + esc(JSON.stringify(v, null, 1)) + '</pre>' : esc(v)) + '</td>';
+                    }).join('') + '</tr>';
+                }).join('') + '</table>';
+        }
+        function render(state) {
+            var rt = state.runtime || {};
+            var triggers = Object.keys(rt.triggers || {}).sort().map(function(k) { return Object.assign({ trigger: k }, rt.triggers[k]); });
+            document.getElementById('root').innerHTML =
+                '<h2>Windows</h2>' + table(state.windows, ['id', 'url', 'config']) +
+                '<h2>Triggers</h2>' + table(triggers, ['trigger', 'file', 'interpreter']) +
+                '<h2>Runtime</h2><pre>' + esc(JSON.stringify(Object.assign({}, rt, { triggers: undefined }), null, 2)) + '</pre>' +
+                '<h2>Recent events</h2>' + table(state.events.slice().reverse().map(function(e) {
+                    return { time: new Date(e.time_ms).toLocaleTimeString(), kind: e.kind, detail: e.detail };
+                }), ['time', 'kind', 'detail']);
+        }
+        function refresh() {
+            fetch('__state').then(function(r) { return r.json(); }).then(render)
+                .catch(function(e) { document.getElementById('root').textContent = 'Failed to load state: ' + e; });
+        }
+        refresh();
+        setInterval(refresh, 2000);
+    </script>
+</body>
+</html>
+"#;
@@ -0,0 +1,121 @@
+// Copyright (c) 2026 The Frontier Framework Authors
+// SPDX-License-Identifier: Apache-2.0 WITH LLVM-exception OR MIT
+
+// Running Instance Module
+//
+// Apps with [[file_associations]] are started by the OS with the opened file
+// as an argument, and jump list tasks and notification buttons start them with
+// --task. When the app is already running, the new process hands its files
+// and tasks to that instance over a localhost socket and exits, so the file
+// opens in the running app (as an 'open-file' event) instead of in a second copy.
+// The launch's own arguments go along, for the 'app:launch' event; a launch
+// with arguments but no files or tasks starts a copy of its own.
+// The port and a random token of the running instance are kept in
+// <data_dir>/instance.lock; a stale file (crash, reused port) simply fails the
+// handshake and the new process starts normally.
+
+use std::fs;
+use std::io::{BufRead, BufReader, Write};
+use std::net::{Ipv4Addr, SocketAddr, TcpListener, TcpStream};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use tracing::{debug, info};
+
+use crate::jumplist;
+
+const LOCK_FILE: &str = "instance.lock";
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(2);
+
+// What a launch asks the app to do
+#[derive(Serialize, Deserialize, Default)]
+pub struct Launch {
+    #[serde(default)]
+    pub args: Vec<String>,  // The app's own arguments, as given (Frontier.app.args())
+    pub files: Vec<String>, // Absolute paths, so another instance can open them
+    pub tasks: Vec<String>, // --task values (see jumplist.rs)
+}
+
+impl Launch {
+    // Arguments, files and tasks passed on the command line (the runtime's own
+    // flags and their values are left out of the arguments)
+    pub fn from_args(args: &[String]) -> Self {
+        let mut launch = Launch::default();
+        let mut iter = args.iter().skip(1);
+        while let Some(arg) = iter.next() {
+            if arg == jumplist::TASK_FLAG {
+                launch.tasks.extend(iter.next().cloned());
+                continue;
+            }
+            if arg == "--log-file" || arg == "--trace-file" || arg == "--automation" {
+                iter.next();
+                continue;
+            }
+            // The runtime's switches, and the -psn_ argument older macOS versions add
+            if matches!(arg.as_str(), "--dev" | "--console" | "--rpc-stdio") || arg.starts_with("-psn_") { continue; }
+            launch.args.push(arg.clone());
+            if arg.starts_with('-') { continue; }
+            let path = Path::new(arg);
+            if path.is_file() {
+                let path = fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+                launch.files.push(clean_path(path));
+            }
+        }
+        launch
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty() && self.tasks.is_empty()
+    }
+}
+
+// Hand a launch to a running instance; true when it accepted it
+pub fn forward(data_dir: &Path, launch: &Launch) -> bool {
+    let Ok(lock) = fs::read_to_string(data_dir.join(LOCK_FILE)) else { return false };
+    let mut lines = lock.lines();
+    let (Some(port), Some(token)) = (lines.next().and_then(|p| p.parse::<u16>().ok()), lines.next()) else { return false };
+
+    let addr = SocketAddr::from((Ipv4Addr::LOCALHOST, port));
+    let Ok(mut stream) = TcpStream::connect_timeout(&addr, HANDSHAKE_TIMEOUT) else { return false };
+    let _ = stream.set_read_timeout(Some(HANDSHAKE_TIMEOUT));
+    let message = format!("{}\n{}\n", token, serde_json::to_string(launch).unwrap_or_else(|_| "{}".into()));
+    if stream.write_all(message.as_bytes()).is_err() { return false; }
+
+    let mut answer = String::new();
+    let accepted = BufReader::new(stream).read_line(&mut answer).is_ok() && answer.trim() == "ok";
+    if accepted {
+        info!(target: "app", "📂 [APP] {} file(s) and {} task(s) sent to the running instance", launch.files.len(), launch.tasks.len());
+    }
+    accepted
+}
+
+// Accept files and tasks from later launches; `on_launch` runs on a background thread
+pub fn listen(data_dir: &Path, on_launch: impl Fn(Launch) + Send + 'static) {
+    let Ok(listener) = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)) else { return };
+    let Ok(port) = listener.local_addr().map(|a| a.port()) else { return };
+    let token = uuid::Uuid::new_v4().to_string();
+    if fs::write(data_dir.join(LOCK_FILE), format!("{}\n{}\n", port, token)).is_err() { return; }
+    debug!(target: "app", "📂 [APP] Accepting files and tasks from other launches on port {}", port);
+
+    std::thread::Builder::new().name("frontier-instance".into()).spawn(move || {
+        for stream in listener.incoming().flatten() {
+            let _ = stream.set_read_timeout(Some(HANDSHAKE_TIMEOUT));
+            let Ok(mut writer) = stream.try_clone() else { continue };
+            let mut reader = BufReader::new(stream);
+            let (mut sent_token, mut payload) = (String::new(), String::new());
+            if reader.read_line(&mut sent_token).is_err() || sent_token.trim() != token { continue; }
+            if reader.read_line(&mut payload).is_err() { continue; }
+            if let Ok(launch) = serde_json::from_str::<Launch>(payload.trim()) {
+                let _ = writer.write_all(b"ok\n");
+                on_launch(launch);
+            }
+        }
+    }).ok();
+}
+
+// Windows canonical paths start with \\?\, which pages shouldn't have to strip
+fn clean_path(path: PathBuf) -> String {
+    let text = path.to_string_lossy().to_string();
+    text.strip_prefix(r"\\?\").map(str::to_string).unwrap_or(text)
+}
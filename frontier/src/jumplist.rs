@@ -0,0 +1,238 @@
+// Copyright (c) 2026 The Frontier Framework Authors
+// SPDX-License-Identifier: Apache-2.0 WITH LLVM-exception OR MIT
+
+// Jump List Module
+//
+// The menu Windows shows when the app's taskbar button is right-clicked: the
+// tasks from [jump_list] (or Frontier.jumpList.setTasks) and the Recent
+// category, which lists documents added with Frontier.jumpList.addRecent or
+// opened through [[file_associations]]. A task starts the executable with
+// `--task open:<page>` or `--task trigger:<name>:<args>`; like an opened file,
+// the task is handed to the running instance when there is one (instance.rs).
+// Elsewhere the calls do nothing.
+
+use tracing::{debug, warn};
+
+use crate::config::JumpTask;
+
+pub const TASK_FLAG: &str = "--task";
+
+// What a --task argument asks for
+pub enum TaskAction {
+    Open(String),
+    Trigger(String, String),
+    Notification(String, String, String), // Tag, action id and reply of a notification button (notification.rs)
+}
+
+pub fn parse_task(arg: &str) -> Option<TaskAction> {
+    match arg.split_once(':')? {
+        ("open", page) if !page.is_empty() => Some(TaskAction::Open(page.to_string())),
+        ("trigger", rest) => {
+            let (trigger, args) = rest.split_once(':').unwrap_or((rest, ""));
+            // Only backend triggers, not the runtime's own IPC messages
+            let reserved = trigger.is_empty() || trigger.starts_with("__") || trigger == "open" || trigger == "spawn";
+            (!reserved).then(|| TaskAction::Trigger(trigger.to_string(), args.to_string()))
+        }
+        ("notification", rest) => {
+            let mut parts = rest.splitn(3, ':');
+            let (tag, action) = (parts.next()?, parts.next()?);
+            Some(TaskAction::Notification(tag.to_string(), action.to_string(), parts.next().unwrap_or("").to_string()))
+        }
+        _ => None,
+    }
+}
+
+// --task value for a task (None when it has neither `open` nor `trigger`);
+// also what the macOS Dock menu items run
+pub fn task_argument(task: &JumpTask) -> Option<String> {
+    match (&task.open, &task.trigger) {
+        (Some(page), _) => Some(format!("open:{}", page)),
+        (None, Some(trigger)) => Some(format!("trigger:{}:{}", trigger, task.args.as_deref().unwrap_or(""))),
+        (None, None) => None,
+    }
+}
+
+// Replace the jump list; the shell is slow to answer, so it runs on its own thread
+pub fn set(tasks: Vec<JumpTask>, recent: bool) {
+    let links: Vec<(String, String, String)> = tasks.iter().filter_map(|task| {
+        let Some(arg) = task_argument(task) else {
+            warn!(target: "config", "⚠️ [CONFIG] Jump list task '{}' has neither open nor trigger", task.title);
+            return None;
+        };
+        // Quoted for CommandLineToArgvW: \" is a quote, a trailing backslash is doubled
+        let mut quoted = arg.replace('"', "\\\"");
+        if quoted.ends_with('\\') { quoted.push('\\'); }
+        Some((task.title.clone(), task.description.clone().unwrap_or_default(), format!("{} \"{}\"", TASK_FLAG, quoted)))
+    }).collect();
+
+    #[cfg(windows)]
+    std::thread::spawn(move || {
+        if shell::set_jump_list(&links, recent) {
+            debug!(target: "app", "📌 [APP] Jump list updated ({} task(s))", links.len());
+        } else {
+            warn!(target: "app", "⚠️ [APP] Cannot update the jump list");
+        }
+    });
+    #[cfg(not(windows))]
+    {
+        let _ = (links, recent);
+        debug!(target: "app", "📌 [APP] Jump lists are only shown on Windows");
+    }
+}
+
+// Add a document to the Recent category (and the OS's recent files)
+pub fn add_recent(path: &str) {
+    #[cfg(windows)]
+    shell::add_recent_document(path);
+    #[cfg(not(windows))]
+    let _ = path;
+}
+
+#[cfg(windows)]
+#[allow(non_snake_case, non_upper_case_globals, clippy::upper_case_acronyms)]
+mod shell {
+    use std::ffi::OsStr;
+    use std::ops::Deref;
+    use std::os::windows::ffi::OsStrExt;
+    use std::ptr;
+    use winapi::ctypes::c_void;
+    use winapi::shared::guiddef::{GUID, REFIID};
+    use winapi::shared::minwindef::UINT;
+    use winapi::shared::winerror::{HRESULT, SUCCEEDED};
+    use winapi::shared::wtypes::VT_LPWSTR;
+    use winapi::shared::wtypesbase::CLSCTX_INPROC_SERVER;
+    use winapi::um::combaseapi::{CoCreateInstance, CoInitializeEx, CoUninitialize};
+    use winapi::um::objbase::COINIT_APARTMENTTHREADED;
+    use winapi::um::propidl::PROPVARIANT;
+    use winapi::um::propkey::PKEY_Title;
+    use winapi::um::propsys::IPropertyStore;
+    use winapi::um::shobjidl_core::IShellLinkW;
+    use winapi::um::unknwnbase::{IUnknown, IUnknownVtbl};
+    use winapi::um::winnt::LPCWSTR;
+    use winapi::{Interface, DEFINE_GUID, RIDL};
+
+    // Jump list interfaces winapi doesn't declare (shobjidl_core.h, objectarray.h)
+    RIDL!{#[uuid(0x92ca9dcd, 0x5622, 0x4bba, 0xa8, 0x05, 0x5e, 0x9f, 0x54, 0x1b, 0xd8, 0xc9)]
+    interface IObjectArray(IObjectArrayVtbl): IUnknown(IUnknownVtbl) {
+        fn GetCount(pcObjects: *mut UINT,) -> HRESULT,
+        fn GetAt(uiIndex: UINT, riid: REFIID, ppv: *mut *mut c_void,) -> HRESULT,
+    }}
+    RIDL!{#[uuid(0x5632b1a4, 0xe38a, 0x400a, 0x92, 0x8a, 0xd4, 0xcd, 0x63, 0x23, 0x02, 0x95)]
+    interface IObjectCollection(IObjectCollectionVtbl): IObjectArray(IObjectArrayVtbl) {
+        fn AddObject(punk: *mut IUnknown,) -> HRESULT,
+        fn AddFromArray(poaSource: *mut IObjectArray,) -> HRESULT,
+        fn RemoveObjectAt(uiIndex: UINT,) -> HRESULT,
+        fn Clear() -> HRESULT,
+    }}
+    RIDL!{#[uuid(0x6332debf, 0x87b5, 0x4670, 0x90, 0xc0, 0x5e, 0x57, 0xb4, 0x08, 0xa4, 0x9e)]
+    interface ICustomDestinationList(ICustomDestinationListVtbl): IUnknown(IUnknownVtbl) {
+        fn SetAppID(pszAppID: LPCWSTR,) -> HRESULT,
+        fn BeginList(pcMinSlots: *mut UINT, riid: REFIID, ppv: *mut *mut c_void,) -> HRESULT,
+        fn AppendCategory(pszCategory: LPCWSTR, poa: *mut IObjectArray,) -> HRESULT,
+        fn AppendKnownCategory(category: i32,) -> HRESULT,
+        fn AddUserTasks(poa: *mut IObjectArray,) -> HRESULT,
+        fn CommitList() -> HRESULT,
+        fn GetRemovedDestinations(riid: REFIID, ppv: *mut *mut c_void,) -> HRESULT,
+        fn DeleteList(pszAppID: LPCWSTR,) -> HRESULT,
+        fn AbortList() -> HRESULT,
+    }}
+    DEFINE_GUID!{CLSID_DestinationList, 0x77f10cf0, 0x3db5, 0x4966, 0xb5, 0x20, 0xb7, 0xc5, 0x4f, 0xd3, 0x5e, 0xd6}
+    DEFINE_GUID!{CLSID_EnumerableObjectCollection, 0x2d3468c1, 0x36a7, 0x43b6, 0xac, 0x24, 0xd3, 0xf0, 0x2f, 0xd9, 0x60, 0x7a}
+    DEFINE_GUID!{CLSID_ShellLink, 0x00021401, 0x0000, 0x0000, 0xc0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x46}
+    const KDC_RECENT: i32 = 2;
+    const SHARD_PATHW: u32 = 3;
+
+    #[link(name = "shell32")]
+    extern "system" {
+        fn SHAddToRecentDocs(flags: u32, pv: *const c_void);
+    }
+
+    // COM pointer, released when dropped
+    struct Com<T: Interface>(*mut T);
+
+    impl<T: Interface> Com<T> {
+        fn create(clsid: &GUID) -> Option<Self> {
+            let mut object = ptr::null_mut();
+            let hr = unsafe { CoCreateInstance(clsid, ptr::null_mut(), CLSCTX_INPROC_SERVER, &T::uuidof(), &mut object) };
+            (SUCCEEDED(hr) && !object.is_null()).then(|| Com(object as *mut T))
+        }
+
+        fn cast<U: Interface>(&self) -> Option<Com<U>> {
+            let mut object = ptr::null_mut();
+            let hr = unsafe { (*(self.0 as *mut IUnknown)).QueryInterface(&U::uuidof(), &mut object) };
+            (SUCCEEDED(hr) && !object.is_null()).then(|| Com(object as *mut U))
+        }
+    }
+
+    impl<T: Interface> Deref for Com<T> {
+        type Target = T;
+        fn deref(&self) -> &T {
+            unsafe { &*self.0 }
+        }
+    }
+
+    impl<T: Interface> Drop for Com<T> {
+        fn drop(&mut self) {
+            unsafe { (*(self.0 as *mut IUnknown)).Release(); }
+        }
+    }
+
+    fn wide(s: &str) -> Vec<u16> {
+        OsStr::new(s).encode_wide().chain(Some(0)).collect()
+    }
+
+    fn check(hr: HRESULT) -> Option<()> {
+        SUCCEEDED(hr).then_some(())
+    }
+
+    // (title, tooltip, arguments) of each task
+    pub fn set_jump_list(links: &[(String, String, String)], recent: bool) -> bool {
+        unsafe {
+            let initialized = SUCCEEDED(CoInitializeEx(ptr::null_mut(), COINIT_APARTMENTTHREADED));
+            let done = Com::<ICustomDestinationList>::create(&CLSID_DestinationList).is_some_and(|list| {
+                let done = fill(&list, links, recent).is_some();
+                if !done { list.AbortList(); }
+                done
+            });
+            if initialized { CoUninitialize(); }
+            done
+        }
+    }
+
+    unsafe fn fill(list: &ICustomDestinationList, links: &[(String, String, String)], recent: bool) -> Option<()> {
+        let mut slots = 0;
+        let mut removed = ptr::null_mut();
+        check(list.BeginList(&mut slots, &IObjectArray::uuidof(), &mut removed))?;
+        if !removed.is_null() { drop(Com(removed as *mut IObjectArray)); }
+        if recent {
+            check(list.AppendKnownCategory(KDC_RECENT))?;
+        }
+        if !links.is_empty() {
+            let exe = wide(&std::env::current_exe().ok()?.to_string_lossy());
+            let tasks = Com::<IObjectCollection>::create(&CLSID_EnumerableObjectCollection)?;
+            for (title, description, arguments) in links {
+                let link = Com::<IShellLinkW>::create(&CLSID_ShellLink)?;
+                check(link.SetPath(exe.as_ptr()))?;
+                check(link.SetArguments(wide(arguments).as_ptr()))?;
+                check(link.SetIconLocation(exe.as_ptr(), 0))?;
+                check(link.SetDescription(wide(description).as_ptr()))?;
+                // The task's label is the link's title property
+                let store = link.cast::<IPropertyStore>()?;
+                let mut title = wide(title);
+                let mut value: PROPVARIANT = std::mem::zeroed();
+                value.vt = VT_LPWSTR as u16;
+                *value.data.pwszVal_mut() = title.as_mut_ptr();
+                check(store.SetValue(&PKEY_Title, &value))?;
+                check(store.Commit())?;
+                check(tasks.AddObject(link.0 as *mut IUnknown))?;
+            }
+            check(list.AddUserTasks(tasks.0 as *mut IObjectArray))?;
+        }
+        check(list.CommitList())
+    }
+
+    pub fn add_recent_document(path: &str) {
+        let path = wide(path);
+        unsafe { SHAddToRecentDocs(SHARD_PATHW, path.as_ptr() as *const _) }
+    }
+}
@@ -0,0 +1,49 @@
+// Copyright (c) 2026 The Frontier Framework Authors
+// SPDX-License-Identifier: Apache-2.0 WITH LLVM-exception OR MIT
+
+// Window Layouts Module
+//
+// Named sets of windows and their places on screen, for dashboard-style apps:
+// [[layouts.<name>]] in frontier.toml, and the layouts pages save or define at
+// runtime (Frontier.layout), which are kept in <data_dir>/layouts.json and win
+// over configured ones of the same name. Applying a layout moves the windows
+// that are already open to their places and opens the missing ones; windows
+// that aren't part of it are left alone.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use crate::config::{self, LayoutWindow};
+use crate::window;
+
+const LAYOUTS_FILE: &str = "layouts.json";
+
+// Layouts saved at runtime
+fn saved(data_dir: &Path) -> HashMap<String, Vec<LayoutWindow>> {
+    fs::read_to_string(data_dir.join(LAYOUTS_FILE)).ok()
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+pub fn find(base_dir: &Path, data_dir: &Path, name: &str) -> Option<Vec<LayoutWindow>> {
+    saved(data_dir).remove(name)
+        .or_else(|| config::load_layouts(&base_dir.join("frontier.toml")).remove(name))
+}
+
+pub fn store(data_dir: &Path, name: &str, windows: Vec<LayoutWindow>) -> std::io::Result<()> {
+    let mut layouts = saved(data_dir);
+    layouts.insert(name.to_string(), windows);
+    fs::write(data_dir.join(LAYOUTS_FILE), serde_json::to_string_pretty(&layouts)?)
+}
+
+// Position of a window of `size` on a monitor of `screen` size, relative to the
+// monitor (None when the layout leaves it where it is); `monitors` are the sizes
+// of all monitors, for the formulas
+pub fn place(entry: &LayoutWindow, screen: (f64, f64), size: (f64, f64), monitors: &[(f64, f64)]) -> Option<(f64, f64)> {
+    if entry.x.is_none() && entry.y.is_none() { return None; }
+    let eval = |formula: &Option<String>| formula.as_deref()
+        .map(|f| window::evaluate_math_expression(f, screen, size, monitors))
+        .unwrap_or(0.0);
+    Some((eval(&entry.x), eval(&entry.y)))
+}
@@ -0,0 +1,171 @@
+// Copyright (c) 2026 The Frontier Framework Authors
+// SPDX-License-Identifier: Apache-2.0 WITH LLVM-exception OR MIT
+
+// Logging Module
+//
+// This module installs the runtime's `tracing` subscriber from the [logging]
+// section of frontier.toml. Log lines keep their emoji/[CATEGORY] prefixes on
+// the console, and are also written (with time, level and span context) to a
+// size-rotated log file in <data_dir>/logs, so released apps (which have no
+// console) still leave evidence behind.
+//
+// Every log call uses a category target ("asset", "routing", "ipc", ...) which
+// is what [logging.filters] keys refer to.
+
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::collections::VecDeque;
+use std::sync::Mutex;
+use tracing::{Event, Subscriber};
+use tracing_subscriber::fmt::{self, format, FmtContext, FormatEvent, FormatFields};
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::{prelude::*, EnvFilter};
+
+use crate::config::LoggingConfig;
+
+const DEFAULT_LOG_FILE: &str = "logs/frontier.log";
+const DEFAULT_MAX_SIZE_MB: u64 = 5;
+const DEFAULT_MAX_FILES: u32 = 3;
+const RECENT_LINES: usize = 200;
+
+// Last log lines, kept in memory for crash reports
+static RECENT: Mutex<VecDeque<String>> = Mutex::new(VecDeque::new());
+
+// Install the global subscriber (console in dev mode and with --console, rotating log file)
+pub fn init(cfg: &LoggingConfig, is_dev: bool, console: bool, data_dir: &Path) {
+    let default_level = if is_dev { "debug" } else if console { "info" } else { "warn" };
+    let mut directives = vec![cfg.level.clone().unwrap_or_else(|| default_level.into())];
+    for (category, level) in &cfg.filters {
+        directives.push(format!("{}={}", category.to_lowercase(), level));
+    }
+    let filter = EnvFilter::builder().parse_lossy(directives.join(","));
+
+    let stderr = console.then(|| fmt::layer().with_writer(io::stderr).event_format(ConsoleFormat));
+
+    // `file = ""` turns the log file off
+    let file_name = cfg.file.clone().unwrap_or_else(|| DEFAULT_LOG_FILE.into());
+    let file = if file_name.is_empty() {
+        None
+    } else {
+        let path = resolve_log_path(&file_name, data_dir);
+        let max_bytes = cfg.max_size_mb.unwrap_or(DEFAULT_MAX_SIZE_MB) * 1024 * 1024;
+        let max_files = cfg.max_files.unwrap_or(DEFAULT_MAX_FILES);
+        match RotatingFile::open(path.clone(), max_bytes, max_files) {
+            Ok(writer) => Some(fmt::layer().with_writer(Mutex::new(writer)).with_ansi(false)),
+            Err(e) => {
+                if console { eprintln!("❌ [LOG] Cannot open {}: {}", path.display(), e); }
+                None
+            }
+        }
+    };
+
+    let recent = fmt::layer().with_writer(|| RecentWriter).with_ansi(false);
+
+    let _ = tracing_subscriber::registry()
+        .with(filter)
+        .with(stderr)
+        .with(file)
+        .with(recent)
+        .try_init();
+}
+
+// Most recent log lines (oldest first)
+pub fn recent_lines() -> Vec<String> {
+    RECENT.lock().map(|r| r.iter().cloned().collect()).unwrap_or_default()
+}
+
+struct RecentWriter;
+
+impl Write for RecentWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if let Ok(mut recent) = RECENT.lock() {
+            for line in String::from_utf8_lossy(buf).lines() {
+                if recent.len() == RECENT_LINES { recent.pop_front(); }
+                recent.push_back(line.to_string());
+            }
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+// Console lines are just the message, as they were before spans existed
+struct ConsoleFormat;
+
+impl<S, N> FormatEvent<S, N> for ConsoleFormat
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+    N: for<'a> FormatFields<'a> + 'static,
+{
+    fn format_event(&self, ctx: &FmtContext<'_, S, N>, mut writer: format::Writer<'_>, event: &Event<'_>) -> std::fmt::Result {
+        ctx.field_format().format_fields(writer.by_ref(), event)?;
+        writeln!(writer)
+    }
+}
+
+// Relative log paths are kept under the data directory
+fn resolve_log_path(file: &str, data_dir: &Path) -> PathBuf {
+    let path = PathBuf::from(file);
+    if path.is_absolute() { path } else { data_dir.join(path) }
+}
+
+// Log file that moves itself to <name>.1 (then .2, ...) once it grows past max_bytes
+struct RotatingFile {
+    path: PathBuf,
+    file: File,
+    size: u64,
+    max_bytes: u64,
+    max_files: u32,
+}
+
+impl RotatingFile {
+    fn open(path: PathBuf, max_bytes: u64, max_files: u32) -> io::Result<Self> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let file = OpenOptions::new().create(true).append(true).open(&path)?;
+        let size = file.metadata().map(|m| m.len()).unwrap_or(0);
+        Ok(Self { path, file, size, max_bytes, max_files })
+    }
+
+    fn rotate(&mut self) -> io::Result<()> {
+        self.file.flush()?;
+        for i in (1..self.max_files).rev() {
+            let from = self.rotated_path(i);
+            if from.exists() {
+                let _ = fs::rename(&from, self.rotated_path(i + 1));
+            }
+        }
+        if self.max_files > 0 {
+            let _ = fs::rename(&self.path, self.rotated_path(1));
+        }
+        self.file = OpenOptions::new().create(true).write(true).truncate(true).open(&self.path)?;
+        self.size = 0;
+        Ok(())
+    }
+
+    fn rotated_path(&self, index: u32) -> PathBuf {
+        let mut name = self.path.file_name().unwrap_or_default().to_os_string();
+        name.push(format!(".{}", index));
+        self.path.with_file_name(name)
+    }
+}
+
+impl Write for RotatingFile {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.max_bytes > 0 && self.size > 0 && self.size + buf.len() as u64 > self.max_bytes {
+            self.rotate()?;
+        }
+        let written = self.file.write(buf)?;
+        self.size += written as u64;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.file.flush()
+    }
+}
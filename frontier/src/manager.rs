@@ -14,9 +14,13 @@ mod config;
 mod backend;
 mod assets;
 mod build;
+mod doctor;
+mod report;
+mod cli;
 
+use std::env::consts::EXE_SUFFIX;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 // --- CONSTANTS ---
 const APP_DIR: &str = "app";
@@ -26,12 +30,47 @@ const DIST_DIR: &str = "dist";
 const BASE_DIR: &str = ".frontier";
 
 fn main() {
-    let args: Vec<String> = std::env::args().collect();
-    if args.len() > 1 && args[1] == "install" {
-        return;
+    let matches = cli::command().get_matches();
+    // Messages from the shared config module are printed as plain stderr lines
+    tracing_subscriber::fmt()
+        .with_writer(std::io::stderr)
+        .without_time()
+        .with_level(false)
+        .with_target(false)
+        .init();
+
+    let build_args = match matches.subcommand() {
+        Some(("install", _)) => return,
+        Some(("doctor", _)) => {
+            let failures = doctor::run_doctor(Path::new(APP_DIR), Path::new(MODULES_DIR), Path::new("frontier.toml"));
+            std::process::exit(if failures > 0 { 1 } else { 0 });
+        }
+        Some(("check", _)) => std::process::exit(check_config()),
+        Some(("dev", _)) => std::process::exit(run_dev()),
+        Some(("completions", sub)) => {
+            if let Some(shell) = sub.get_one::<clap_complete::Shell>("shell") {
+                cli::print_completions(*shell);
+            }
+            return;
+        }
+        Some(("man", _)) => {
+            if let Err(e) = cli::print_man_page() {
+                eprintln!("❌ Cannot write the man page: {}", e);
+                std::process::exit(1);
+            }
+            return;
+        }
+        Some(("build", sub)) => Some(sub),
+        _ => None,
+    };
+    let option = |name: &str| build_args.and_then(|m| m.get_one::<String>(name)).cloned();
+
+    // --format json: emit one JSON record per line instead of log text
+    if option("format").as_deref() == Some("json") {
+        report::enable_json();
     }
 
-    
+    select_config_environment(option("profile"));
 
     // 1. Cleanup
     if Path::new(DIST_DIR).exists() {
@@ -44,18 +83,122 @@ fn main() {
     fs::create_dir_all(format!("{}/frontend", ASSETS_DIR)).expect("Failed to create frontend directory");
     fs::create_dir_all(DIST_DIR).expect("Failed to create dist directory");
 
-    println!("⚙️  [LOADING] Configuration...");
+    report::phase("config", "⚙️  [LOADING] Configuration...");
+    // The runtime would drop the invalid parts, so a release never ships with them
+    let overlay = format!("frontier.{}.toml", config::active_environment());
+    let errors: Vec<String> = config::check_config_files(Path::new("frontier.toml"))
+        .into_iter()
+        .filter(|(file, _)| file.ends_with("frontier.toml") || file.ends_with(&overlay))
+        .filter_map(|(file, outcome)| outcome.err().map(|e| format!("{}:\n{}", file.display(), e)))
+        .collect();
+    if !errors.is_empty() {
+        panic!("Invalid configuration (see `frontier check`):\n{}", errors.join("\n"));
+    }
+    let (missing_env, strict_env) = config::missing_env_vars(Path::new("frontier.toml"));
+    if !missing_env.is_empty() {
+        if strict_env {
+            panic!("Missing environment variable(s) in frontier.toml: {}", missing_env.join(", "));
+        }
+        report::warn(&format!("Unset environment variable(s) in frontier.toml: {}", missing_env.join(", ")));
+    }
     let app_config = config::load_config(Path::new("frontier.toml"));
+    let hooks = config::load_hooks_config(Path::new("frontier.toml"));
+    let final_name = app_config.name.clone().unwrap_or_else(|| "MyApp".into());
+
+    run_hook("pre_build", &hooks.pre_build, &app_config, &final_name);
 
-    println!("📦 [PROCESSING] Backend files:");
+    report::phase("backend", "📦 [PROCESSING] Backend files:");
     process_backend();
+    report::phase("frontend", "🎨 [COPYING] Frontend assets...");
     copy_frontend_assets();
+    bundle_webview2_bootstrapper();
 
-    println!("⚙️  [COMPILING] Core...");
-    compile_core(&app_config);
+    run_hook("post_backend", &hooks.post_backend, &app_config, &final_name);
 
-    let final_name = app_config.name.clone().unwrap_or_else(|| "MyApp".into());
-    finalize_distribution(&final_name);
+    report::phase("compile", "⚙️  [COMPILING] Core...");
+    let profile = select_build_profile(option("profile"));
+    let mut build_config = make_build_config(&app_config, &profile, select_build_target(option("target")));
+    if build_args.is_some_and(|m| m.get_flag("console")) {
+        build_config.console = true;
+    }
+    if build_config.console {
+        report::step("compile", "console", "🖥️  [CONSOLE] Console variant (the log is shown in a console window)");
+    }
+    // The profile's [features] overrides are baked into the bundled frontier.toml
+    if let Err(e) = config::write_feature_overrides(&Path::new(ASSETS_DIR).join("frontier.toml"), &profile.features) {
+        panic!("Failed to apply profile features: {}", e);
+    }
+    compile_core(&build_config);
+
+    let product = finalize_distribution(&final_name, &build_config);
+    copy_webview2_runtime();
+
+    run_hook("post_bundle", &hooks.post_bundle, &app_config, &final_name);
+    // Last, so it's the final record of `--format json`
+    report::result(&product);
+}
+
+// Run the app in dev mode (what the `dev` script command does), returning its exit code
+fn run_dev() -> i32 {
+    let manifest = Path::new(BASE_DIR).join("Cargo.toml");
+    let status = std::process::Command::new("cargo")
+        .args(["run", "--manifest-path", &manifest.to_string_lossy(), "--bin", "core", "--", "--dev"])
+        .status();
+    status.map(|s| s.code().unwrap_or(1)).unwrap_or(1)
+}
+
+// Validate frontier.toml and its overlays, returning the process exit code
+fn check_config() -> i32 {
+    let config_path = Path::new("frontier.toml");
+    if !config_path.exists() {
+        println!("❌ frontier.toml not found in the current directory");
+        return 1;
+    }
+
+    let mut failed = false;
+    for (file, outcome) in config::check_config_files(config_path) {
+        match outcome {
+            Ok(_) => println!("✅ {} is valid", file.display()),
+            Err(e) => {
+                failed = true;
+                println!("❌ {}:\n{}", file.display(), e);
+            }
+        }
+    }
+
+    let (missing_env, strict_env) = config::missing_env_vars(config_path);
+    if !missing_env.is_empty() {
+        if strict_env {
+            failed = true;
+            println!("❌ Missing environment variable(s) ([env] strict): {}", missing_env.join(", "));
+        } else {
+            println!("⚠️  Unset environment variable(s), replaced with \"\": {}", missing_env.join(", "));
+        }
+    }
+    if failed { 1 } else { 0 }
+}
+
+// Run a [hooks] command from frontier.toml, if configured
+fn run_hook(name: &str, command: &Option<String>, app_config: &config::AppConfig, app_name: &str) {
+    let Some(command) = command else { return };
+
+    report::step("hook", name, &format!("🪝 [HOOK] {}: {}", name, command));
+    let exe_path = if cfg!(target_os = "macos") {
+        Path::new(DIST_DIR).join(format!("{}.app", app_name)).join("Contents/MacOS").join(app_name)
+    } else {
+        Path::new(DIST_DIR).join(format!("{}{}", app_name, EXE_SUFFIX))
+    };
+    let env = [
+        ("FRONTIER_APP_NAME", app_name.to_string()),
+        ("FRONTIER_APP_VERSION", app_config.version.clone().unwrap_or_default()),
+        ("FRONTIER_DIST_DIR", DIST_DIR.to_string()),
+        ("FRONTIER_ASSETS_DIR", ASSETS_DIR.to_string()),
+        ("FRONTIER_EXE_PATH", exe_path.to_string_lossy().to_string()),
+    ];
+
+    if let Err(e) = build::run_hook(name, command, &env) {
+        panic!("{}", e);
+    }
 }
 
 // Load backend modules and process files
@@ -73,52 +216,193 @@ fn copy_frontend_assets() {
     let src = Path::new(APP_DIR).join("frontend");
     let dst = Path::new(ASSETS_DIR).join("frontend");
 
-    fs::create_dir_all(&dst).ok();
-    assets::copy_frontend_files(&src, &dst);
+    let frontend_cfg = config::load_frontend_config(Path::new("frontier.toml"));
+    if frontend_cfg.archive {
+        // One compressed file (core's embedded::FRONTEND_ARCHIVE) instead of a folder
+        fs::create_dir_all(ASSETS_DIR).ok();
+        match assets::archive_frontend_files(&src, &Path::new(ASSETS_DIR).join("frontend.zip"), &frontend_cfg.exclude) {
+            Ok(count) => report::step("frontend", "archive", &format!("🗜️  [ARCHIVE] frontend.zip ({} files)", count)),
+            Err(e) => panic!("Failed to pack the frontend into frontend.zip: {}", e),
+        }
+    } else {
+        fs::create_dir_all(&dst).ok();
+        assets::copy_frontend_files(&src, &dst, &frontend_cfg.exclude);
+    }
+
+    // Bundle frontier.toml (merged with its environment overlay) so the runtime
+    // sees the same [window]/[security] settings
+    let _ = config::write_merged_config(Path::new("frontier.toml"), &Path::new(ASSETS_DIR).join("frontier.toml"));
+
+    // Bundle [i18n] locale files as assets/locales
+    let i18n_cfg = config::load_i18n_config(Path::new("frontier.toml"));
+    let locales_src = Path::new(i18n_cfg.dir.as_deref().unwrap_or("app/locales"));
+    assets::copy_frontend_files(locales_src, &Path::new(ASSETS_DIR).join("locales"), &[]);
 
     // Copy icon if present
     if let Some(window_cfg) = config::load_window_config(Path::new("frontier.toml")) {
         if let Some(icon_path) = window_cfg.icon {
             let icon_src = Path::new(&icon_path);
-            let _ = assets::copy_icon(icon_src, Path::new(ASSETS_DIR));
+            let _ = assets::copy_icon(icon_src, Path::new(ASSETS_DIR), "app_icon");
         }
     }
+    if let Some(icon_path) = config::load_tray_config(Path::new("frontier.toml")).and_then(|tray| tray.icon) {
+        let _ = assets::copy_icon(Path::new(&icon_path), Path::new(ASSETS_DIR), "tray_icon");
+    }
 }
 
-// Compile the core binary using cargo
-fn compile_core(app_config: &config::AppConfig) {
-    let build_config = build::BuildConfig {
+// [webview2] mode = "bootstrapper": embed the setup the runtime runs when
+// WebView2 is missing (core's webview2::BOOTSTRAPPER_ASSET)
+fn bundle_webview2_bootstrapper() {
+    let webview2 = config::load_webview2_config(Path::new("frontier.toml"));
+    if !cfg!(windows) || webview2.mode.as_deref() != Some("bootstrapper") { return; }
+
+    let Some(setup) = webview2.bootstrapper else {
+        report::warn("[webview2] mode = \"bootstrapper\" without a bootstrapper file, users will be sent to the download page");
+        return;
+    };
+    let dst = Path::new(ASSETS_DIR).join("webview2");
+    let _ = fs::create_dir_all(&dst);
+    if let Err(e) = fs::copy(&setup, dst.join("MicrosoftEdgeWebview2Setup.exe")) {
+        panic!("Failed to bundle the WebView2 bootstrapper '{}': {}", setup, e);
+    }
+    report::step("backend", "webview2", &format!("🧭 [WEBVIEW2] Bootstrapper bundled: {}", setup));
+}
+
+// [webview2] mode = "fixed": ship the Fixed Version runtime as dist/WebView2
+// (core's webview2::FIXED_RUNTIME_DIR)
+fn copy_webview2_runtime() {
+    let webview2 = config::load_webview2_config(Path::new("frontier.toml"));
+    if !cfg!(windows) || webview2.mode.as_deref() != Some("fixed") { return; }
+
+    let src = PathBuf::from(webview2.runtime_dir.unwrap_or_else(|| "WebView2".into()));
+    if !src.join("msedgewebview2.exe").is_file() {
+        panic!("[webview2] runtime_dir '{}' is not an extracted Fixed Version runtime (msedgewebview2.exe not found)", src.display());
+    }
+    assets::copy_frontend_files(&src, &Path::new(DIST_DIR).join("WebView2"), &[]);
+    report::step("bundle", "webview2", &format!("🧭 [WEBVIEW2] Fixed Version runtime copied to {}/WebView2", DIST_DIR));
+}
+
+// Resolve the build profile (--profile <name> or [build] profile)
+fn select_build_profile(requested: Option<String>) -> config::BuildProfile {
+    let settings = config::load_build_settings(Path::new("frontier.toml"));
+    let profile_name = requested
+        .or(settings.profile.clone())
+        .unwrap_or_else(|| "release".into());
+
+    match settings.profiles.get(&profile_name) {
+        Some(p) => p.clone(),
+        None => {
+            if profile_name != "release" {
+                panic!("Build profile '{}' not found in [build.profiles]", profile_name);
+            }
+            config::BuildProfile::default()
+        }
+    }
+}
+
+// The build profile (--profile or [build] profile) also picks the config overlay,
+// frontier.<profile>.toml, unless FRONTIER_ENV names one; "release" keeps frontier.prod.toml
+fn select_config_environment(requested: Option<String>) {
+    if std::env::var("FRONTIER_ENV").map_or(true, |e| e.trim().is_empty()) {
+        let profile = requested.or(config::load_build_settings(Path::new("frontier.toml")).profile);
+        if let Some(profile) = profile.filter(|p| p != "release") {
+            std::env::set_var("FRONTIER_ENV", profile);
+        }
+    }
+    let environment = config::active_environment();
+    let overlay = format!("frontier.{}.toml", environment);
+    if environment != "prod" && !Path::new(&overlay).exists() {
+        report::warn(&format!("{} not found, this build uses frontier.toml without an overlay", overlay));
+    }
+}
+
+// Resolve the target triple (--target <triple> or [build] target); None builds
+// for this machine
+fn select_build_target(requested: Option<String>) -> Option<String> {
+    let target = requested.or(config::load_build_settings(Path::new("frontier.toml")).target)?;
+    report::step("compile", &target, &format!("🎯 [TARGET] {}", target));
+    Some(target)
+}
+
+// Turn the app metadata and build profile into a BuildConfig
+fn make_build_config(app_config: &config::AppConfig, profile: &config::BuildProfile, target: Option<String>) -> build::BuildConfig {
+    let profile = profile.clone();
+    build::BuildConfig {
         app_name: app_config.name.clone(),
         version: app_config.version.clone(),
         description: app_config.description.clone(),
         copyright: app_config.copyright.clone(),
-    };
+        strip: profile.strip,
+        lto: profile.lto,
+        panic_abort: profile.panic_abort,
+        opt_level: profile.opt_level,
+        upx: profile.upx,
+        upx_args: profile.upx_args,
+        target,
+        console: profile.console,
+    }
+}
 
+// Compile the core binary using cargo
+fn compile_core(build_config: &build::BuildConfig) {
     match build::run_cargo_build(
         Path::new(".frontier/Cargo.toml"),
         "core",
-        &build_config,
+        build_config,
     ) {
-        Ok(_) => println!("✅ Core compiled successfully"),
+        Ok(_) => report::done("compile", "✅ Core compiled successfully"),
         Err(e) => panic!("{}", e),
     }
 }
 
-// Move the compiled executable to dist/ and rename it
-fn finalize_distribution(app_name: &str) {
-    let target_dir = Path::new(BASE_DIR).join("target/release");
+// Move the compiled executable to dist/ and rename it, returning what was built (the exe or the .app)
+fn finalize_distribution(app_name: &str, build_config: &build::BuildConfig) -> String {
+    let target_dir = build::release_dir(&Path::new(BASE_DIR).join("target"), build_config.target.as_deref());
     let dist_dir = Path::new(DIST_DIR);
-    let core_name = "core.exe";
-    let final_exe_name = format!("{}.exe", app_name);
+    let core_name = format!("core{}", EXE_SUFFIX);
+    let final_exe_name = format!("{}{}", app_name, EXE_SUFFIX);
 
     let src_exe = target_dir.join(core_name);
     let dst_exe = dist_dir.join(&final_exe_name);
 
-    match build::finalize_executable(&src_exe, &dst_exe) {
-        Ok(_) => {
-            println!("\n✅ SUCCESS!");
-            println!("📁 Native App: {}/{}", DIST_DIR, final_exe_name);
+    report::phase("bundle", "📦 [BUNDLING] Executable...");
+    if let Err(e) = build::finalize_executable(&src_exe, &dst_exe, build_config) {
+        panic!("{}", e);
+    }
+
+    if cfg!(target_os = "macos") {
+        let macos = config::load_macos_config(Path::new("frontier.toml"));
+        let bundle = build::MacBundle {
+            identifier: config::bundle_identifier(&config::load_config(Path::new("frontier.toml")), &macos),
+            minimum_system_version: macos.minimum_system_version.unwrap_or_else(|| "10.13".into()),
+            file_associations: config::load_file_associations(Path::new("frontier.toml")),
+        };
+        if let Err(e) = build::write_app_bundle(dist_dir, app_name, &dst_exe, build_config, &bundle, find_app_icon().as_deref()) {
+            panic!("{}", e);
         }
-        Err(e) => panic!("{}", e),
+        return format!("{}/{}.app", DIST_DIR, app_name);
     }
+
+    if cfg!(target_os = "linux") {
+        let app_id = config::load_linux_config(Path::new("frontier.toml")).app_id.unwrap_or_else(|| app_name.to_string());
+        let associations = config::load_file_associations(Path::new("frontier.toml"));
+        match build::write_desktop_entry(dist_dir, app_name, &app_id, build_config, find_app_icon().as_deref(), &associations) {
+            Ok(_) => report::step("bundle", "desktop", &format!("🐧 [BUNDLING] {}/{}.desktop", DIST_DIR, app_id)),
+            Err(e) => report::warn(&e),
+        }
+    }
+    format!("{}/{}", DIST_DIR, final_exe_name)
+}
+
+// Icon for the Linux launcher and the macOS bundle: [window] icon, or the frontend's icon.ico/icon.png
+fn find_app_icon() -> Option<PathBuf> {
+    let bundled = fs::read_dir(ASSETS_DIR).ok()?
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .find(|p| p.file_stem().is_some_and(|s| s == "app_icon"));
+    bundled.or_else(|| {
+        ["icon.png", "icon.ico"].iter()
+            .map(|name| Path::new(APP_DIR).join("frontend").join(name))
+            .find(|p| p.is_file())
+    })
 }
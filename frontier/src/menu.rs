@@ -0,0 +1,176 @@
+// Copyright (c) 2026 The Frontier Framework Authors
+// SPDX-License-Identifier: Apache-2.0 WITH LLVM-exception OR MIT
+
+// Menu Bar Module
+//
+// The [[menu]] menus of the window menu bar. Picking an item with an id sends
+// it to the window's page as Frontier.dispatch('menu', id); items with open or
+// trigger run like jump list tasks in that window, so its [security.windows]
+// commands apply. Native items (copy, paste, quit, ...) are handled by the OS.
+// Windows and Linux give each window its own bar, which a page hides with
+// <meta name="frontier-menu" content="none">. On macOS the bar belongs to the
+// app and shows the focused window's menus after the standard App and Edit
+// menus; a page that hides the menu keeps only the standard ones.
+
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use tracing::{debug, warn};
+use wry::application::{
+    accelerator::Accelerator,
+    event::Event,
+    menu::{MenuBar, MenuId, MenuItem, MenuItemAttributes, MenuType},
+    window::WindowId,
+};
+
+use crate::config::{JumpTask, MenuConfig, MenuItemConfig};
+use crate::jumplist;
+
+// What a picked item asks for
+#[derive(Clone)]
+pub struct MenuPick {
+    pub id: Option<String>,   // Sent to the page
+    pub task: Option<String>, // --task value
+}
+
+// A [[menu]] ready to be added to each window's bar
+struct Menu {
+    title: String,
+    entries: Vec<Entry>,
+}
+
+enum Entry {
+    Native(MenuItem),
+    Item { menu_id: MenuId, title: String, accelerator: Option<Accelerator> },
+}
+
+pub struct AppMenu {
+    menus: Vec<Menu>,
+    items: HashMap<MenuId, MenuPick>,
+}
+
+impl AppMenu {
+    // Items that can't be shown are dropped (logged) here, once
+    pub fn new(config: Vec<MenuConfig>) -> Self {
+        let mut items = HashMap::new();
+        let mut menus = Vec::new();
+        for (m, menu) in config.into_iter().enumerate() {
+            let mut entries = Vec::new();
+            for (i, item) in menu.items.iter().enumerate() {
+                if let Some(native) = &item.native {
+                    match native_item(native) {
+                        Some(native) => entries.push(Entry::Native(native)),
+                        None => warn!(target: "config", "⚠️ [CONFIG] Unknown native item '{}' in menu '{}'", native, menu.title),
+                    }
+                    continue;
+                }
+                let task = item_task(item);
+                let Some(title) = item.title.clone().filter(|_| item.id.is_some() || task.is_some()) else {
+                    warn!(target: "config", "⚠️ [CONFIG] Item {} of menu '{}' needs a title and an id, open or trigger", i + 1, menu.title);
+                    continue;
+                };
+                let accelerator = item.accelerator.as_deref().and_then(|keys| match Accelerator::from_str(keys) {
+                    Ok(accelerator) => Some(accelerator),
+                    Err(e) => { warn!(target: "config", "⚠️ [CONFIG] Invalid accelerator '{}' for menu item '{}': {}", keys, title, e); None }
+                });
+                // Ids are numbered, so every window's bar reports the same ones
+                let menu_id = MenuId::new(&format!("frontier-menu-{}-{}", m, i));
+                items.insert(menu_id, MenuPick { id: item.id.clone(), task });
+                entries.push(Entry::Item { menu_id, title, accelerator });
+            }
+            menus.push(Menu { title: menu.title, entries });
+        }
+        AppMenu { menus, items }
+    }
+
+    // The bar of a new window; None when it has none (no [[menu]], or the page hides it)
+    pub fn bar(&self, show: bool) -> Option<MenuBar> {
+        #[cfg(target_os = "macos")]
+        let mut bar = standard_menus();
+        #[cfg(not(target_os = "macos"))]
+        let mut bar = {
+            if !show || self.menus.is_empty() { return None; }
+            MenuBar::new()
+        };
+        for menu in self.menus.iter().filter(|_| show) {
+            let mut submenu = MenuBar::new();
+            for entry in &menu.entries {
+                match entry {
+                    Entry::Native(item) => { submenu.add_native_item(item.clone()); }
+                    Entry::Item { menu_id, title, accelerator } => {
+                        let mut attributes = MenuItemAttributes::new(title).with_id(*menu_id);
+                        if let Some(accelerator) = accelerator {
+                            attributes = attributes.with_accelerators(accelerator);
+                        }
+                        submenu.add_item(attributes);
+                    }
+                }
+            }
+            bar.add_submenu(&menu.title, true, submenu);
+        }
+        Some(bar)
+    }
+
+    // The window an item was picked in (None on macOS when no window is focused) and what it asks for
+    pub fn action<T>(&self, event: &Event<T>) -> Option<(Option<WindowId>, MenuPick)> {
+        let Event::MenuEvent { window_id, menu_id, origin: MenuType::MenuBar, .. } = event else { return None };
+        let pick = self.items.get(menu_id)?;
+        debug!(target: "window", "📋 [MENU] Picked {}", pick.id.as_deref().or(pick.task.as_deref()).unwrap_or_default());
+        Some((*window_id, pick.clone()))
+    }
+}
+
+fn item_task(item: &MenuItemConfig) -> Option<String> {
+    jumplist::task_argument(&JumpTask {
+        title: item.title.clone().unwrap_or_default(),
+        description: None,
+        open: item.open.clone(),
+        trigger: item.trigger.clone(),
+        args: item.args.clone(),
+    })
+}
+
+fn native_item(name: &str) -> Option<MenuItem> {
+    Some(match name {
+        "separator" => MenuItem::Separator,
+        "undo" => MenuItem::Undo,
+        "redo" => MenuItem::Redo,
+        "cut" => MenuItem::Cut,
+        "copy" => MenuItem::Copy,
+        "paste" => MenuItem::Paste,
+        "select_all" => MenuItem::SelectAll,
+        "minimize" => MenuItem::Minimize,
+        "zoom" => MenuItem::Zoom,
+        "fullscreen" => MenuItem::EnterFullScreen,
+        "close" => MenuItem::CloseWindow,
+        "quit" => MenuItem::Quit,
+        _ => return None,
+    })
+}
+
+// App, Edit, View and Window menus, which also give macOS windows their
+// copy/paste and window shortcuts
+#[cfg(target_os = "macos")]
+fn standard_menus() -> MenuBar {
+    let mut app_menu = MenuBar::new();
+    for item in [MenuItem::Hide, MenuItem::HideOthers, MenuItem::ShowAll, MenuItem::Separator, MenuItem::Quit] {
+        app_menu.add_native_item(item);
+    }
+    let mut edit_menu = MenuBar::new();
+    for item in [MenuItem::Undo, MenuItem::Redo, MenuItem::Separator, MenuItem::Cut, MenuItem::Copy, MenuItem::Paste, MenuItem::SelectAll] {
+        edit_menu.add_native_item(item);
+    }
+    let mut view_menu = MenuBar::new();
+    view_menu.add_native_item(MenuItem::EnterFullScreen);
+    let mut window_menu = MenuBar::new();
+    for item in [MenuItem::Minimize, MenuItem::Zoom, MenuItem::Separator, MenuItem::CloseWindow] {
+        window_menu.add_native_item(item);
+    }
+    let mut menu = MenuBar::new();
+    // The first title is replaced by the app name
+    menu.add_submenu("App", true, app_menu);
+    menu.add_submenu("Edit", true, edit_menu);
+    menu.add_submenu("View", true, view_menu);
+    menu.add_submenu("Window", true, window_menu);
+    menu
+}
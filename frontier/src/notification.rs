@@ -0,0 +1,201 @@
+// Copyright (c) 2026 The Frontier Framework Authors
+// SPDX-License-Identifier: Apache-2.0 WITH LLVM-exception OR MIT
+
+// Notifications Module
+//
+// OS notifications shown with Frontier.notify, and their action buttons. Each
+// action runs like a jump list task (see jumplist.rs): it opens a page, runs a
+// backend trigger, or is sent back to the page as a 'notification-action'
+// event. The notification is shown by a helper process (notify-send, a
+// PowerShell toast) that outlives the app: when a button is picked it starts
+// the executable with `--task <value>`, which hands the task to the running
+// instance (instance.rs) or launches the app to run it.
+// Clicking the notification itself (with `click`) is sent back the same way,
+// as `notification:<tag>:` with no action id.
+// Inline replies (a text box in the notification) are shown by Windows only;
+// the typed text is appended to the task value. macOS shows the notification
+// without its buttons, icon or click.
+
+use std::process::{Command, Stdio};
+
+use serde::Deserialize;
+use tracing::{debug, warn};
+
+use crate::config::JumpTask;
+
+// A Frontier.notify() call
+#[derive(Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct Notification {
+    pub tag: String, // Sent back with the page's actions
+    pub title: String,
+    pub body: String,
+    pub icon: Option<String>, // Image file, resolved by the runtime to an absolute path
+    pub click: bool,          // Send clicks on the notification back to the page
+    pub actions: Vec<NotificationAction>,
+}
+
+#[derive(Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct NotificationAction {
+    pub id: String, // Sent back to the page when it has neither open nor trigger
+    pub title: String,
+    pub open: Option<String>,
+    pub trigger: Option<String>,
+    pub args: Option<String>,
+    pub reply: Option<String>, // Placeholder of the reply box; the reply follows args
+}
+
+impl NotificationAction {
+    // As a jump list task, to check its trigger like one
+    pub fn as_task(&self) -> JumpTask {
+        JumpTask { title: self.title.clone(), open: self.open.clone(), trigger: self.trigger.clone(), args: self.args.clone(), ..Default::default() }
+    }
+
+    // --task value; a leading '+' marks the ones the reply is appended to
+    fn task(&self, tag: &str) -> String {
+        let task = match (&self.open, &self.trigger) {
+            (Some(page), _) => return format!("open:{}", page),
+            (None, Some(trigger)) => format!("trigger:{}:{}", trigger, self.args.as_deref().unwrap_or("")),
+            (None, None) => format!("notification:{}:{}:", tag, self.id),
+        };
+        if self.reply.is_some() { format!("+{}", task) } else { task }
+    }
+}
+
+// Actions sent back to pages: (tag, action id) must survive the --task value
+pub fn valid_id(id: &str) -> bool {
+    !id.is_empty() && !id.contains(':')
+}
+
+// Show a notification; buttons start `exe --task <value>`
+pub fn show(app_name: &str, notification: &Notification) {
+    let exe = std::env::current_exe().map(|p| p.to_string_lossy().to_string()).unwrap_or_default();
+    // The helper gets the task of each button as FRONTIER_TASK_<index>
+    let tasks: Vec<(String, String)> = notification.actions.iter().enumerate()
+        .map(|(i, action)| (format!("FRONTIER_TASK_{}", i), action.task(&notification.tag)))
+        .collect();
+    let Some(mut cmd) = helper(app_name, notification) else { return };
+    if notification.click {
+        cmd.env("FRONTIER_TASK_CLICK", format!("notification:{}:", notification.tag));
+    }
+    cmd.env("FRONTIER_EXE", exe).envs(tasks).stdin(Stdio::null()).stdout(Stdio::null()).stderr(Stdio::null());
+    match cmd.spawn() {
+        Ok(_) => debug!(target: "app", "🔔 [NOTIFY] {} ({} action(s))", notification.title, notification.actions.len()),
+        Err(e) => warn!(target: "app", "⚠️ [NOTIFY] Cannot show the notification: {}", e),
+    }
+}
+
+// notify-send (libnotify 0.7.9+ for buttons) prints the key of the picked one;
+// servers report clicks on the notification as the "default" action
+#[cfg(all(unix, not(target_os = "macos")))]
+fn helper(app_name: &str, notification: &Notification) -> Option<Command> {
+    let mut cmd = Command::new("sh");
+    cmd.arg("-c").arg(concat!(
+        "i=$(notify-send \"$@\") || exit; case $i in default) task=$FRONTIER_TASK_CLICK;; ''|*[!0-9]*) exit;; ",
+        "*) eval \"task=\\$FRONTIER_TASK_$i\";; esac; exec \"$FRONTIER_EXE\" --task \"${task#+}\"",
+    ));
+    cmd.arg("sh").arg(format!("--app-name={}", app_name));
+    if let Some(icon) = &notification.icon {
+        cmd.arg(format!("--icon={}", icon));
+    }
+    if !notification.actions.is_empty() || notification.click {
+        cmd.arg("--wait");
+    }
+    if notification.click {
+        cmd.arg("--action=default=Open");
+    }
+    for (i, action) in notification.actions.iter().enumerate() {
+        cmd.arg(format!("--action={}={}", i, action.title));
+    }
+    cmd.arg("--").arg(&notification.title).arg(&notification.body);
+    Some(cmd)
+}
+
+#[cfg(target_os = "macos")]
+fn helper(_app_name: &str, notification: &Notification) -> Option<Command> {
+    if !notification.actions.is_empty() {
+        debug!(target: "app", "🔔 [NOTIFY] Notification actions aren't shown on macOS");
+    }
+    let mut cmd = Command::new("osascript");
+    cmd.args(["-e", "on run argv", "-e", "display notification (item 2 of argv) with title (item 1 of argv)", "-e", "end run"])
+        .arg(&notification.title).arg(&notification.body);
+    Some(cmd)
+}
+
+// PowerShell shows the toast and waits for a button (a day at most); toasts
+// are attributed to PowerShell, since the app has no AppUserModelID of its own
+#[cfg(windows)]
+fn helper(_app_name: &str, notification: &Notification) -> Option<Command> {
+    use std::os::windows::process::CommandExt;
+    const POWERSHELL_APP_ID: &str = r"{1AC14E77-02E7-4E5D-B744-2EB1AE5198B7}\WindowsPowerShell\v1.0\powershell.exe";
+    // Single quotes only: double quotes don't survive the command line
+    const SCRIPT: &str = r"
+$null = [Windows.UI.Notifications.ToastNotificationManager, Windows.UI.Notifications, ContentType = WindowsRuntime]
+$null = [Windows.Data.Xml.Dom.XmlDocument, Windows.Data.Xml.Dom.XmlDocument, ContentType = WindowsRuntime]
+$xml = New-Object Windows.Data.Xml.Dom.XmlDocument
+$xml.LoadXml($env:FRONTIER_TOAST)
+$toast = New-Object Windows.UI.Notifications.ToastNotification $xml
+$null = Register-ObjectEvent -InputObject $toast -EventName Activated -SourceIdentifier activated
+$null = Register-ObjectEvent -InputObject $toast -EventName Dismissed -SourceIdentifier dismissed
+[Windows.UI.Notifications.ToastNotificationManager]::CreateToastNotifier($env:FRONTIER_TOAST_APP).Show($toast)
+while ($e = Wait-Event -Timeout 86400) {
+    Remove-Event -EventIdentifier $e.EventIdentifier
+    if ($e.SourceIdentifier -eq 'dismissed') {
+        if ([string]$e.SourceArgs[1].Reason -eq 'TimedOut') { continue }
+        break
+    }
+    $activation = $e.SourceArgs[1]
+    if ($activation.Arguments -notmatch '^(\d+|click)$') { break }
+    $task = [Environment]::GetEnvironmentVariable('FRONTIER_TASK_' + $activation.Arguments.ToUpper())
+    if (-not $task) { break }
+    if ($task.StartsWith('+')) {
+        $task = $task.Substring(1)
+        if ($activation.UserInput -and $activation.UserInput.ContainsKey('reply')) { $task += [string]$activation.UserInput['reply'] }
+    }
+    $q = [string][char]34
+    $quoted = $q + (($task -replace ('(\\*)' + $q), ('$1$1\' + $q)) -replace '(\\+)$', '$1$1') + $q
+    Start-Process -FilePath $env:FRONTIER_EXE -ArgumentList ('--task ' + $quoted)
+    break
+}
+";
+    let mut cmd = Command::new("powershell");
+    cmd.args(["-NoProfile", "-NonInteractive", "-WindowStyle", "Hidden", "-Command", SCRIPT])
+        .env("FRONTIER_TOAST", toast_xml(notification))
+        .env("FRONTIER_TOAST_APP", POWERSHELL_APP_ID)
+        .creation_flags(crate::system::CREATE_NO_WINDOW);
+    Some(cmd)
+}
+
+#[cfg(windows)]
+fn toast_xml(notification: &Notification) -> String {
+    let escape = |text: &str| text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;");
+    let launch = if notification.click { " launch=\"click\"" } else { "" };
+    let image = notification.icon.as_ref()
+        .map(|icon| format!("<image placement=\"appLogoOverride\" src=\"{}\"/>", escape(&format!("file:///{}", icon.replace('\\', "/")))))
+        .unwrap_or_default();
+    let mut xml = format!(
+        "<toast{}><visual><binding template=\"ToastGeneric\"><text>{}</text><text>{}</text>{}</binding></visual>",
+        launch, escape(&notification.title), escape(&notification.body), image
+    );
+    if !notification.actions.is_empty() {
+        xml.push_str("<actions>");
+        // One reply box, for the actions that take a reply
+        if let Some(placeholder) = notification.actions.iter().find_map(|a| a.reply.as_deref()) {
+            xml.push_str(&format!("<input id=\"reply\" type=\"text\" placeHolderContent=\"{}\"/>", escape(placeholder)));
+        }
+        for (i, action) in notification.actions.iter().enumerate() {
+            let input = if action.reply.is_some() { " hint-inputId=\"reply\"" } else { "" };
+            xml.push_str(&format!("<action content=\"{}\" arguments=\"{}\" activationType=\"foreground\"{}/>", escape(&action.title), i, input));
+        }
+        xml.push_str("</actions>");
+    }
+    xml.push_str("</toast>");
+    xml
+}
+
+#[cfg(not(any(unix, windows)))]
+fn helper(_app_name: &str, _notification: &Notification) -> Option<Command> {
+    warn!(target: "app", "⚠️ [NOTIFY] Notifications aren't supported on this platform");
+    None
+}
@@ -0,0 +1,47 @@
+// Copyright (c) 2026 The Frontier Framework Authors
+// SPDX-License-Identifier: Apache-2.0 WITH LLVM-exception OR MIT
+
+// Binary Backend Replies
+//
+// Triggers listed in [backend] msgpack print MessagePack instead of text. Their
+// output is not squeezed through evaluate_script as a string: it is parked
+// here and the page fetches it as an ArrayBuffer from <scheme>://app/__reply/<id>,
+// decodes it with the bridge's MessagePack decoder and receives a 'data' event.
+// Each reply can be fetched once, and only by the window that ran the trigger.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+pub const REPLY_PATH: &str = "__reply/";
+
+// Replies not fetched by then are dropped (the page was closed or reloaded)
+const MAX_PENDING: usize = 32;
+
+static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+
+lazy_static::lazy_static! {
+    static ref PENDING: Mutex<HashMap<u64, (String, Vec<u8>)>> = Mutex::new(HashMap::new());
+}
+
+// Park a reply for `window` (a window key) and return its id
+pub fn store(window: &str, bytes: Vec<u8>) -> u64 {
+    let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+    if let Ok(mut pending) = PENDING.lock() {
+        if pending.len() >= MAX_PENDING {
+            if let Some(oldest) = pending.keys().min().copied() { pending.remove(&oldest); }
+        }
+        pending.insert(id, (window.to_string(), bytes));
+    }
+    id
+}
+
+// Hand a reply to the window it belongs to (once); `windows` are the windows
+// the asking handler serves
+pub fn take(windows: &[String], id: &str) -> Option<Vec<u8>> {
+    let id: u64 = id.parse().ok()?;
+    let mut pending = PENDING.lock().ok()?;
+    let (owner, _) = pending.get(&id)?;
+    if !windows.contains(owner) { return None; }
+    pending.remove(&id).map(|(_, bytes)| bytes)
+}
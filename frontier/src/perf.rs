@@ -0,0 +1,94 @@
+// Copyright (c) 2026 The Frontier Framework Authors
+// SPDX-License-Identifier: Apache-2.0 WITH LLVM-exception OR MIT
+
+// Performance Timing Module
+//
+// This module records how long the expensive steps take: asset extraction,
+// window creation, first paint of each window and every backend invocation.
+// Timings are listed by the dev state endpoint (__state) and, with
+// `--trace-file <path>`, written on exit as a Chrome trace (open it in
+// chrome://tracing or https://ui.perfetto.dev).
+
+use serde_json::{json, Value};
+use std::collections::VecDeque;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Mutex, OnceLock};
+use std::time::Instant;
+use tracing::{debug, error, info};
+
+const MAX_TIMINGS: usize = 1000;
+
+static PROCESS_START: OnceLock<Instant> = OnceLock::new();
+static TRACE_FILE: Mutex<Option<PathBuf>> = Mutex::new(None);
+static TIMINGS: Mutex<VecDeque<Value>> = Mutex::new(VecDeque::new());
+static NEXT_THREAD: AtomicU64 = AtomicU64::new(1);
+static STARTUP_DONE: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+    static THREAD_ID: u64 = NEXT_THREAD.fetch_add(1, Ordering::Relaxed);
+}
+
+// Start the clock; call first thing in main
+pub fn init(trace_file: Option<PathBuf>) {
+    let _ = PROCESS_START.set(Instant::now());
+    if let Ok(mut file) = TRACE_FILE.lock() {
+        *file = trace_file;
+    }
+}
+
+pub fn process_start() -> Instant {
+    *PROCESS_START.get_or_init(Instant::now)
+}
+
+// Record a step that began at `started` and ends now
+pub fn record(name: &str, detail: &str, started: Instant) {
+    let start_us = started.saturating_duration_since(process_start()).as_micros() as u64;
+    let dur_us = started.elapsed().as_micros() as u64;
+    debug!(target: "perf", "⏱️ [PERF] {} {} took {} ms", name, detail, dur_us / 1000);
+
+    let thread = THREAD_ID.with(|id| *id);
+    if let Ok(mut timings) = TIMINGS.lock() {
+        if timings.len() == MAX_TIMINGS { timings.pop_front(); }
+        timings.push_back(json!({ "name": name, "detail": detail, "start_us": start_us, "dur_us": dur_us, "thread": thread }));
+    }
+}
+
+// A window painted for the first time; the first one also ends "startup"
+pub fn first_paint(window: &str, window_started: Instant) {
+    record("first-paint", window, window_started);
+    if !STARTUP_DONE.swap(true, Ordering::Relaxed) {
+        let startup = process_start();
+        record("startup", window, startup);
+        info!(target: "perf", "⏱️ [PERF] First window painted {} ms after launch", startup.elapsed().as_millis());
+    }
+}
+
+// Recorded timings (oldest first)
+pub fn timings() -> Vec<Value> {
+    TIMINGS.lock().map(|t| t.iter().cloned().collect()).unwrap_or_default()
+}
+
+// Write the timings as a Chrome trace if `--trace-file` was given
+pub fn write_trace() {
+    let Some(path) = TRACE_FILE.lock().ok().and_then(|f| f.clone()) else { return };
+
+    let events: Vec<Value> = timings().iter().map(|t| json!({
+        "name": t["name"],
+        "cat": "frontier",
+        "ph": "X",
+        "ts": t["start_us"],
+        "dur": t["dur_us"],
+        "pid": 1,
+        "tid": t["thread"],
+        "args": { "detail": t["detail"] },
+    })).collect();
+    let trace = json!({ "traceEvents": events, "displayTimeUnit": "ms" });
+
+    if let Some(parent) = path.parent() { let _ = fs::create_dir_all(parent); }
+    match fs::write(&path, serde_json::to_vec_pretty(&trace).unwrap_or_default()) {
+        Ok(_) => info!(target: "perf", "⏱️ [PERF] Trace written to {}", path.display()),
+        Err(e) => error!(target: "perf", "❌ [PERF] Cannot write trace {}: {}", path.display(), e),
+    }
+}
@@ -0,0 +1,499 @@
+// Copyright (c) 2026 The Frontier Framework Authors
+// SPDX-License-Identifier: Apache-2.0 WITH LLVM-exception OR MIT
+
+// Platform Module
+//
+// Where the runtime keeps its files on each OS, the WebKitGTK workarounds
+// applied on Linux, the input method, the OS accessibility settings, file
+// associations on Windows and the Dock (reopen, badge, menu) and Finder
+// integration on macOS. Base folders come from the dirs crate; each app gets
+// its own data, config and cache folders inside them, named the way the
+// `directories` crate names project folders:
+//
+// Windows: %LOCALAPPDATA%\<organization>\<name>\{data,cache}, %APPDATA%\<organization>\<name>\config
+// macOS:   ~/Library/{Application Support,Caches}/<qualifier>.<organization>.<name>
+// Linux:   $XDG_{DATA,CONFIG,CACHE}_HOME/<name in lowercase, without spaces>
+
+use std::env;
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+use tracing::{debug, warn};
+
+use crate::config::{AppConfig, FileAssociation, KeyboardConfig, LinuxConfig, MacosConfig};
+
+// WebKitGTK keeps the first <scheme>:// handler registered on a WebContext and
+// ignores the ones of later windows, so one handler answers every window of a
+// context. WebView2 (and WKWebView) give each window its own handler.
+pub const SHARED_SCHEME_HANDLER: bool = cfg!(not(any(target_os = "windows", target_os = "macos")));
+
+// Per-app folders ([app] qualifier, organization and name)
+#[derive(Clone, Debug)]
+pub struct ProjectDirs {
+    pub data: PathBuf,   // Window state, cookies/localStorage, logs, crash reports
+    pub config: PathBuf, // Settings the app's backends want to keep apart from data
+    pub cache: PathBuf,  // Disposable files (the extracted runtime folder of release builds)
+}
+
+impl ProjectDirs {
+    pub fn new(app: &AppConfig, macos: &MacosConfig) -> Self {
+        let name = app.name.clone().unwrap_or_else(|| "App".into());
+        if cfg!(target_os = "windows") {
+            let mut local = data_home();
+            let mut roaming = dirs_next::config_dir().unwrap_or_else(|| local.clone());
+            if let Some(org) = app.organization.as_deref().filter(|o| !o.is_empty()) {
+                local.push(org);
+                roaming.push(org);
+            }
+            return ProjectDirs { data: local.join(&name).join("data"), config: roaming.join(&name).join("config"), cache: local.join(&name).join("cache") };
+        }
+        if cfg!(target_os = "macos") {
+            let id = crate::config::bundle_identifier(app, macos);
+            return ProjectDirs { data: data_home().join(&id), config: data_home().join(&id), cache: cache_home().join(&id) };
+        }
+        let folder = name.trim().to_lowercase().replace(' ', "");
+        ProjectDirs {
+            data: data_home().join(&folder),
+            config: dirs_next::config_dir().unwrap_or_else(|| home_dir().join(".config")).join(&folder),
+            cache: cache_home().join(&folder),
+        }
+    }
+}
+
+// Per-user data folder (%LOCALAPPDATA%, ~/Library/Application Support, $XDG_DATA_HOME)
+pub fn data_home() -> PathBuf {
+    dirs_next::data_local_dir().unwrap_or_else(|| home_dir().join(".local/share"))
+}
+
+// Per-user cache folder (%LOCALAPPDATA%, ~/Library/Caches, $XDG_CACHE_HOME); not
+// the temp folder, which on Linux is shared by every user of the machine
+pub fn cache_home() -> PathBuf {
+    dirs_next::cache_dir().unwrap_or_else(env::temp_dir)
+}
+
+pub fn home_dir() -> PathBuf {
+    dirs_next::home_dir().unwrap_or_else(|| PathBuf::from("."))
+}
+
+// Environment tweaks that must be in place before the first webview is created
+//
+// With the proprietary NVIDIA driver, WebKitGTK's DMA-BUF renderer shows blank
+// or white windows; it is turned off unless the user already decided
+// (WEBKIT_DISABLE_DMABUF_RENDERER=0 keeps it on).
+pub fn apply_webkit_quirks() {
+    if !cfg!(target_os = "linux") { return; }
+    if env::var_os("WEBKIT_DISABLE_DMABUF_RENDERER").is_none() && Path::new("/proc/driver/nvidia/version").exists() {
+        env::set_var("WEBKIT_DISABLE_DMABUF_RENDERER", "1");
+        debug!(target: "window", "🐧 [WINDOW] NVIDIA driver detected, DMA-BUF renderer disabled");
+    }
+}
+
+// [linux] display backend, decorations and app id; like the WebKitGTK quirks
+// they only take effect before GTK starts
+pub fn apply_linux_options(linux: &LinuxConfig) {
+    if !cfg!(target_os = "linux") { return; }
+
+    // Wayland ignores window positions and window icons (the icon comes from the
+    // .desktop file matching the app id); X11 through XWayland honors both
+    match linux.backend.as_deref() {
+        Some(backend @ ("x11" | "wayland")) if env::var_os("GDK_BACKEND").is_none() => env::set_var("GDK_BACKEND", backend),
+        None | Some("auto") | Some("x11") | Some("wayland") => {}
+        Some(other) => warn!(target: "config", "⚠️ [CONFIG] Unknown [linux] backend '{}' (use auto, x11 or wayland)", other),
+    }
+    match linux.decorations.as_deref() {
+        Some("client") => env::set_var("GTK_CSD", "1"),
+        Some("server") => env::set_var("GTK_CSD", "0"),
+        None | Some("auto") => {}
+        Some(other) => warn!(target: "config", "⚠️ [CONFIG] Unknown [linux] decorations '{}' (use auto, client or server)", other),
+    }
+    #[cfg(target_os = "linux")]
+    if let Some(app_id) = &linux.app_id {
+        // GTK uses the program name as the Wayland app id and the X11 WM_CLASS
+        glib::set_prgname(Some(app_id));
+    }
+}
+
+// OS accessibility settings, as pages see them (Frontier.accessibility)
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct AccessibilitySettings {
+    pub high_contrast: bool,
+    pub reduced_motion: bool,
+    pub screen_reader: bool,
+    pub text_scale: f64, // OS text size, 1.0 = 100%
+}
+
+impl Default for AccessibilitySettings {
+    fn default() -> Self {
+        AccessibilitySettings { high_contrast: false, reduced_motion: false, screen_reader: false, text_scale: 1.0 }
+    }
+}
+
+// Windows: the high contrast theme, "Show animations", a running screen reader
+// and Settings > Accessibility > Text size
+#[cfg(windows)]
+pub fn accessibility_settings() -> AccessibilitySettings {
+    use winapi::shared::minwindef::BOOL;
+    use winapi::um::winuser::{
+        SystemParametersInfoW, HCF_HIGHCONTRASTON, HIGHCONTRASTW, SPI_GETCLIENTAREAANIMATION, SPI_GETHIGHCONTRAST, SPI_GETSCREENREADER,
+    };
+
+    let mut contrast: HIGHCONTRASTW = unsafe { std::mem::zeroed() };
+    contrast.cbSize = std::mem::size_of::<HIGHCONTRASTW>() as u32;
+    let (mut animations, mut screen_reader): (BOOL, BOOL) = (1, 0);
+    unsafe {
+        SystemParametersInfoW(SPI_GETHIGHCONTRAST, contrast.cbSize, &mut contrast as *mut _ as *mut _, 0);
+        SystemParametersInfoW(SPI_GETCLIENTAREAANIMATION, 0, &mut animations as *mut _ as *mut _, 0);
+        SystemParametersInfoW(SPI_GETSCREENREADER, 0, &mut screen_reader as *mut _ as *mut _, 0);
+    }
+    // 100-225, missing until the slider is first moved
+    let text_size = registry::read_dword(r"Software\Microsoft\Accessibility", "TextScaleFactor").unwrap_or(100);
+    AccessibilitySettings {
+        high_contrast: contrast.dwFlags & HCF_HIGHCONTRASTON != 0,
+        reduced_motion: animations == 0,
+        screen_reader: screen_reader != 0,
+        text_scale: text_size as f64 / 100.0,
+    }
+}
+
+// macOS: "Increase contrast", "Reduce motion" and VoiceOver; there is no
+// system-wide text size for web content
+#[cfg(target_os = "macos")]
+pub fn accessibility_settings() -> AccessibilitySettings {
+    use objc::runtime::{Class, Object, BOOL, YES};
+    use objc::{msg_send, sel, sel_impl};
+
+    let Some(class) = Class::get("NSWorkspace") else { return AccessibilitySettings::default() };
+    unsafe {
+        let workspace: *mut Object = msg_send![class, sharedWorkspace];
+        let high_contrast: BOOL = msg_send![workspace, accessibilityDisplayShouldIncreaseContrast];
+        let reduced_motion: BOOL = msg_send![workspace, accessibilityDisplayShouldReduceMotion];
+        let voice_over: BOOL = msg_send![workspace, isVoiceOverEnabled];
+        AccessibilitySettings {
+            high_contrast: high_contrast == YES,
+            reduced_motion: reduced_motion == YES,
+            screen_reader: voice_over == YES,
+            text_scale: 1.0,
+        }
+    }
+}
+
+// Linux: the GNOME settings most desktops honor (read with gsettings, so
+// nothing is reported where it isn't installed); GTK_THEME=HighContrast also counts
+#[cfg(not(any(windows, target_os = "macos")))]
+pub fn accessibility_settings() -> AccessibilitySettings {
+    fn gsetting(schema: &str, key: &str) -> Option<String> {
+        let output = std::process::Command::new("gsettings").args(["get", schema, key]).output().ok()?;
+        output.status.success().then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    }
+    let theme = env::var("GTK_THEME").unwrap_or_default();
+    AccessibilitySettings {
+        high_contrast: theme.starts_with("HighContrast") || gsetting("org.gnome.desktop.a11y.interface", "high-contrast").as_deref() == Some("true"),
+        reduced_motion: gsetting("org.gnome.desktop.interface", "enable-animations").as_deref() == Some("false"),
+        screen_reader: gsetting("org.gnome.desktop.a11y.applications", "screen-reader-enabled").as_deref() == Some("true"),
+        text_scale: gsetting("org.gnome.desktop.interface", "text-scaling-factor").and_then(|v| v.parse().ok()).unwrap_or(1.0),
+    }
+}
+
+// [keyboard] options checked once at startup; the input method, like the
+// [linux] options, only takes effect before GTK starts
+pub fn apply_keyboard_options(keyboard: &KeyboardConfig) {
+    match keyboard.shortcuts.as_deref() {
+        None | Some("physical") | Some("character") => {}
+        Some(other) => warn!(target: "config", "⚠️ [CONFIG] Unknown [keyboard] shortcuts '{}' (use physical or character)", other),
+    }
+    match &keyboard.im_module {
+        Some(module) if cfg!(target_os = "linux") && env::var_os("GTK_IM_MODULE").is_none() => {
+            env::set_var("GTK_IM_MODULE", module);
+            debug!(target: "window", "⌨️ [WINDOW] Input method: {}", module);
+        }
+        _ => {}
+    }
+}
+
+// Extracted backends lose their permissions; native binaries and #! scripts
+// need the executable bit back on Unix to be run directly
+pub fn mark_executable(path: &Path, data: &[u8]) {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        // ELF, Mach-O (64-bit and universal) and scripts
+        let magic: [&[u8]; 4] = [b"\x7fELF", b"\xcf\xfa\xed\xfe", b"\xca\xfe\xba\xbe", b"#!"];
+        if magic.iter().any(|m| data.starts_with(m)) {
+            let _ = std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o755));
+        }
+    }
+    #[cfg(not(unix))]
+    let _ = (path, data);
+}
+
+// Register [[file_associations]] for the current user on Windows
+// (HKCU\Software\Classes, no admin rights needed). Each extension lists the app
+// in its "Open with" menu, and opens with it unless another app already is the
+// default. Keys are only rewritten when the executable moved, so Explorer isn't
+// asked to refresh its icons on every start.
+pub fn register_file_associations(app_name: &str, associations: &[FileAssociation]) {
+    #[cfg(windows)]
+    {
+        let Ok(exe) = env::current_exe() else { return };
+        let command = format!("\"{}\" \"%1\"", exe.display());
+        let mut app_key: String = app_name.chars().filter(|c| c.is_ascii_alphanumeric()).collect();
+        if app_key.is_empty() { app_key = "FrontierApp".into(); }
+
+        let mut changed = false;
+        for assoc in associations {
+            for ext in &assoc.extensions {
+                let ext = ext.trim_start_matches('.').to_lowercase();
+                let prog_id = format!("{}.{}", app_key, ext);
+                let prog_key = format!(r"Software\Classes\{}", prog_id);
+                let command_key = format!(r"{}\shell\open\command", prog_key);
+                if registry::read(&command_key, "").as_deref() == Some(command.as_str()) { continue; }
+
+                let name = assoc.name.clone().unwrap_or_else(|| format!("{} File", ext.to_uppercase()));
+                registry::write(&prog_key, "", &name);
+                registry::write(&format!(r"{}\DefaultIcon", prog_key), "", &format!("{},0", exe.display()));
+                registry::write(&command_key, "", &command);
+                let ext_key = format!(r"Software\Classes\.{}", ext);
+                registry::write(&format!(r"{}\OpenWithProgids", ext_key), &prog_id, "");
+                if registry::read(&ext_key, "").unwrap_or_default().is_empty() {
+                    registry::write(&ext_key, "", &prog_id);
+                }
+                changed = true;
+            }
+        }
+        if changed {
+            registry::notify_associations_changed();
+            debug!(target: "app", "📂 [APP] File associations registered for {}", exe.display());
+        }
+    }
+    #[cfg(not(windows))]
+    let _ = (app_name, associations);
+}
+
+// Per-user registry values (strings, and DWORDs for reading)
+#[cfg(windows)]
+mod registry {
+    use std::ffi::{c_void, OsStr};
+    use std::os::windows::ffi::OsStrExt;
+    use std::ptr;
+    use winapi::um::winnt::REG_SZ;
+    use winapi::um::winreg::{RegGetValueW, RegSetKeyValueW, HKEY_CURRENT_USER, RRF_RT_REG_DWORD, RRF_RT_REG_SZ};
+
+    #[link(name = "shell32")]
+    extern "system" {
+        fn SHChangeNotify(event_id: i32, flags: u32, item1: *const c_void, item2: *const c_void);
+    }
+    const SHCNE_ASSOCCHANGED: i32 = 0x0800_0000;
+
+    fn wide(s: &str) -> Vec<u16> {
+        OsStr::new(s).encode_wide().chain(Some(0)).collect()
+    }
+
+    // `value` "" is the key's default value
+    pub fn read(key: &str, value: &str) -> Option<String> {
+        let (key, value) = (wide(key), wide(value));
+        let mut buf = vec![0u16; 2048];
+        let mut size = (buf.len() * 2) as u32;
+        let status = unsafe {
+            RegGetValueW(HKEY_CURRENT_USER, key.as_ptr(), value.as_ptr(), RRF_RT_REG_SZ, ptr::null_mut(), buf.as_mut_ptr() as *mut _, &mut size)
+        };
+        if status != 0 { return None; }
+        let len = (size as usize / 2).saturating_sub(1);
+        Some(String::from_utf16_lossy(&buf[..len]))
+    }
+
+    pub fn read_dword(key: &str, value: &str) -> Option<u32> {
+        let (key, value) = (wide(key), wide(value));
+        let mut data = 0u32;
+        let mut size = 4u32;
+        let status = unsafe {
+            RegGetValueW(HKEY_CURRENT_USER, key.as_ptr(), value.as_ptr(), RRF_RT_REG_DWORD, ptr::null_mut(), &mut data as *mut u32 as *mut _, &mut size)
+        };
+        (status == 0).then_some(data)
+    }
+
+    // Missing keys are created
+    pub fn write(key: &str, value: &str, data: &str) {
+        let (key, value, data) = (wide(key), wide(value), wide(data));
+        unsafe {
+            RegSetKeyValueW(HKEY_CURRENT_USER, key.as_ptr(), value.as_ptr(), REG_SZ, data.as_ptr() as *const _, (data.len() * 2) as u32);
+        }
+    }
+
+    // Explorer re-reads associations and icons
+    pub fn notify_associations_changed() {
+        unsafe { SHChangeNotify(SHCNE_ASSOCCHANGED, 0, ptr::null(), ptr::null()) }
+    }
+}
+
+// Call `callback` with the paths Finder opens with the app (double-clicked
+// documents, drops on the Dock icon), whether the app was running or not.
+// Like the Dock reopen below, application:openURLs: is added to tao's delegate.
+#[cfg(target_os = "macos")]
+pub fn on_open_files(callback: impl Fn(Vec<String>) + Send + 'static) {
+    use objc::runtime::{class_addMethod, Class, Imp, Object, Sel, BOOL, NO};
+    use objc::{msg_send, sel, sel_impl};
+    use std::ffi::CStr;
+    use std::os::raw::c_char;
+    use std::sync::Mutex;
+
+    static OPEN: Mutex<Option<Box<dyn Fn(Vec<String>) + Send>>> = Mutex::new(None);
+
+    extern "C" fn open_urls(_: &Object, _: Sel, _: *mut Object, urls: *mut Object) {
+        let mut files = Vec::new();
+        unsafe {
+            let count: usize = msg_send![urls, count];
+            for i in 0..count {
+                let url: *mut Object = msg_send![urls, objectAtIndex: i];
+                let is_file: BOOL = msg_send![url, isFileURL];
+                if is_file == NO { continue; }
+                let path: *mut Object = msg_send![url, path];
+                let utf8: *const c_char = msg_send![path, UTF8String];
+                if !utf8.is_null() { files.push(CStr::from_ptr(utf8).to_string_lossy().into_owned()); }
+            }
+        }
+        if files.is_empty() { return; }
+        if let Ok(open) = OPEN.lock() {
+            if let Some(callback) = open.as_ref() { callback(files); }
+        }
+    }
+
+    let Some(class) = Class::get("TaoAppDelegate") else { return };
+    if let Ok(mut open) = OPEN.lock() { *open = Some(Box::new(callback)); }
+    unsafe {
+        let imp: Imp = std::mem::transmute(open_urls as extern "C" fn(&Object, Sel, *mut Object, *mut Object));
+        class_addMethod(class as *const Class as *mut Class, sel!(application:openURLs:), imp, b"v@:@@\0".as_ptr() as *const _);
+    }
+}
+
+// Call `callback` when the Dock icon is clicked while no window is visible
+//
+// tao doesn't forward applicationShouldHandleReopen:hasVisibleWindows:, so the
+// method is added to its app delegate class (which exists once the event loop
+// has been created).
+#[cfg(target_os = "macos")]
+pub fn on_dock_reopen(callback: impl Fn() + Send + 'static) {
+    use objc::runtime::{class_addMethod, Class, Imp, Object, Sel, BOOL, NO, YES};
+    use objc::{sel, sel_impl, Encode};
+    use std::sync::Mutex;
+
+    static REOPEN: Mutex<Option<Box<dyn Fn() + Send>>> = Mutex::new(None);
+
+    extern "C" fn should_handle_reopen(_: &Object, _: Sel, _: *mut Object, has_visible_windows: BOOL) -> BOOL {
+        if has_visible_windows == NO {
+            if let Ok(reopen) = REOPEN.lock() {
+                if let Some(callback) = reopen.as_ref() { callback(); }
+            }
+        }
+        YES
+    }
+
+    let Some(class) = Class::get("TaoAppDelegate") else { return };
+    if let Ok(mut reopen) = REOPEN.lock() { *reopen = Some(Box::new(callback)); }
+    let bool_type = BOOL::encode();
+    let types = format!("{}@:@{}\0", bool_type.as_str(), bool_type.as_str());
+    unsafe {
+        let imp: Imp = std::mem::transmute(should_handle_reopen as extern "C" fn(&Object, Sel, *mut Object, BOOL) -> BOOL);
+        class_addMethod(class as *const Class as *mut Class, sel!(applicationShouldHandleReopen:hasVisibleWindows:), imp, types.as_ptr() as *const _);
+    }
+}
+
+// NSString from UTF-8; the caller releases it
+#[cfg(target_os = "macos")]
+unsafe fn ns_string(text: &str) -> *mut objc::runtime::Object {
+    use objc::runtime::Object;
+    use objc::{class, msg_send, sel, sel_impl};
+    const NS_UTF8_STRING_ENCODING: usize = 4;
+    let string: *mut Object = msg_send![class!(NSString), alloc];
+    msg_send![string, initWithBytes: text.as_ptr() length: text.len() encoding: NS_UTF8_STRING_ENCODING]
+}
+
+// Text on the Dock icon, e.g. an unread count ("" removes it)
+#[cfg(target_os = "macos")]
+pub fn set_dock_badge(text: &str) {
+    use objc::runtime::Object;
+    use objc::{class, msg_send, sel, sel_impl};
+    unsafe {
+        let app: *mut Object = msg_send![class!(NSApplication), sharedApplication];
+        let tile: *mut Object = msg_send![app, dockTile];
+        if text.is_empty() {
+            let _: () = msg_send![tile, setBadgeLabel: std::ptr::null_mut::<Object>()];
+        } else {
+            let label = ns_string(text);
+            let _: () = msg_send![tile, setBadgeLabel: label];
+            let _: () = msg_send![label, release];
+        }
+    }
+}
+
+#[cfg(not(target_os = "macos"))]
+pub fn set_dock_badge(text: &str) {
+    debug!(target: "app", "🔔 [APP] Dock badges are only shown on macOS ('{}')", text);
+}
+
+#[cfg(target_os = "macos")]
+static DOCK_MENU: std::sync::Mutex<Vec<(String, String)>> = std::sync::Mutex::new(Vec::new());
+
+// Items above the Dock icon's standard menu, as (title, --task value)
+pub fn set_dock_menu(items: Vec<(String, String)>) {
+    #[cfg(target_os = "macos")]
+    {
+        if let Ok(mut menu) = DOCK_MENU.lock() { *menu = items; }
+    }
+    #[cfg(not(target_os = "macos"))]
+    debug!(target: "app", "📌 [APP] Dock menus are only shown on macOS ({} item(s))", items.len());
+}
+
+// Call `callback` with the task of the Dock menu item the user picks
+//
+// The menu comes from applicationDockMenu:, added to tao's app delegate like
+// the Dock reopen above; it is rebuilt from set_dock_menu's items each time
+// the menu opens, and each item calls frontierDockMenuItem: on the delegate.
+#[cfg(target_os = "macos")]
+pub fn on_dock_menu(callback: impl Fn(String) + Send + 'static) {
+    use objc::runtime::{class_addMethod, Class, Imp, Object, Sel};
+    use objc::{class, msg_send, sel, sel_impl};
+    use std::sync::Mutex;
+
+    static PICK: Mutex<Option<Box<dyn Fn(String) + Send>>> = Mutex::new(None);
+
+    extern "C" fn dock_menu(this: &Object, _: Sel, _: *mut Object) -> *mut Object {
+        let items = DOCK_MENU.lock().map(|menu| menu.clone()).unwrap_or_default();
+        if items.is_empty() { return std::ptr::null_mut(); }
+        unsafe {
+            let menu: *mut Object = msg_send![class!(NSMenu), new];
+            let no_key = ns_string("");
+            for (tag, (title, _)) in items.iter().enumerate() {
+                let title = ns_string(title);
+                let item: *mut Object = msg_send![class!(NSMenuItem), alloc];
+                let item: *mut Object = msg_send![item, initWithTitle: title action: sel!(frontierDockMenuItem:) keyEquivalent: no_key];
+                let _: () = msg_send![item, setTag: tag as isize];
+                let _: () = msg_send![item, setTarget: this];
+                let _: () = msg_send![menu, addItem: item];
+                let _: () = msg_send![item, release];
+                let _: () = msg_send![title, release];
+            }
+            let _: () = msg_send![no_key, release];
+            msg_send![menu, autorelease]
+        }
+    }
+
+    extern "C" fn pick_item(_: &Object, _: Sel, item: *mut Object) {
+        let tag: isize = unsafe { msg_send![item, tag] };
+        let task = DOCK_MENU.lock().ok().and_then(|menu| menu.get(tag as usize).map(|(_, task)| task.clone()));
+        let Some(task) = task else { return };
+        if let Ok(pick) = PICK.lock() {
+            if let Some(callback) = pick.as_ref() { callback(task); }
+        }
+    }
+
+    let Some(class) = Class::get("TaoAppDelegate") else { return };
+    if let Ok(mut pick) = PICK.lock() { *pick = Some(Box::new(callback)); }
+    unsafe {
+        let class = class as *const Class as *mut Class;
+        let imp: Imp = std::mem::transmute(dock_menu as extern "C" fn(&Object, Sel, *mut Object) -> *mut Object);
+        class_addMethod(class, sel!(applicationDockMenu:), imp, b"@@:@\0".as_ptr() as *const _);
+        let imp: Imp = std::mem::transmute(pick_item as extern "C" fn(&Object, Sel, *mut Object));
+        class_addMethod(class, sel!(frontierDockMenuItem:), imp, b"v@:@\0".as_ptr() as *const _);
+    }
+}
@@ -0,0 +1,305 @@
+// Copyright (c) 2026 The Frontier Framework Authors
+// SPDX-License-Identifier: Apache-2.0 WITH LLVM-exception OR MIT
+
+// Interpreter Worker Pool
+//
+// Starting Python or Node costs 100-300 ms, paid on every button click. Modules
+// that set `worker = "python"` or `worker = "node"` in their manifest can run
+// in long-lived interpreter processes instead: with [backend] pool = N, N
+// workers per interpreter are started ahead of time and each job is sent to an
+// idle one over stdin.
+//
+// Protocol (one JSON object per line):
+//   core -> worker: {"script": path, "args": [...], "cwd": dir, "env": {...}}
+//   worker -> core: {"out": text} / {"err": text} while the script runs,
+//                   then {"exit": code}
+//
+// A worker that is cancelled, times out or dies is killed and replaced. In dev,
+// editing a backend replaces every worker, since they keep the modules scripts
+// import (sys.modules, require.cache) between jobs.
+
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader, Lines};
+use tokio::process::{Child, ChildStdin, ChildStdout, Command};
+use tracing::{debug, warn};
+
+use crate::system::{ChildProcesses, OutputBuffer, TrackedChild, MAX_STDERR};
+
+// Output of one pooled job, shaped like a finished process
+pub struct WorkerOutput {
+    pub stdout: OutputBuffer,
+    pub stderr: OutputBuffer,
+    pub code: i32,
+}
+
+struct Worker {
+    generation: usize, // Pool generation it was started in (see recycle)
+    _child: Child,
+    _tracked: TrackedChild,
+    stdin: ChildStdin,
+    frames: Lines<BufReader<ChildStdout>>,
+}
+
+pub struct WorkerPool {
+    size: AtomicUsize,
+    generation: AtomicUsize,
+    dir: PathBuf,
+    idle: Mutex<HashMap<String, Vec<Worker>>>,
+    children: Arc<ChildProcesses>,
+}
+
+impl WorkerPool {
+    // `dir` receives the bootstrap scripts; `size` is [backend] pool (0 = off)
+    pub fn new(dir: PathBuf, size: usize, children: Arc<ChildProcesses>) -> Self {
+        WorkerPool { size: AtomicUsize::new(size), generation: AtomicUsize::new(0), dir, idle: Mutex::new(HashMap::new()), children }
+    }
+
+    pub fn enabled(&self) -> bool {
+        self.size.load(Ordering::Relaxed) > 0
+    }
+
+    // Idle workers are killed (they hold nothing); busy ones stop with their job
+    pub fn shutdown(&self) {
+        self.set_size(0);
+    }
+
+    // Replace every worker: idle ones are killed now, busy ones when their job ends
+    pub fn recycle(&self) {
+        self.generation.fetch_add(1, Ordering::Relaxed);
+        if let Ok(mut idle) = self.idle.lock() {
+            if idle.values().any(|workers| !workers.is_empty()) { debug!(target: "backend", "🔥 [POOL] Restarting workers"); }
+            idle.clear();
+        }
+    }
+
+    pub fn set_size(&self, size: usize) {
+        self.size.store(size, Ordering::Relaxed);
+        if let Ok(mut idle) = self.idle.lock() {
+            for workers in idle.values_mut() { workers.truncate(size); }
+        }
+    }
+
+    // Start workers until `size` are idle for this interpreter
+    pub async fn warm(&self, kind: &str, interpreter: &str) {
+        let key = pool_key(kind, interpreter);
+        loop {
+            let idle = self.idle.lock().map(|i| i.get(&key).map_or(0, Vec::len)).unwrap_or(0);
+            if idle >= self.size.load(Ordering::Relaxed) { break; }
+            match self.spawn(kind, interpreter).await {
+                Ok(worker) => self.release(&key, worker),
+                Err(e) => {
+                    warn!(target: "backend", "⚠️ [POOL] Cannot start {} worker: {}", kind, e);
+                    break;
+                }
+            }
+        }
+    }
+
+    // Run a script in an idle worker (or a fresh one if none is idle)
+    pub async fn run(
+        &self,
+        kind: &str,
+        interpreter: &str,
+        job: Value,
+        max_output: usize,
+        mut on_chunk: impl FnMut(&str),
+    ) -> Result<WorkerOutput, String> {
+        let key = pool_key(kind, interpreter);
+        let idle = self.idle.lock().ok().and_then(|mut i| i.get_mut(&key).and_then(Vec::pop));
+        let mut worker = match idle {
+            Some(worker) => worker,
+            None => self.spawn(kind, interpreter).await.map_err(|e| format!("Cannot start {} worker: {}", kind, e))?,
+        };
+
+        let mut line = job.to_string();
+        line.push('\n');
+        worker.stdin.write_all(line.as_bytes()).await.map_err(|e| format!("Worker write failed: {}", e))?;
+        worker.stdin.flush().await.map_err(|e| format!("Worker write failed: {}", e))?;
+
+        let mut output = WorkerOutput { stdout: OutputBuffer::new(max_output), stderr: OutputBuffer::new(MAX_STDERR), code: 0 };
+        loop {
+            let frame = match worker.frames.next_line().await {
+                Ok(Some(frame)) => frame,
+                _ => return Err(format!("{} worker exited unexpectedly", kind)),
+            };
+            let Ok(frame) = serde_json::from_str::<Value>(&frame) else { continue };
+            if let Some(text) = frame["out"].as_str() {
+                output.stdout.push(text.as_bytes());
+                on_chunk(text);
+            } else if let Some(text) = frame["err"].as_str() {
+                output.stderr.push(text.as_bytes());
+            } else if let Some(code) = frame["exit"].as_i64() {
+                output.code = code as i32;
+                break;
+            }
+        }
+
+        self.release(&key, worker);
+        Ok(output)
+    }
+
+    fn release(&self, key: &str, worker: Worker) {
+        if worker.generation != self.generation.load(Ordering::Relaxed) { return; }
+        if let Ok(mut idle) = self.idle.lock() {
+            let workers = idle.entry(key.to_string()).or_default();
+            if workers.len() < self.size.load(Ordering::Relaxed) { workers.push(worker); }
+        }
+    }
+
+    async fn spawn(&self, kind: &str, interpreter: &str) -> std::io::Result<Worker> {
+        let generation = self.generation.load(Ordering::Relaxed);
+        let script = self.bootstrap(kind)?;
+        let parts = crate::system::split_shell_args(interpreter);
+        let Some(program) = parts.first() else {
+            return Err(std::io::Error::new(std::io::ErrorKind::InvalidInput, "empty interpreter"));
+        };
+
+        let mut cmd = Command::new(program);
+        cmd.args(&parts[1..]).arg(&script);
+        cmd.stdin(Stdio::piped()).stdout(Stdio::piped()).stderr(Stdio::inherit());
+        cmd.kill_on_drop(true);
+        #[cfg(target_os = "windows")]
+        cmd.creation_flags(crate::system::CREATE_NO_WINDOW);
+
+        let mut child = cmd.spawn()?;
+        let stdin = child.stdin.take().ok_or_else(|| std::io::Error::other("no stdin"))?;
+        let stdout = child.stdout.take().ok_or_else(|| std::io::Error::other("no stdout"))?;
+        debug!(target: "backend", "🔥 [POOL] Started {} worker ({})", kind, interpreter);
+        let tracked = self.children.track(child.id(), kind);
+        Ok(Worker { generation, _child: child, _tracked: tracked, stdin, frames: BufReader::new(stdout).lines() })
+    }
+
+    // Write the bootstrap script for `kind` (once) and return its path
+    fn bootstrap(&self, kind: &str) -> std::io::Result<PathBuf> {
+        let (name, source) = match kind {
+            "python" => ("frontier_worker.py", PYTHON_WORKER),
+            "node" => ("frontier_worker.js", NODE_WORKER),
+            other => return Err(std::io::Error::new(std::io::ErrorKind::InvalidInput, format!("unknown worker '{}'", other))),
+        };
+        let path = self.dir.join(name);
+        if fs::read_to_string(&path).ok().as_deref() != Some(source) {
+            fs::create_dir_all(&self.dir)?;
+            fs::write(&path, source)?;
+        }
+        Ok(path)
+    }
+}
+
+// Job message for a worker
+pub fn job(script: &Path, args: &[String], cwd: &Path, env: &HashMap<String, String>) -> Value {
+    json!({
+        "script": script,
+        "args": args,
+        "cwd": cwd,
+        "env": env,
+    })
+}
+
+fn pool_key(kind: &str, interpreter: &str) -> String {
+    format!("{}|{}", kind, interpreter)
+}
+
+const PYTHON_WORKER: &str = r#"# Frontier worker: runs backend scripts sent as JSON lines on stdin
+import json, os, runpy, sys, traceback
+
+frames = sys.stdout
+
+def send(frame):
+    frames.write(json.dumps(frame) + "\n")
+    frames.flush()
+
+class Pipe:
+    def __init__(self, key):
+        self.key = key
+    def write(self, text):
+        if text:
+            send({self.key: text})
+        return len(text)
+    def flush(self):
+        pass
+    def isatty(self):
+        return False
+
+while True:
+    line = sys.stdin.readline()
+    if not line:
+        break
+    job = json.loads(line)
+    os.chdir(job["cwd"])
+    os.environ.update(job["env"])
+    sys.argv = [job["script"]] + job["args"]
+    sys.stdout, sys.stderr = Pipe("out"), Pipe("err")
+    code = 0
+    try:
+        runpy.run_path(job["script"], run_name="__main__")
+    except SystemExit as e:
+        if isinstance(e.code, int):
+            code = e.code
+        elif e.code is not None:
+            print(e.code, file=sys.stderr)
+            code = 1
+    except BaseException:
+        traceback.print_exc()
+        code = 1
+    finally:
+        sys.stdout, sys.stderr = sys.__stdout__, sys.__stderr__
+    send({"exit": code})
+"#;
+
+const NODE_WORKER: &str = r#"// Frontier worker: runs backend scripts sent as JSON lines on stdin
+const readline = require('readline');
+const path = require('path');
+
+const writeFrame = process.stdout.write.bind(process.stdout);
+const send = (frame) => writeFrame(JSON.stringify(frame) + '\n');
+const exit = process.exit;
+
+class ExitSignal { constructor(code) { this.code = code; } }
+
+const jobs = [];
+let busy = false;
+let closed = false;
+
+async function run(job) {
+    process.chdir(job.cwd);
+    Object.assign(process.env, job.env);
+    process.argv = [process.argv[0], job.script, ...job.args];
+    process.stdout.write = (chunk) => { send({ out: String(chunk) }); return true; };
+    process.stderr.write = (chunk) => { send({ err: String(chunk) }); return true; };
+    process.exit = (code) => { throw new ExitSignal(code || 0); };
+
+    const file = path.resolve(job.script);
+    delete require.cache[file];
+    let code = 0;
+    try {
+        // Scripts that do async work should export a promise (module.exports = main())
+        const result = require(file);
+        if (result && typeof result.then === 'function') await result;
+    } catch (e) {
+        if (e instanceof ExitSignal) code = e.code;
+        else { process.stderr.write(String(e && e.stack || e) + '\n'); code = 1; }
+    }
+    delete process.stdout.write;
+    delete process.stderr.write;
+    process.exit = exit;
+    send({ exit: code });
+}
+
+async function next() {
+    if (busy) return;
+    busy = true;
+    while (jobs.length) await run(jobs.shift());
+    busy = false;
+    if (closed) exit(0);
+}
+
+readline.createInterface({ input: process.stdin })
+    .on('line', (line) => { jobs.push(JSON.parse(line)); next(); })
+    .on('close', () => { closed = true; if (!busy) exit(0); });
+"#;
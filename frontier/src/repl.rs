@@ -0,0 +1,93 @@
+// Copyright (c) 2026 The Frontier Framework Authors
+// SPDX-License-Identifier: Apache-2.0 WITH LLVM-exception OR MIT
+
+// Dev REPL Module
+//
+// In `frontier dev`, the terminal accepts commands on stdin so backends can be
+// exercised without writing throwaway buttons:
+//
+//   list                 -> registered triggers
+//   <trigger> [args...]  -> run it and print the raw reply
+//   open <page.html>     -> open a window
+//   help                 -> usage
+
+use std::io::{self, BufRead, Write};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Instant;
+use tokio::runtime::Handle;
+use tokio::sync::Notify;
+
+use crate::inspect;
+use crate::system::{self, SystemState};
+
+// Start the REPL thread; `open_window` is called for `open <page>`
+pub fn start<F>(system: Arc<Mutex<SystemState>>, runtime: Handle, open_window: F)
+where
+    F: Fn(String) + Send + 'static,
+{
+    thread::spawn(move || {
+        eprintln!("⌨️  [REPL] Type 'help' for dev commands");
+        let stdin = io::stdin();
+        for line in stdin.lock().lines() {
+            let Ok(line) = line else { break };
+            let line = line.trim();
+            if line.is_empty() { continue; }
+
+            let (cmd, args) = line.split_once(' ').map(|(c, a)| (c, a.trim())).unwrap_or((line, ""));
+            match cmd {
+                "help" => print_help(),
+                "list" => print_triggers(&system),
+                "open" if !args.is_empty() => open_window(args.to_string()),
+                trigger => run_trigger(&system, &runtime, trigger, args),
+            }
+            let _ = io::stderr().flush();
+        }
+    });
+}
+
+fn print_help() {
+    eprintln!("⌨️  [REPL] Commands:");
+    eprintln!("    list                 Show registered triggers");
+    eprintln!("    <trigger> [args...]  Run a backend and print its raw reply");
+    eprintln!("    open <page.html>     Open a window");
+}
+
+fn print_triggers(system: &Arc<Mutex<SystemState>>) {
+    let sys = system.lock().unwrap();
+    let mut triggers: Vec<(&String, &String)> = sys.commands.iter().map(|(k, m)| (k, &m.filename)).collect();
+    triggers.sort();
+    if triggers.is_empty() {
+        eprintln!("⌨️  [REPL] No triggers registered (add files to app/backend)");
+    }
+    for (trigger, file) in triggers {
+        eprintln!("    {:<20} {}", trigger, file);
+    }
+}
+
+fn run_trigger(system: &Arc<Mutex<SystemState>>, runtime: &Handle, trigger: &str, args: &str) {
+    let started = Instant::now();
+    let job = {
+        let sys = system.lock().unwrap();
+        if !sys.commands.contains_key(trigger) {
+            eprintln!("⌨️  [REPL] Unknown command '{}' (try 'list')", trigger);
+            return;
+        }
+        system::BackendJob::new(&sys, trigger, args)
+    };
+    let result = match job {
+        Ok(job) => runtime.block_on(system::run_backend(job, Arc::new(Notify::new()), None)),
+        Err(result) => result,
+    };
+    let detail = result.error.as_deref().unwrap_or(args);
+    inspect::record_ipc("repl", "backend", trigger, detail, Some(started.elapsed().as_millis()), result.error.is_some());
+
+    eprintln!("⌨️  [REPL] {} {}", trigger, args);
+    match &result.binary {
+        Some(bytes) => eprintln!("<{} bytes of MessagePack>", bytes.len()),
+        None => eprintln!("{}", result.reply.trim_end()),
+    }
+    if let Some(err) = result.error {
+        eprintln!("❌ [REPL] {}", err);
+    }
+}
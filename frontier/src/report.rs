@@ -0,0 +1,92 @@
+// Copyright (c) 2026 The Frontier Framework Authors
+// SPDX-License-Identifier: Apache-2.0 WITH LLVM-exception OR MIT
+
+// Build Report Module
+//
+// This module centralizes the Manager's progress output. In the default text
+// mode it prints the usual emoji log lines; with `--format json` every record
+// is a single JSON object per line on stdout, so CI systems and GUIs can drive
+// the build without scraping text. Child process output (cargo, compilers,
+// hooks) is redirected to stderr in JSON mode to keep stdout parseable.
+
+use serde_json::{json, Value};
+use std::process::{Command, Stdio};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static JSON_MODE: AtomicBool = AtomicBool::new(false);
+
+// Enable JSON output and report panics (build failures) as error records
+pub fn enable_json() {
+    JSON_MODE.store(true, Ordering::SeqCst);
+    std::panic::set_hook(Box::new(|info| {
+        let message = if let Some(s) = info.payload().downcast_ref::<&str>() {
+            s.to_string()
+        } else if let Some(s) = info.payload().downcast_ref::<String>() {
+            s.clone()
+        } else {
+            "Unknown error".to_string()
+        };
+        emit(json!({ "type": "error", "message": message }));
+    }));
+}
+
+pub fn is_json() -> bool {
+    JSON_MODE.load(Ordering::SeqCst)
+}
+
+// A build phase started (e.g. "config", "backend", "frontend", "compile", "bundle")
+pub fn phase(name: &str, text: &str) {
+    if is_json() {
+        emit(json!({ "type": "phase", "phase": name }));
+    } else {
+        println!("{}", text);
+    }
+}
+
+// A step inside a phase (e.g. one backend file being built)
+pub fn step(phase: &str, item: &str, text: &str) {
+    if is_json() {
+        emit(json!({ "type": "step", "phase": phase, "item": item }));
+    } else {
+        println!("{}", text);
+    }
+}
+
+// A phase finished successfully
+pub fn done(phase: &str, text: &str) {
+    if is_json() {
+        emit(json!({ "type": "done", "phase": phase }));
+    } else {
+        println!("{}", text);
+    }
+}
+
+// A non-fatal problem
+pub fn warn(text: &str) {
+    if is_json() {
+        emit(json!({ "type": "warning", "message": text }));
+    } else {
+        println!("⚠️  {}", text);
+    }
+}
+
+// Final build result
+pub fn result(exe_path: &str) {
+    if is_json() {
+        emit(json!({ "type": "result", "status": "success", "exe": exe_path }));
+    } else {
+        println!("\n✅ SUCCESS!");
+        println!("📁 Native App: {}", exe_path);
+    }
+}
+
+// Keep child process output off stdout while in JSON mode
+pub fn redirect_child_output(cmd: &mut Command) {
+    if is_json() {
+        cmd.stdout(Stdio::from(std::io::stderr()));
+    }
+}
+
+fn emit(record: Value) {
+    println!("{}", record);
+}
@@ -0,0 +1,95 @@
+// Copyright (c) 2026 The Frontier Framework Authors
+// SPDX-License-Identifier: Apache-2.0 WITH LLVM-exception OR MIT
+
+// URL Security Module
+//
+// The [security] section is compiled once (each whitelist becomes a single
+// RegexSet) and shared by every window through an Arc. Windows look the rules
+// up on each navigation instead of keeping their own copies, so a reloaded
+// frontier.toml applies to windows that are already open.
+
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+use crate::config::SecurityConfig;
+
+// Compiled [security] section, shared by all windows
+pub type SharedSecurity = Arc<RwLock<SecurityRules>>;
+
+pub struct SecurityRules {
+    pub config: SecurityConfig, // As written in frontier.toml (listed by __state)
+    pub internal: UrlWhitelist,
+    pub browser: UrlWhitelist,
+    windows: HashMap<String, WindowRules>,
+}
+
+// [security.windows.<id>]
+pub struct WindowRules {
+    pub internal: UrlWhitelist,
+    pub browser: UrlWhitelist,
+    pub csp: Option<String>,           // Content-Security-Policy for <scheme>:// responses
+    pub commands: Option<Vec<String>>, // Backend triggers this window may run (None = all)
+}
+
+impl SecurityRules {
+    pub fn new(config: SecurityConfig) -> Self {
+        let windows = config.windows.iter()
+            .map(|(id, sec)| (id.clone(), WindowRules {
+                internal: UrlWhitelist::new(&sec.allowed_internal),
+                browser: UrlWhitelist::new(&sec.allowed_browser),
+                csp: sec.csp.clone(),
+                commands: sec.commands.clone(),
+            }))
+            .collect();
+        SecurityRules {
+            internal: UrlWhitelist::new(&config.allowed_internal),
+            browser: UrlWhitelist::new(&config.allowed_browser),
+            windows,
+            config,
+        }
+    }
+
+    pub fn shared(config: SecurityConfig) -> SharedSecurity {
+        Arc::new(RwLock::new(Self::new(config)))
+    }
+
+    pub fn window(&self, id: &str) -> Option<&WindowRules> {
+        self.windows.get(id)
+    }
+}
+
+// URL whitelist ([security] allowed_internal/allowed_browser or page additions),
+// compiled to a single RegexSet
+pub struct UrlWhitelist {
+    patterns: regex::RegexSet,
+}
+
+impl UrlWhitelist {
+    pub fn new(whitelist: &[String]) -> Self {
+        let regexes: Vec<String> = whitelist.iter()
+            .map(|pattern| {
+                let has_wildcard = pattern.ends_with('*');
+                let base_pattern = pattern.trim_end_matches('*').trim_end_matches('/');
+                let regex_pattern = base_pattern.replace(".", "\\.").replace("/", "\\/");
+
+                // If pattern has wildcard: allow base path and any subpaths (e.g., https://kaiohsg.dev/*)
+                // If pattern has no wildcard: allow only exact URL (e.g., https://kaiohsg.dev)
+                if has_wildcard {
+                    format!(r"^{}(/.*)?\/?$", regex_pattern)
+                } else {
+                    format!(r"^{}\/?$", regex_pattern)
+                }
+            })
+            // Patterns that don't compile never matched before either; skip them
+            .filter(|re| regex::Regex::new(re).is_ok())
+            .collect();
+        let patterns = regex::RegexSet::new(&regexes).unwrap_or_else(|_| regex::RegexSet::empty());
+        UrlWhitelist { patterns }
+    }
+
+    pub fn matches(&self, url: &str) -> bool {
+        let base_url = url.split('?').next().unwrap_or(url).split('#').next().unwrap_or(url);
+        let clean_url = base_url.trim_end_matches('/');
+        self.patterns.is_match(base_url) || self.patterns.is_match(clean_url)
+    }
+}
@@ -0,0 +1,283 @@
+// Copyright (c) 2026 The Frontier Framework Authors
+// SPDX-License-Identifier: Apache-2.0 WITH LLVM-exception OR MIT
+
+// Static File Serving Module
+//
+// Files served over <scheme>:// are kept in memory (up to MAX_CACHED_BYTES
+// each) and only re-read when their size or modification time changes.
+// Responses carry an ETag and Last-Modified header, and a request whose
+// If-None-Match / If-Modified-Since still matches is answered with an empty
+// 304, so large bundles and images aren't re-read or re-sent on every window.
+// In release, bundled files are served from the executable's memory instead.
+// Files inflated from the frontend archive take memory of their own, so they
+// are only kept under the same MAX_CACHED_BYTES limit.
+//
+// Single byte ranges (Range: bytes=...) are answered with 206 Partial Content
+// so <video> and <audio> can seek; ranges of large files are read straight
+// from disk without loading the whole file.
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fs;
+use std::io::{self, Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use wry::http::{header, HeaderMap};
+
+use crate::embedded;
+
+const MAX_CACHED_BYTES: u64 = 8 * 1024 * 1024;
+
+pub struct Asset {
+    data: Option<Cow<'static, [u8]>>, // None for files too large to keep in memory
+    pub len: u64,
+    pub etag: String,
+    pub last_modified: String,
+    modified: SystemTime,
+}
+
+lazy_static::lazy_static! {
+    static ref CACHE: Mutex<HashMap<PathBuf, Arc<Asset>>> = Mutex::new(HashMap::new());
+}
+
+// Read a file, from memory when it hasn't changed since the last request
+pub fn read(path: &Path) -> io::Result<Arc<Asset>> {
+    let meta = fs::metadata(path)?;
+    let modified = meta.modified().unwrap_or(UNIX_EPOCH);
+    let len = meta.len();
+
+    if let Some(cached) = CACHE.lock().ok().and_then(|c| c.get(path).cloned()) {
+        if cached.modified == modified && cached.len == len {
+            return Ok(cached);
+        }
+    }
+
+    let cacheable = len <= MAX_CACHED_BYTES;
+    let asset = Arc::new(Asset {
+        data: if cacheable { Some(Cow::Owned(fs::read(path)?)) } else { None },
+        len,
+        etag: etag(len, modified),
+        last_modified: httpdate::fmt_http_date(modified),
+        modified,
+    });
+    if cacheable {
+        if let Ok(mut cache) = CACHE.lock() {
+            cache.insert(path.to_path_buf(), asset.clone());
+        }
+    }
+    Ok(asset)
+}
+
+// Bundled file (release), served from the executable's memory (large
+// archive entries are inflated again on each request instead of kept)
+pub fn embedded(name: &str) -> Option<Arc<Asset>> {
+    let key = PathBuf::from(format!("embedded:{}", name));
+    if let Some(cached) = CACHE.lock().ok().and_then(|c| c.get(&key).cloned()) {
+        return Some(cached);
+    }
+
+    let file = embedded::file(name)?;
+    let modified = file.last_modified.map(|s| UNIX_EPOCH + Duration::from_secs(s)).unwrap_or(UNIX_EPOCH);
+    let cacheable = matches!(file.data, Cow::Borrowed(_)) || file.data.len() as u64 <= MAX_CACHED_BYTES;
+    let asset = Arc::new(Asset {
+        len: file.data.len() as u64,
+        data: Some(file.data),
+        etag: format!("\"{}\"", file.hash),
+        last_modified: httpdate::fmt_http_date(modified),
+        modified,
+    });
+    if cacheable {
+        if let Ok(mut cache) = CACHE.lock() {
+            cache.insert(key, asset.clone());
+        }
+    }
+    Some(asset)
+}
+
+impl Asset {
+    // Bytes start..=end (the whole file without a range)
+    pub fn bytes(&self, path: &Path, range: Option<(u64, u64)>) -> io::Result<Cow<'static, [u8]>> {
+        let (start, end) = range.unwrap_or((0, self.len.saturating_sub(1)));
+        match (&self.data, range) {
+            (Some(data), None) => return Ok(data.clone()),
+            (Some(data), Some(_)) => return Ok(Cow::Owned(data.get(start as usize..=end as usize).unwrap_or_default().to_vec())),
+            (None, None) => return fs::read(path).map(Cow::Owned),
+            (None, Some(_)) => {}
+        }
+        let mut file = fs::File::open(path)?;
+        file.seek(SeekFrom::Start(start))?;
+        let mut buf = Vec::with_capacity((end - start + 1) as usize);
+        file.take(end - start + 1).read_to_end(&mut buf)?;
+        Ok(Cow::Owned(buf))
+    }
+}
+
+// Content-Type of an asset: [mime] by extension, then mime_guess
+pub fn content_type(path: &Path, overrides: &HashMap<String, String>) -> String {
+    path.extension()
+        .and_then(|ext| overrides.get(&ext.to_string_lossy().to_lowercase()))
+        .cloned()
+        .unwrap_or_else(|| mime_guess::from_path(path).first_or_octet_stream().to_string())
+}
+
+// Requested byte range, if any
+//
+// Ok(None): no (usable) Range header, send the whole file
+// Ok(Some((start, end))): inclusive range to send with 206
+// Err(()): the range can't be satisfied, send 416
+pub fn requested_range(request: &HeaderMap, asset: &Asset) -> Result<Option<(u64, u64)>, ()> {
+    let Some(range) = request.get(header::RANGE).and_then(|v| v.to_str().ok()) else { return Ok(None) };
+    // If-Range: only honour the range when the page still has this version
+    if let Some(if_range) = request.get(header::IF_RANGE).and_then(|v| v.to_str().ok()) {
+        if if_range.trim() != asset.etag && if_range.trim() != asset.last_modified {
+            return Ok(None);
+        }
+    }
+    // Multiple ranges (bytes=0-10,20-30) aren't supported; the whole file is sent instead
+    let Some(spec) = range.trim().strip_prefix("bytes=").filter(|s| !s.contains(',')) else { return Ok(None) };
+    let Some((from, to)) = spec.split_once('-') else { return Ok(None) };
+
+    let len = asset.len;
+    let (start, end) = match (from.trim(), to.trim()) {
+        // bytes=-500: the last 500 bytes
+        ("", suffix) => {
+            let n: u64 = suffix.parse().map_err(|_| ())?;
+            if n == 0 { return Err(()); }
+            (len.saturating_sub(n), len.saturating_sub(1))
+        }
+        (start, "") => (start.parse().map_err(|_| ())?, len.saturating_sub(1)),
+        (start, end) => {
+            let start: u64 = start.parse().map_err(|_| ())?;
+            let end: u64 = end.parse().map_err(|_| ())?;
+            (start, end.min(len.saturating_sub(1)))
+        }
+    };
+    if len == 0 || start >= len || start > end { return Err(()); }
+    Ok(Some((start, end)))
+}
+
+// True when the page already holds this version (answer with 304)
+pub fn is_not_modified(request: &HeaderMap, asset: &Asset) -> bool {
+    // If-None-Match wins over If-Modified-Since when both are sent
+    if let Some(tags) = request.get(header::IF_NONE_MATCH).and_then(|v| v.to_str().ok()) {
+        return tags.split(',').any(|t| t.trim() == "*" || t.trim() == asset.etag);
+    }
+    if let Some(since) = request.get(header::IF_MODIFIED_SINCE).and_then(|v| v.to_str().ok()) {
+        if let Ok(since) = httpdate::parse_http_date(since) {
+            // HTTP dates have 1 second resolution
+            let modified = asset.modified.duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0);
+            let since = since.duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0);
+            return modified <= since;
+        }
+    }
+    false
+}
+
+fn etag(len: u64, modified: SystemTime) -> String {
+    let nanos = modified.duration_since(UNIX_EPOCH).map(|d| d.as_nanos()).unwrap_or(0);
+    format!("\"{:x}-{:x}\"", len, nanos)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wry::http::HeaderValue;
+
+    // 1000 bytes, last modified at 1_700_000_000 seconds
+    fn asset() -> Asset {
+        let modified = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        Asset { data: None, len: 1000, etag: "\"abc\"".into(), last_modified: httpdate::fmt_http_date(modified), modified }
+    }
+
+    fn headers(pairs: &[(header::HeaderName, &str)]) -> HeaderMap {
+        let mut map = HeaderMap::new();
+        for (name, value) in pairs {
+            map.insert(name.clone(), HeaderValue::from_str(value).unwrap());
+        }
+        map
+    }
+
+    fn range(value: &str) -> Result<Option<(u64, u64)>, ()> {
+        requested_range(&headers(&[(header::RANGE, value)]), &asset())
+    }
+
+    #[test]
+    fn no_range_sends_the_whole_file() {
+        assert_eq!(requested_range(&HeaderMap::new(), &asset()), Ok(None));
+    }
+
+    #[test]
+    fn closed_range() {
+        assert_eq!(range("bytes=0-99"), Ok(Some((0, 99))));
+        assert_eq!(range("bytes=500-500"), Ok(Some((500, 500))));
+    }
+
+    #[test]
+    fn open_ended_range_runs_to_the_end() {
+        assert_eq!(range("bytes=900-"), Ok(Some((900, 999))));
+    }
+
+    #[test]
+    fn suffix_range_is_the_last_bytes() {
+        assert_eq!(range("bytes=-100"), Ok(Some((900, 999))));
+        // Longer than the file: the whole file
+        assert_eq!(range("bytes=-5000"), Ok(Some((0, 999))));
+        assert_eq!(range("bytes=-0"), Err(()));
+    }
+
+    #[test]
+    fn end_past_the_file_is_clamped() {
+        assert_eq!(range("bytes=990-5000"), Ok(Some((990, 999))));
+    }
+
+    #[test]
+    fn unsatisfiable_ranges_answer_416() {
+        assert_eq!(range("bytes=1000-"), Err(()));
+        assert_eq!(range("bytes=2000-3000"), Err(()));
+        assert_eq!(range("bytes=50-10"), Err(()));
+        assert_eq!(range("bytes=abc-"), Err(()));
+    }
+
+    #[test]
+    fn unsupported_ranges_send_the_whole_file() {
+        assert_eq!(range("bytes=0-10,20-30"), Ok(None));
+        assert_eq!(range("items=0-10"), Ok(None));
+    }
+
+    #[test]
+    fn if_range_only_honours_the_current_version() {
+        let current = asset();
+        let matching = headers(&[(header::RANGE, "bytes=0-9"), (header::IF_RANGE, "\"abc\"")]);
+        assert_eq!(requested_range(&matching, &current), Ok(Some((0, 9))));
+        let by_date = headers(&[(header::RANGE, "bytes=0-9"), (header::IF_RANGE, &current.last_modified)]);
+        assert_eq!(requested_range(&by_date, &current), Ok(Some((0, 9))));
+        let stale = headers(&[(header::RANGE, "bytes=0-9"), (header::IF_RANGE, "\"old\"")]);
+        assert_eq!(requested_range(&stale, &current), Ok(None));
+    }
+
+    #[test]
+    fn if_none_match() {
+        assert!(is_not_modified(&headers(&[(header::IF_NONE_MATCH, "\"abc\"")]), &asset()));
+        assert!(is_not_modified(&headers(&[(header::IF_NONE_MATCH, "\"x\", \"abc\"")]), &asset()));
+        assert!(is_not_modified(&headers(&[(header::IF_NONE_MATCH, "*")]), &asset()));
+        assert!(!is_not_modified(&headers(&[(header::IF_NONE_MATCH, "\"old\"")]), &asset()));
+        assert!(!is_not_modified(&HeaderMap::new(), &asset()));
+    }
+
+    #[test]
+    fn if_modified_since() {
+        let at = |secs: u64| httpdate::fmt_http_date(UNIX_EPOCH + Duration::from_secs(secs));
+        assert!(is_not_modified(&headers(&[(header::IF_MODIFIED_SINCE, &at(1_700_000_000))]), &asset()));
+        assert!(is_not_modified(&headers(&[(header::IF_MODIFIED_SINCE, &at(1_800_000_000))]), &asset()));
+        assert!(!is_not_modified(&headers(&[(header::IF_MODIFIED_SINCE, &at(1_600_000_000))]), &asset()));
+        assert!(!is_not_modified(&headers(&[(header::IF_MODIFIED_SINCE, "not a date")]), &asset()));
+    }
+
+    #[test]
+    fn if_none_match_wins_over_if_modified_since() {
+        let since = asset().last_modified;
+        let changed = headers(&[(header::IF_NONE_MATCH, "\"old\""), (header::IF_MODIFIED_SINCE, &since)]);
+        assert!(!is_not_modified(&changed, &asset()));
+    }
+}
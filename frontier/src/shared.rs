@@ -0,0 +1,107 @@
+// Copyright (c) 2026 The Frontier Framework Authors
+// SPDX-License-Identifier: Apache-2.0 WITH LLVM-exception OR MIT
+
+// Shared State Module
+//
+// One JSON tree held by the runtime for every window and backend
+// (Frontier.state). Pages read it with get(path), change it with
+// set(path, value) and subscribe to the parts they show; each change is
+// broadcast as { path, value } to the windows that subscribed. Paths are
+// dot-separated keys ("user.name"; "" is the whole tree) and null removes a key.
+// Backends get the tree as FRONTIER_STATE and change it by printing
+// `::frontier-state::<JSON merge patch>` lines, which are left out of their reply.
+// The tree lives as long as the app.
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+use tracing::warn;
+
+pub const PATCH_PREFIX: &str = "::frontier-state::";
+
+// A change, as broadcast to subscribed windows
+#[derive(Serialize, Clone)]
+pub struct Change {
+    pub path: String,
+    pub value: Value,
+}
+
+fn keys(path: &str) -> impl Iterator<Item = &str> {
+    path.split('.').filter(|k| !k.is_empty())
+}
+
+fn object(node: &mut Value) -> &mut Map<String, Value> {
+    if !node.is_object() { *node = Value::Object(Map::new()); }
+    match node {
+        Value::Object(map) => map,
+        _ => unreachable!(),
+    }
+}
+
+pub fn get(tree: &Value, path: &str) -> Value {
+    keys(path).try_fold(tree, |node, key| node.get(key)).cloned().unwrap_or(Value::Null)
+}
+
+// Set `value` at `path`, creating the objects on the way; None when nothing changed
+pub fn set(tree: &mut Value, path: &str, value: Value) -> Option<Change> {
+    if get(tree, path) == value { return None; }
+    let keys: Vec<&str> = keys(path).collect();
+    let Some((last, parents)) = keys.split_last() else {
+        // The root stays an object
+        if !value.is_object() && !value.is_null() {
+            warn!(target: "ipc", "⚠️ [STATE] The whole state can only be set to an object");
+            return None;
+        }
+        *tree = if value.is_null() { Value::Object(Map::new()) } else { value.clone() };
+        return Some(Change { path: String::new(), value });
+    };
+    let mut node = tree;
+    for key in parents {
+        node = object(node).entry(key.to_string()).or_insert(Value::Null);
+    }
+    let map = object(node);
+    if value.is_null() {
+        map.remove(*last);
+    } else {
+        map.insert(last.to_string(), value.clone());
+    }
+    Some(Change { path: keys.join("."), value })
+}
+
+// Apply a JSON merge patch (RFC 7386), as the changes it makes
+pub fn merge(tree: &mut Value, patch: Value) -> Vec<Change> {
+    let mut changes = Vec::new();
+    merge_at(tree, "", patch, &mut changes);
+    changes
+}
+
+fn merge_at(tree: &mut Value, path: &str, patch: Value, changes: &mut Vec<Change>) {
+    let Value::Object(fields) = patch else {
+        changes.extend(set(tree, path, patch));
+        return;
+    };
+    for (key, value) in fields {
+        let child = if path.is_empty() { key } else { format!("{}.{}", path, key) };
+        if value.is_object() && get(tree, &child).is_object() {
+            merge_at(tree, &child, value, changes);
+        } else {
+            changes.extend(set(tree, &child, value));
+        }
+    }
+}
+
+// Split a backend's output into its reply and the patches it printed
+pub fn take_patches(output: &str) -> (String, Vec<Value>) {
+    if !output.contains(PATCH_PREFIX) { return (output.to_string(), Vec::new()); }
+    let mut reply = Vec::new();
+    let mut patches = Vec::new();
+    for line in output.lines() {
+        match line.trim_start().strip_prefix(PATCH_PREFIX) {
+            Some(patch) => match serde_json::from_str(patch) {
+                Ok(patch) => patches.push(patch),
+                Err(e) => warn!(target: "backend", "⚠️ [STATE] Invalid state patch from a backend: {}", e),
+            },
+            None => reply.push(line),
+        }
+    }
+    (reply.join("\n"), patches)
+}
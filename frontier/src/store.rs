@@ -0,0 +1,140 @@
+// Copyright (c) 2026 The Frontier Framework Authors
+// SPDX-License-Identifier: Apache-2.0 WITH LLVM-exception OR MIT
+
+// Key/Value Store Module
+//
+// Frontier.store: JSON values kept in the data folder without a backend, such
+// as settings. Each namespace is one file, store/<name>.json: "default", one
+// the page names, or "window-<page id>" for Frontier.store.window(). Calls run
+// on the event loop one at a time, and each change is written to a temporary
+// file that then replaces the old one, so windows never interleave writes and
+// a crash never leaves half a file. Backends can read the files from
+// FRONTIER_DATA_DIR.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+use serde::Deserialize;
+use serde_json::{json, Map, Value};
+use tracing::{debug, warn};
+
+#[derive(Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum Op {
+    Get,
+    Set, // null deletes
+    Delete,
+    Keys,
+}
+
+#[derive(Deserialize)]
+struct Request {
+    op: Op,
+    namespace: Option<String>,
+    #[serde(default)]
+    window: bool, // The calling page's own namespace
+    #[serde(default)]
+    key: String,
+    #[serde(default)]
+    value: Value,
+}
+
+impl Request {
+    // The file the request is for; `page` is the calling page's id
+    fn namespace(&self, page: &str) -> String {
+        if self.window {
+            let page: String = page.chars().map(|c| if c.is_ascii_alphanumeric() || c == '-' { c } else { '_' }).collect();
+            return format!("window-{}", page);
+        }
+        self.namespace.clone().unwrap_or_else(|| "default".to_string())
+    }
+}
+
+pub struct Store {
+    dir: PathBuf,
+    namespaces: HashMap<String, Map<String, Value>>, // Loaded on first use
+}
+
+impl Store {
+    pub fn new(dir: PathBuf) -> Self {
+        Store { dir, namespaces: HashMap::new() }
+    }
+
+    // { value } or { error } for a page's request
+    pub fn answer(&mut self, page: &str, request: &str) -> Value {
+        let reply = serde_json::from_str::<Request>(request)
+            .map_err(|e| format!("Invalid store request: {}", e))
+            .and_then(|request| self.handle(&request.namespace(page), request));
+        match reply {
+            Ok(value) => json!({ "value": value }),
+            Err(e) => json!({ "error": e }),
+        }
+    }
+
+    fn handle(&mut self, namespace: &str, request: Request) -> Result<Value, String> {
+        if !valid_name(namespace) {
+            return Err(format!("Invalid store namespace '{}' (letters, digits, '-' and '_')", namespace));
+        }
+        let entries = self.load(namespace)?;
+        let changed = match request.op {
+            Op::Get => return Ok(entries.get(&request.key).cloned().unwrap_or(Value::Null)),
+            Op::Keys => return Ok(entries.keys().cloned().map(Value::String).collect()),
+            _ if request.key.is_empty() => return Err("A store key can't be empty".to_string()),
+            Op::Set if !request.value.is_null() => {
+                let mut changed = entries.clone();
+                changed.insert(request.key, request.value);
+                changed
+            }
+            Op::Set | Op::Delete => {
+                if !entries.contains_key(&request.key) { return Ok(Value::Null); }
+                let mut changed = entries.clone();
+                changed.remove(&request.key);
+                changed
+            }
+        };
+        // Only kept once it's on disk
+        self.save(namespace, &changed)?;
+        self.namespaces.insert(namespace.to_string(), changed);
+        Ok(Value::Null)
+    }
+
+    fn load(&mut self, namespace: &str) -> Result<&Map<String, Value>, String> {
+        if !self.namespaces.contains_key(namespace) {
+            let path = self.file(namespace);
+            let entries = match fs::read_to_string(&path) {
+                Ok(text) => serde_json::from_str(&text).map_err(|e| {
+                    // Left as it is, so nothing the user had is overwritten
+                    warn!(target: "ipc", "⚠️ [STORE] {} is not a JSON object: {}", path.display(), e);
+                    format!("Store '{}' is damaged: {}", namespace, e)
+                })?,
+                Err(_) => Map::new(),
+            };
+            self.namespaces.insert(namespace.to_string(), entries);
+        }
+        Ok(&self.namespaces[namespace])
+    }
+
+    fn save(&self, namespace: &str, entries: &Map<String, Value>) -> Result<(), String> {
+        let path = self.file(namespace);
+        let temp = path.with_extension("json.tmp");
+        let text = serde_json::to_string_pretty(entries).unwrap_or_default();
+        fs::create_dir_all(&self.dir)
+            .and_then(|_| fs::write(&temp, text))
+            .and_then(|_| fs::rename(&temp, &path))
+            .map_err(|e| {
+                warn!(target: "ipc", "⚠️ [STORE] Cannot write {}: {}", path.display(), e);
+                format!("Cannot save store '{}': {}", namespace, e)
+            })?;
+        debug!(target: "ipc", "💾 [STORE] Saved {}", namespace);
+        Ok(())
+    }
+
+    fn file(&self, namespace: &str) -> PathBuf {
+        self.dir.join(format!("{}.json", namespace))
+    }
+}
+
+fn valid_name(name: &str) -> bool {
+    !name.is_empty() && name.len() <= 64 && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
@@ -3,13 +3,38 @@
 
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::path::PathBuf;
-use std::process::Command;
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::process::{ChildStderr, ChildStdout, Command};
+use tokio::sync::mpsc::{self, UnboundedReceiver, UnboundedSender};
+use tokio::sync::{Notify, OwnedSemaphorePermit, Semaphore};
+use tracing::{debug, error, info, info_span, warn, Instrument};
+
+use crate::config::ServiceConfig;
+use crate::embedded;
+use crate::envelope::BackendCall;
+use crate::perf;
+use crate::pool::{self, WorkerPool};
 
 #[cfg(target_os = "windows")]
-use std::os::windows::process::CommandExt;
-#[cfg(target_os = "windows")]
-const CREATE_NO_WINDOW: u32 = 0x08000000;
+pub(crate) const CREATE_NO_WINDOW: u32 = 0x08000000;
+
+// stdout kept for the reply unless [backend] max_output says otherwise
+const DEFAULT_MAX_OUTPUT: usize = 16 * 1024 * 1024;
+// Captured stderr, for error messages and detailed replies (also the warm workers')
+pub(crate) const MAX_STDERR: usize = 64 * 1024;
+// A streamed "line" is handed over once it gets this long, even without a newline
+const MAX_LINE: usize = 1024 * 1024;
+// Restarts in a row before a [[service]] that keeps stopping is given up on
+const DEFAULT_MAX_RESTARTS: u32 = 5;
+// Seconds before a [[service]] is started again
+const DEFAULT_RESTART_DELAY: u64 = 1;
+// A service that ran this long before it stopped starts its restart count over
+const SERVICE_STABLE_RUN: Duration = Duration::from_secs(60);
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct RuntimeMeta {
@@ -18,6 +43,14 @@ pub struct RuntimeMeta {
     pub interpreter: Option<String>,
     #[serde(default = "default_true")]
     pub suppress_window: bool,
+    #[serde(default)]
+    pub worker: Option<String>,
+    #[serde(default)]
+    pub timeout_ms: Option<u64>, // The module's, unless [backend.timeouts] sets one for the trigger
+    #[serde(default)]
+    pub args_mode: Option<String>,
+    #[serde(default)]
+    pub serial: bool,
 }
 
 fn default_true() -> bool { true }
@@ -28,6 +61,14 @@ pub struct ModuleManifest {
     pub interpreter: Option<String>,
     #[serde(default = "default_true")]
     pub suppress_window: bool,
+    #[serde(default)]
+    pub worker: Option<String>,
+    #[serde(default)]
+    pub timeout_ms: Option<u64>, // Milliseconds before its backends are killed (default: [backend] timeout)
+    #[serde(default)]
+    pub args_mode: Option<String>, // How page calls pass their arguments: "argv" (default), "single" or "stdin-json"
+    #[serde(default)]
+    pub serial: bool, // Page calls of each of its triggers run one at a time, in order
     #[cfg(debug_assertions)]
     pub build: Option<BuildRule>,
 }
@@ -44,17 +85,40 @@ pub struct SystemState {
     pub modules_map: HashMap<String, ModuleManifest>,
     pub base_dir: PathBuf,
     pub data_dir: PathBuf,
+    pub config_dir: PathBuf,
+    pub cache_dir: PathBuf,
     #[cfg(debug_assertions)]
     pub dev_cache: PathBuf,
-    pub allowed_internal: Vec<String>,
-    pub allowed_browser: Vec<String>,
     pub is_dev: bool,
+    pub devtools: bool,
+    pub security: crate::security::SharedSecurity,
+    pub scheme: String,
+    pub dev_frontend_url: Option<String>,
+    pub network: crate::config::NetworkConfig,
+    pub backend: crate::config::BackendConfig,
+    pub workers: Arc<WorkerPool>,
+    pub i18n: serde_json::Value,
+    pub features: HashMap<String, bool>,
     pub window_icon: Option<wry::application::window::Icon>,
+    pub window_defaults: crate::config::WindowConfig,
+    pub window_overrides: HashMap<String, crate::config::WindowOverride>,
+    pub accessibility: crate::config::AccessibilityConfig,
+    pub os_accessibility: crate::platform::AccessibilitySettings,
+    pub keyboard: crate::config::KeyboardConfig,
+    pub frontend: crate::config::FrontendConfig,
+    pub appdata: crate::config::AppDataConfig,
+    pub mime_types: HashMap<String, String>, // [mime]
+    pub backend_env: HashMap<String, String>, // [env.vars]
+    pub shared_state: serde_json::Value, // Frontier.state (shared.rs)
+    pub launch_args: Vec<String>,  // The app's own command-line arguments (instance::Launch)
+    pub launch_files: Vec<String>, // Files it was started with, as absolute paths
+    pub children: Arc<ChildProcesses>,    // Backend and worker processes alive
+    pub scheduler: Arc<Scheduler>,        // Concurrency limits of page calls
 }
 
 // Safely splits the command into parts, respecting quotes.
 // Shared between Build and Interpreter logic.
-fn split_shell_args(cmd: &str) -> Vec<String> {
+pub(crate) fn split_shell_args(cmd: &str) -> Vec<String> {
     let mut args = Vec::new();
     let mut current = String::new();
     let mut in_quotes = false;
@@ -74,83 +138,861 @@ fn split_shell_args(cmd: &str) -> Vec<String> {
     args
 }
 
-pub fn execute_backend(system: &SystemState, trigger: &str, args: &str) -> String {
-    #[allow(unused_mut)]
-    if let Some(mut meta) = system.commands.get(trigger).cloned() {
-        
+// Reply sent back to the page, and what went wrong (if anything) for the dev error overlay
+pub struct BackendResult {
+    pub reply: String,
+    pub binary: Option<Vec<u8>>, // MessagePack reply ([backend] msgpack), sent instead of `reply`
+    pub error: Option<String>,
+    pub cancelled: bool, // Stopped by Frontier.cancel (not an error, but Frontier.run rejects)
+    pub timed_out: bool,
+    pub busy: bool, // Refused because [backend] max_queue calls were already waiting
+    pub stderr: String,         // Up to 64 KB
+    pub exit_code: Option<i32>, // None when it didn't exit on its own (never started, killed, signal)
+}
+
+impl BackendResult {
+    fn failed(message: String) -> Self {
+        BackendResult { reply: message.clone(), binary: None, error: Some(message), cancelled: false, timed_out: false, busy: false, stderr: String::new(), exit_code: None }
+    }
+
+    // Why a failed call failed, for the page ("timeout", "cancelled", "busy"; None for other errors)
+    pub fn code(&self) -> Option<&'static str> {
+        if self.timed_out { Some("timeout") } else if self.cancelled { Some("cancelled") } else if self.busy { Some("busy") } else { None }
+    }
+}
+
+// Called with each stdout line while a backend runs ([backend] stream = true)
+pub type LineSink = Box<dyn Fn(&str) + Send + Sync>;
+
+// Everything needed to run one trigger, copied out of SystemState so the
+// state lock is not held while the process runs
+pub struct BackendJob {
+    trigger: String,
+    args: Vec<String>,
+    stdin: Option<String>, // Written to its stdin, which is then closed (args_mode = "stdin-json")
+    run_path: PathBuf,
+    interpreter: Option<String>,
+    #[allow(dead_code)]
+    suppress_window: bool,
+    base_dir: PathBuf,
+    features: HashMap<String, bool>,
+    dirs: [(&'static str, PathBuf); 3], // FRONTIER_DATA_DIR, FRONTIER_CONFIG_DIR, FRONTIER_CACHE_DIR
+    state: String, // FRONTIER_STATE: the shared state when the job started
+    launch: [(&'static str, String); 2], // FRONTIER_ARGS, FRONTIER_FILES (JSON arrays)
+    env: Vec<(String, String)>, // [env.vars], FRONTIER_BASE_DIR, FRONTIER_DEV and FRONTIER_WINDOW_ID
+    timeout: Option<Duration>,
+    max_output: usize,
+    msgpack: bool, // stdout is MessagePack ([backend] msgpack)
+    // Dev only: compile command (C, Go, ...) and the binary it produces
+    build: Option<(Vec<String>, PathBuf)>,
+    // Warm interpreter to run in (worker kind, pool), see pool.rs
+    worker: Option<(String, Arc<WorkerPool>)>,
+    // Release only: write out the bundled backend files before the first run
+    extract: bool,
+    children: Arc<ChildProcesses>,
+    // Page calls only: waits for a slot first (the scheduler, and the trigger's own limit)
+    queue: Option<(Arc<Scheduler>, Option<usize>)>,
+}
+
+impl BackendJob {
+    // Arguments split on whitespace (`trigger|args`)
+    pub fn new(system: &SystemState, trigger: &str, args: &str) -> Result<Self, BackendResult> {
+        Self::with_args(system, trigger, args.split_whitespace().map(str::to_string).collect())
+    }
+
+    // A page's call, with its arguments passed as the trigger's module says (args_mode)
+    pub fn for_call(system: &SystemState, call: BackendCall) -> Result<Self, BackendResult> {
+        let mode = system.commands.get(&call.trigger).and_then(|meta| meta.args_mode.as_deref()).unwrap_or("argv");
+        let (args, stdin) = match mode {
+            "single" => (call.single_argument(), None),
+            "stdin-json" => (Vec::new(), Some(format!("{}\n", call.payload))),
+            other => {
+                if other != "argv" { warn!(target: "config", "⚠️ [CONFIG] Unknown args_mode '{}' for {}; using argv", other, call.trigger); }
+                (call.args, None)
+            }
+        };
+        let mut job = Self::with_args(system, &call.trigger, args)?;
+        // [backend.concurrency] for the trigger, then its module's serial
+        let limit = system.backend.concurrency.get(&call.trigger).copied()
+            .or_else(|| system.commands.get(&call.trigger).filter(|meta| meta.serial).map(|_| 1));
+        job.queue = Some((system.scheduler.clone(), limit));
+        if stdin.is_some() {
+            // Warm workers have no stdin of their own to give it
+            job.worker = None;
+            job.stdin = stdin;
+        }
+        Ok(job)
+    }
+
+    // Run for a page: FRONTIER_WINDOW_ID is its id (e.g. "index_html")
+    pub fn in_window(mut self, window: &str) -> Self {
+        self.env.push(("FRONTIER_WINDOW_ID".to_string(), window.to_string()));
+        self
+    }
+
+    // Arguments passed as they are (JSON calls, see envelope.rs)
+    pub fn with_args(system: &SystemState, trigger: &str, args: Vec<String>) -> Result<Self, BackendResult> {
+        let Some(meta) = system.commands.get(trigger) else {
+            return Err(BackendResult::failed(format!("Command '{}' not registered", trigger)));
+        };
+        let run_path = resolve_path(&system.base_dir, &meta.filename);
+        #[allow(unused_mut)]
+        let mut build = None;
+
         #[cfg(debug_assertions)]
         if system.is_dev {
-            let file_path = if std::path::Path::new(&meta.filename).is_absolute() {
-                std::path::PathBuf::from(&meta.filename)
-            } else {
-                system.base_dir.join(&meta.filename)
-            };
+            let module = run_path.extension().and_then(|e| e.to_str()).and_then(|ext| system.modules_map.get(ext));
+            if let Some(build_rule) = module.and_then(|m| m.build.as_ref()) {
+                let output_path = system.dev_cache.join(trigger);
+                let in_str = run_path.to_str().unwrap_or("");
+                let out_str = output_path.to_str().unwrap_or("");
+
+                let cmd_parts: Vec<String> = split_shell_args(&build_rule.command)
+                    .into_iter()
+                    .map(|part| part.replace("%IN%", in_str).replace("%OUT%", out_str))
+                    .collect();
 
-            if let Some(ext) = file_path.extension().and_then(|e| e.to_str()) {
-                if let Some(module) = system.modules_map.get(ext) {
-                    if let Some(build_rule) = &module.build {
-                        let output_path = system.dev_cache.join(trigger);
-                        let in_str = file_path.to_str().unwrap_or("");
-                        let out_str = output_path.to_str().unwrap_or("");
-
-                        let cmd_parts: Vec<String> = split_shell_args(&build_rule.command)
-                            .into_iter()
-                            .map(|part| part.replace("%IN%", in_str).replace("%OUT%", out_str))
-                            .collect();
-
-                        if !cmd_parts.is_empty() {
-                            let status = Command::new(&cmd_parts[0])
-                                .args(&cmd_parts[1..])
-                                .current_dir(&system.base_dir)
-                                .status();
-
-                            if let Ok(s) = status {
-                                if s.success() {
-                                    meta.filename = output_path.to_string_lossy().to_string();
-                                } else {
-                                    return format!("Build failed for '{}'.", trigger);
-                                }
-                            }
-                        }
-                    }
+                if !cmd_parts.is_empty() {
+                    build = Some((cmd_parts, output_path));
                 }
             }
         }
 
-        let run_path = if std::path::Path::new(&meta.filename).is_absolute() {
-            std::path::PathBuf::from(&meta.filename)
-        } else {
-            system.base_dir.join(&meta.filename)
+        // Warm workers relay stdout as text, so MessagePack triggers always get their own process
+        let msgpack = system.backend.msgpack.iter().any(|t| t == trigger);
+        let worker = match (&meta.worker, &meta.interpreter) {
+            (Some(kind), Some(_)) if build.is_none() && !msgpack && system.workers.enabled() => Some((kind.clone(), system.workers.clone())),
+            _ => None,
         };
 
-        // Execution Logic
-        let mut cmd = if let Some(interpreter) = &meta.interpreter {
-            // FIX: Use split_shell_args to support complex one-liners like PowerShell
-            let parts = split_shell_args(interpreter);
-            let mut c = Command::new(&parts[0]);
-            c.args(&parts[1..]);
-            c.arg(&run_path); // Pass the path as the first argument to the one-liner
-            c
-        } else {
-            let final_path = if cfg!(windows) && !run_path.to_string_lossy().ends_with(".exe") && run_path.with_extension("exe").exists() {
-                run_path.with_extension("exe")
-            } else {
-                run_path
+        Ok(BackendJob {
+            trigger: trigger.to_string(),
+            args,
+            stdin: None,
+            run_path,
+            interpreter: meta.interpreter.clone(),
+            suppress_window: meta.suppress_window,
+            base_dir: system.base_dir.clone(),
+            features: system.features.clone(),
+            dirs: [
+                ("FRONTIER_DATA_DIR", system.data_dir.clone()),
+                ("FRONTIER_CONFIG_DIR", system.config_dir.clone()),
+                ("FRONTIER_CACHE_DIR", system.cache_dir.clone()),
+            ],
+            state: system.shared_state.to_string(),
+            launch: [
+                ("FRONTIER_ARGS", serde_json::to_string(&system.launch_args).unwrap_or_default()),
+                ("FRONTIER_FILES", serde_json::to_string(&system.launch_files).unwrap_or_default()),
+            ],
+            // [backend.timeouts] for the trigger, then its module's timeout_ms, then [backend] timeout
+            // [env.vars] first, so they can't replace Frontier's own variables
+            env: system.backend_env.iter().map(|(var, value)| (var.clone(), value.clone()))
+                .chain([("FRONTIER_BASE_DIR".to_string(), system.base_dir.to_string_lossy().to_string())])
+                .chain(system.is_dev.then(|| ("FRONTIER_DEV".to_string(), "true".to_string())))
+                .collect(),
+            timeout: system.backend.timeouts.get(trigger).or(meta.timeout_ms.as_ref()).copied().map(Duration::from_millis)
+                .or_else(|| system.backend.timeout.map(Duration::from_secs))
+                .filter(|t| !t.is_zero()),
+            max_output: system.backend.max_output.unwrap_or(DEFAULT_MAX_OUTPUT),
+            msgpack,
+            build,
+            worker,
+            extract: !system.is_dev && Path::new(&meta.filename).is_relative(),
+            children: system.children.clone(),
+            queue: None,
+        })
+    }
+}
+
+// Run a backend to completion, unless it times out or `cancel` is notified
+//
+// The process is killed in both cases (kill_on_drop), so a stuck script never
+// outlives the window that started it.
+pub async fn run_backend(job: BackendJob, cancel: Arc<Notify>, on_line: Option<LineSink>) -> BackendResult {
+    let span = info_span!("backend", trigger = %job.trigger);
+    // MessagePack output has no lines to stream
+    let on_line = if job.msgpack { None } else { on_line };
+    async move {
+        let started = Instant::now();
+        let run = async {
+            // Waiting for a slot doesn't count towards the timeout
+            let _slot = match &job.queue {
+                Some((scheduler, limit)) => match scheduler.slot(&job.trigger, *limit).await {
+                    Ok(slot) => Some(slot),
+                    Err(busy) => return busy,
+                },
+                None => None,
             };
-            Command::new(&final_path)
+            match job.timeout {
+                Some(limit) => tokio::time::timeout(limit, run_process(&job, on_line.as_ref())).await
+                    .unwrap_or_else(|_| BackendResult {
+                        timed_out: true,
+                        ..BackendResult::failed(format!("'{}' timed out after {} ms", job.trigger, limit.as_millis()))
+                    }),
+                None => run_process(&job, on_line.as_ref()).await,
+            }
         };
 
-        cmd.args(args.split_whitespace());
-        cmd.current_dir(&system.base_dir);
+        let result = tokio::select! {
+            result = run => result,
+            _ = cancel.notified() => {
+                info!(target: "backend", "⏹️ [BACKEND] {} cancelled", job.trigger);
+                BackendResult { cancelled: true, error: None, ..BackendResult::failed(format!("'{}' was cancelled", job.trigger)) }
+            }
+        };
+        perf::record("backend", &job.trigger, started);
+        // Top the pool back up (the worker may have been killed by a cancel or timeout)
+        if let (Some((kind, pool)), Some(interpreter)) = (job.worker.clone(), job.interpreter.clone()) {
+            tokio::spawn(async move { pool.warm(&kind, &interpreter).await });
+        }
+        result
+    }
+    .instrument(span)
+    .await
+}
+
+async fn run_process(job: &BackendJob, on_line: Option<&LineSink>) -> BackendResult {
+    let started = Instant::now();
+    let run_path = match prepare(job).await {
+        Ok(run_path) => run_path,
+        Err(failed) => return failed,
+    };
 
-        #[cfg(target_os = "windows")]
-        if meta.suppress_window { cmd.creation_flags(CREATE_NO_WINDOW); }
+    if let (Some((kind, pool)), Some(interpreter)) = (&job.worker, &job.interpreter) {
+        return run_in_worker(job, kind, pool, interpreter, &run_path, on_line, started).await;
+    }
+
+    let mut cmd = match command(job, run_path) {
+        Ok(cmd) => cmd,
+        Err(failed) => return failed,
+    };
+    debug!(target: "backend", "⚙️ [BACKEND] Running {} {}", job.trigger, job.args.join(" "));
+    let mut child = match cmd.spawn() {
+        Ok(child) => child,
+        Err(e) => return BackendResult::failed(format!("Execution failed: {}", e)),
+    };
+    let _tracked = job.children.track(child.id(), &job.trigger);
+    // Written alongside the reads, so a large payload can't block on a full pipe
+    if let (Some(input), Some(mut stdin)) = (job.stdin.clone(), child.stdin.take()) {
+        tokio::spawn(async move { let _ = stdin.write_all(input.as_bytes()).await; });
+    }
+
+    // stdout and stderr are drained together so a chatty stderr can't block the process
+    let (stdout, stderr, status) = tokio::join!(read_stdout(child.stdout.take(), on_line, job.max_output), read_stderr(child.stderr.take()), child.wait());
 
-        match cmd.output() {
-            Ok(output) => String::from_utf8_lossy(&output.stdout).to_string(),
-            Err(e) => format!("Execution failed: {}", e),
+    match status {
+        Ok(status) => {
+            info!(target: "backend", "⚙️ [BACKEND] {} finished with {} in {} ms", job.trigger, status, started.elapsed().as_millis());
+            let error = (!status.success()).then(|| exit_error(job, status, &stderr));
+            BackendResult { stderr: stderr.text(), exit_code: status.code(), ..reply(job, stdout, error) }
         }
+        Err(e) => BackendResult::failed(format!("Execution failed: {}", e)),
+    }
+}
+
+// Write out the bundled backend files (release) or compile the file (dev) if needed; the path to run
+async fn prepare(job: &BackendJob) -> Result<PathBuf, BackendResult> {
+    let mut run_path = job.run_path.clone();
+
+    if job.extract {
+        let base = job.base_dir.clone();
+        match tokio::task::spawn_blocking(move || embedded::extract_backends(&base)).await {
+            Ok(Ok(_)) => {}
+            Ok(Err(e)) => return Err(BackendResult::failed(format!("Cannot extract '{}': {}", job.trigger, e))),
+            Err(e) => return Err(BackendResult::failed(format!("Cannot extract '{}': {}", job.trigger, e))),
+        }
+    }
+
+    if let Some((cmd_parts, output_path)) = &job.build {
+        debug!(target: "backend", "🔨 [BACKEND] Building {}", run_path.display());
+        let status = Command::new(&cmd_parts[0])
+            .args(&cmd_parts[1..])
+            .current_dir(&job.base_dir)
+            .status()
+            .await;
+
+        if let Ok(s) = status {
+            if s.success() {
+                run_path = output_path.clone();
+            } else {
+                return Err(BackendResult::failed(format!("Build failed for '{}'.", job.trigger)));
+            }
+        }
+    }
+    Ok(run_path)
+}
+
+// The backend's process, with its arguments and environment (stdin is closed, unless the job has input)
+fn command(job: &BackendJob, run_path: PathBuf) -> Result<Command, BackendResult> {
+    let mut cmd = if let Some(interpreter) = &job.interpreter {
+        // FIX: Use split_shell_args to support complex one-liners like PowerShell
+        let parts = split_shell_args(interpreter);
+        let Some(program) = parts.first() else {
+            return Err(BackendResult::failed(format!("'{}' has an empty interpreter", job.trigger)));
+        };
+        let mut c = Command::new(program);
+        c.args(&parts[1..]);
+        c.arg(&run_path); // Pass the path as the first argument to the one-liner
+        c
     } else {
-        format!("Command '{}' not registered", trigger)
+        let final_path = if cfg!(windows) && !run_path.to_string_lossy().ends_with(".exe") && run_path.with_extension("exe").exists() {
+            run_path.with_extension("exe")
+        } else {
+            run_path
+        };
+        Command::new(&final_path)
+    };
+
+    cmd.args(&job.args);
+    cmd.current_dir(&job.base_dir);
+    cmd.stdin(if job.stdin.is_some() { Stdio::piped() } else { Stdio::null() }).stdout(Stdio::piped()).stderr(Stdio::piped());
+    cmd.kill_on_drop(true);
+
+    for (var, value) in &job.env {
+        cmd.env(var, value);
+    }
+    // [features] flags as FRONTIER_FEATURE_<NAME>=1/0
+    for (name, enabled) in &job.features {
+        cmd.env(feature_env_name(name), if *enabled { "1" } else { "0" });
+    }
+    // The app's own folders, for backends that keep files of their own
+    for (var, dir) in &job.dirs {
+        cmd.env(var, dir);
+    }
+    cmd.env("FRONTIER_STATE", &job.state);
+    // How the app was started (`MyApp report.csv`)
+    for (var, value) in &job.launch {
+        cmd.env(var, value);
+    }
+
+    #[cfg(target_os = "windows")]
+    if job.suppress_window { cmd.creation_flags(CREATE_NO_WINDOW); }
+    Ok(cmd)
+}
+
+fn exit_error(job: &BackendJob, status: std::process::ExitStatus, stderr: &OutputBuffer) -> String {
+    let stderr = stderr.text().trim().to_string();
+    format!("'{}' exited with {}{}", job.trigger, status, if stderr.is_empty() { String::new() } else { format!(":\n{}", stderr) })
+}
+
+// Run a backend as a session (Frontier.session): each string sent on `input`
+// is written to its stdin and each stdout line goes to `on_line`, until it
+// exits or `kill` is notified. Dropping the sender closes its stdin.
+pub async fn run_session(job: BackendJob, mut input: UnboundedReceiver<String>, kill: Arc<Notify>, on_line: LineSink) -> Result<Option<i32>, String> {
+    let run_path = prepare(&job).await.map_err(|failed| failed.reply)?;
+    let mut cmd = command(&job, run_path).map_err(|failed| failed.reply)?;
+    cmd.stdin(Stdio::piped());
+    debug!(target: "backend", "⚙️ [BACKEND] Starting session {} {}", job.trigger, job.args.join(" "));
+    let mut child = cmd.spawn().map_err(|e| format!("Execution failed: {}", e))?;
+    let _tracked = job.children.track(child.id(), &job.trigger);
+
+    if let Some(mut stdin) = child.stdin.take() {
+        tokio::spawn(async move {
+            while let Some(data) = input.recv().await {
+                if stdin.write_all(data.as_bytes()).await.is_err() || stdin.flush().await.is_err() { break; }
+            }
+        });
+    }
+    let (stdout, stderr) = (child.stdout.take(), child.stderr.take());
+    let finished = tokio::select! {
+        (_, stderr, status) = async { tokio::join!(read_stdout(stdout, Some(&on_line), 0), read_stderr(stderr), child.wait()) } => Some((stderr, status)),
+        _ = kill.notified() => None,
+    };
+    let Some((stderr, status)) = finished else {
+        let _ = child.kill().await;
+        info!(target: "backend", "⏹️ [BACKEND] Session {} killed", job.trigger);
+        return Ok(None);
+    };
+    let status = status.map_err(|e| format!("Execution failed: {}", e))?;
+    info!(target: "backend", "⚙️ [BACKEND] Session {} ended with {}", job.trigger, status);
+    if status.success() { Ok(status.code()) } else { Err(exit_error(&job, status, &stderr)) }
+}
+
+// [[service]] backends, kept running from launch until the app quits. They run
+// like sessions without stdin, so [backend] timeout doesn't apply, and they are
+// stopped with the other backends (ChildProcesses::shutdown)
+#[derive(Default)]
+pub struct Services {
+    stopping: Arc<AtomicBool>,
+}
+
+// Called with the service's trigger and each line it prints
+pub type ServiceSink = Arc<dyn Fn(&str, &str) + Send + Sync>;
+
+impl Services {
+    pub fn start(system: &Arc<Mutex<SystemState>>, runtime: &tokio::runtime::Handle, config: Vec<ServiceConfig>, on_line: ServiceSink) -> Self {
+        let services = Services::default();
+        for service in config {
+            runtime.spawn(supervise(system.clone(), service, services.stopping.clone(), on_line.clone()));
+        }
+        services
+    }
+
+    // The app is quitting: services that stop from now on aren't restarted
+    pub fn stop(&self) {
+        self.stopping.store(true, Ordering::SeqCst);
+    }
+}
+
+// Run a service, and run it again when it stops as its `restart` says
+async fn supervise(system: Arc<Mutex<SystemState>>, service: ServiceConfig, stopping: Arc<AtomicBool>, on_line: ServiceSink) {
+    let restart = match service.restart.as_deref() {
+        None | Some("on-failure") => "on-failure",
+        Some(restart @ ("always" | "never")) => restart,
+        Some(other) => {
+            warn!(target: "config", "⚠️ [CONFIG] Unknown restart '{}' for service {}; using on-failure", other, service.trigger);
+            "on-failure"
+        }
+    };
+    let max_restarts = service.max_restarts.unwrap_or(DEFAULT_MAX_RESTARTS);
+    let delay = Duration::from_secs(service.restart_delay.unwrap_or(DEFAULT_RESTART_DELAY));
+    let mut restarts = 0;
+    loop {
+        let job = BackendJob::new(&system.lock().unwrap(), &service.trigger, service.args.as_deref().unwrap_or(""));
+        let job = match job {
+            Ok(job) => job,
+            Err(failed) => {
+                error!(target: "backend", "❌ [SERVICE] Cannot start {}: {}", service.trigger, failed.reply);
+                return;
+            }
+        };
+        info!(target: "backend", "🛰️ [SERVICE] Starting {}", service.trigger);
+        let started = Instant::now();
+        let sink: LineSink = {
+            let (on_line, trigger) = (on_line.clone(), service.trigger.clone());
+            Box::new(move |line: &str| on_line(&trigger, line))
+        };
+        // The sender is dropped right away, so the service's stdin is closed
+        let (_, input) = mpsc::unbounded_channel();
+        let end = run_session(job, input, Arc::new(Notify::new()), sink).await;
+        if stopping.load(Ordering::SeqCst) { return; }
+
+        let failed = end.is_err();
+        match end {
+            Ok(_) => info!(target: "backend", "🛰️ [SERVICE] {} stopped", service.trigger),
+            Err(e) => warn!(target: "backend", "⚠️ [SERVICE] {}", e),
+        }
+        if restart == "never" || (restart == "on-failure" && !failed) { return; }
+        if started.elapsed() >= SERVICE_STABLE_RUN { restarts = 0; }
+        if restarts >= max_restarts {
+            error!(target: "backend", "❌ [SERVICE] {} stopped {} times in a row; not restarting it", service.trigger, restarts + 1);
+            return;
+        }
+        restarts += 1;
+        tokio::time::sleep(delay).await;
+        if stopping.load(Ordering::SeqCst) { return; }
+    }
+}
+
+// Same as a process run, but inside a warm interpreter from the pool
+async fn run_in_worker(
+    job: &BackendJob,
+    kind: &str,
+    pool: &WorkerPool,
+    interpreter: &str,
+    script: &Path,
+    on_line: Option<&LineSink>,
+    started: Instant,
+) -> BackendResult {
+    let env: HashMap<String, String> = job.env.iter().cloned()
+        .chain(job.features.iter().map(|(name, enabled)| (feature_env_name(name), if *enabled { "1" } else { "0" }.to_string())))
+        .chain(job.dirs.iter().map(|(var, dir)| (var.to_string(), dir.to_string_lossy().to_string())))
+        .chain(std::iter::once(("FRONTIER_STATE".to_string(), job.state.clone())))
+        .chain(job.launch.iter().map(|(var, value)| (var.to_string(), value.clone())))
+        .collect();
+    let message = pool::job(script, &job.args, &job.base_dir, &env);
+
+    debug!(target: "backend", "⚙️ [BACKEND] Running {} {} (warm {} worker)", job.trigger, job.args.join(" "), kind);
+    let mut lines = LineSplitter::new(on_line);
+    let output = pool.run(kind, interpreter, message, job.max_output, |chunk| lines.push(chunk.as_bytes())).await;
+    lines.finish();
+
+    match output {
+        Ok(output) => {
+            info!(target: "backend", "⚙️ [BACKEND] {} finished with code {} in {} ms", job.trigger, output.code, started.elapsed().as_millis());
+            let error = (output.code != 0).then(|| {
+                let stderr = output.stderr.text().trim().to_string();
+                format!("'{}' exited with code {}{}", job.trigger, output.code, if stderr.is_empty() { String::new() } else { format!(":\n{}", stderr) })
+            });
+            BackendResult { stderr: output.stderr.text(), exit_code: Some(output.code), ..reply(job, output.stdout, error) }
+        }
+        Err(e) => BackendResult::failed(format!("Execution failed: {}", e)),
+    }
+}
+
+async fn read_stderr(stderr: Option<ChildStderr>) -> OutputBuffer {
+    let mut buf = OutputBuffer::new(MAX_STDERR);
+    let Some(mut stderr) = stderr else { return buf };
+    let mut chunk = [0u8; 4096];
+    while let Ok(n) = stderr.read(&mut chunk).await {
+        if n == 0 { break; }
+        buf.push(&chunk[..n]);
+    }
+    buf
+}
+
+// Collect stdout (up to `limit` bytes), handing complete lines to `on_line` as they arrive
+async fn read_stdout(stdout: Option<ChildStdout>, on_line: Option<&LineSink>, limit: usize) -> OutputBuffer {
+    let mut out = OutputBuffer::new(limit);
+    let Some(mut stdout) = stdout else { return out };
+    let mut chunk = [0u8; 4096];
+    let mut lines = LineSplitter::new(on_line);
+    while let Ok(n) = stdout.read(&mut chunk).await {
+        if n == 0 { break; }
+        out.push(&chunk[..n]);
+        lines.push(&chunk[..n]);
     }
-}
\ No newline at end of file
+    lines.finish();
+    out
+}
+
+// The reply sent to the page; output past [backend] max_output is dropped
+fn reply(job: &BackendJob, stdout: OutputBuffer, error: Option<String>) -> BackendResult {
+    if stdout.dropped > 0 {
+        warn!(target: "backend", "⚠️ [BACKEND] {} printed {} bytes more than max_output ({}); the reply was cut", job.trigger, stdout.dropped, job.max_output);
+    }
+    if job.msgpack && error.is_none() {
+        return BackendResult { reply: String::new(), binary: Some(stdout.data), error, cancelled: false, timed_out: false, busy: false, stderr: String::new(), exit_code: None };
+    }
+    BackendResult { reply: stdout.text(), binary: None, error, cancelled: false, timed_out: false, busy: false, stderr: String::new(), exit_code: None }
+}
+
+// Captured output with a size limit. Bytes past the limit are still read, so
+// the process never blocks on a full pipe, but they are not kept.
+pub struct OutputBuffer {
+    data: Vec<u8>,
+    limit: usize,
+    dropped: u64,
+}
+
+impl OutputBuffer {
+    pub fn new(limit: usize) -> Self {
+        OutputBuffer { data: Vec::new(), limit, dropped: 0 }
+    }
+
+    pub fn push(&mut self, bytes: &[u8]) {
+        let room = self.limit.saturating_sub(self.data.len());
+        let kept = bytes.len().min(room);
+        self.data.extend_from_slice(&bytes[..kept]);
+        self.dropped += (bytes.len() - kept) as u64;
+    }
+
+    pub fn text(&self) -> String {
+        String::from_utf8_lossy(&self.data).to_string()
+    }
+}
+
+// Cuts streamed output into lines for a LineSink (a no-op without one)
+struct LineSplitter<'a> {
+    sink: Option<&'a LineSink>,
+    pending: Vec<u8>,
+}
+
+impl<'a> LineSplitter<'a> {
+    fn new(sink: Option<&'a LineSink>) -> Self {
+        LineSplitter { sink, pending: Vec::new() }
+    }
+
+    fn push(&mut self, bytes: &[u8]) {
+        let Some(sink) = self.sink else { return };
+        self.pending.extend_from_slice(bytes);
+        while let Some(pos) = self.pending.iter().position(|b| *b == b'\n') {
+            let line: Vec<u8> = self.pending.drain(..=pos).collect();
+            sink(String::from_utf8_lossy(&line[..pos]).trim_end_matches('\r'));
+        }
+        if self.pending.len() >= MAX_LINE {
+            sink(&String::from_utf8_lossy(&self.pending));
+            self.pending.clear();
+        }
+    }
+
+    fn finish(self) {
+        if let (Some(sink), false) = (self.sink, self.pending.is_empty()) {
+            sink(String::from_utf8_lossy(&self.pending).trim_end_matches('\r'));
+        }
+    }
+}
+
+fn resolve_path(base_dir: &Path, filename: &str) -> PathBuf {
+    if Path::new(filename).is_absolute() { PathBuf::from(filename) } else { base_dir.join(filename) }
+}
+
+// Backends in flight, so they can be cancelled per window (on close), per
+// trigger or per call, and the sessions of each window
+#[derive(Default)]
+pub struct RunningJobs {
+    next_id: u64,
+    jobs: HashMap<u64, (String, String, Option<u64>, Arc<Notify>)>, // owner, trigger, callbackId, cancel
+    sessions: HashMap<(String, String), (UnboundedSender<String>, Arc<Notify>)>, // (owner, session id) -> stdin, kill
+}
+
+impl RunningJobs {
+    // Register a job for `owner` (a window key); returns its id and cancel signal
+    pub fn start(&mut self, owner: &str, trigger: &str, call: Option<u64>) -> (u64, Arc<Notify>) {
+        self.next_id += 1;
+        let cancel = Arc::new(Notify::new());
+        self.jobs.insert(self.next_id, (owner.to_string(), trigger.to_string(), call, cancel.clone()));
+        (self.next_id, cancel)
+    }
+
+    pub fn finish(&mut self, id: u64) {
+        self.jobs.remove(&id);
+    }
+
+    // Register a session; None when the owner already has one with this id
+    pub fn open_session(&mut self, owner: &str, id: &str) -> Option<(UnboundedReceiver<String>, Arc<Notify>)> {
+        let key = (owner.to_string(), id.to_string());
+        if self.sessions.contains_key(&key) { return None; }
+        let (input, receiver) = mpsc::unbounded_channel();
+        let kill = Arc::new(Notify::new());
+        self.sessions.insert(key, (input, kill.clone()));
+        Some((receiver, kill))
+    }
+
+    // Write to a session's stdin; false when it isn't running
+    pub fn send(&self, owner: &str, id: &str, data: String) -> bool {
+        self.sessions.get(&(owner.to_string(), id.to_string())).is_some_and(|(input, _)| input.send(data).is_ok())
+    }
+
+    pub fn kill_session(&mut self, owner: &str, id: &str) -> bool {
+        let Some((_, kill)) = self.sessions.remove(&(owner.to_string(), id.to_string())) else { return false };
+        kill.notify_one();
+        true
+    }
+
+    // The session's process ended (`kill` tells it from a new session with the same id)
+    pub fn close_session(&mut self, owner: &str, id: &str, kill: &Arc<Notify>) {
+        let key = (owner.to_string(), id.to_string());
+        if self.sessions.get(&key).is_some_and(|(_, k)| Arc::ptr_eq(k, kill)) {
+            self.sessions.remove(&key);
+        }
+    }
+
+    // Kill every session of the owner (its window closed)
+    pub fn kill_sessions(&mut self, owner: &str) {
+        self.sessions.retain(|(o, _), (_, kill)| {
+            if o == owner { kill.notify_one(); }
+            o != owner
+        });
+    }
+
+    // Cancel the owner's jobs (only those running `trigger`, if given)
+    pub fn cancel(&mut self, owner: &str, trigger: Option<&str>) -> usize {
+        let ids: Vec<u64> = self.jobs.iter()
+            .filter(|(_, (o, t, _, _))| o == owner && trigger.is_none_or(|tr| tr == t))
+            .map(|(id, _)| *id)
+            .collect();
+        for id in &ids {
+            if let Some((_, _, _, cancel)) = self.jobs.remove(id) { cancel.notify_one(); }
+        }
+        ids.len()
+    }
+
+    // Cancel the owner's job for one Frontier.run call; false when it already finished
+    pub fn cancel_call(&mut self, owner: &str, call: u64) -> bool {
+        let Some(id) = self.jobs.iter().find(|(_, (o, _, c, _))| o == owner && *c == Some(call)).map(|(id, _)| *id) else { return false };
+        if let Some((_, _, _, cancel)) = self.jobs.remove(&id) { cancel.notify_one(); }
+        true
+    }
+}
+
+// Limits on page calls running at once: [backend] max_concurrent for all of them and
+// [backend.concurrency] (or a module's serial) per trigger. Calls over a limit wait
+// their turn in order, and once [backend] max_queue calls wait, new ones are refused.
+pub struct Scheduler {
+    global: Option<Arc<Semaphore>>,
+    triggers: Mutex<HashMap<String, (usize, Arc<Semaphore>)>>, // Limit each semaphore was made for
+    max_queue: Option<usize>,
+    waiting: AtomicUsize,
+}
+
+// A place to run in; released when dropped
+pub struct Slot {
+    _permits: Vec<OwnedSemaphorePermit>,
+}
+
+// Counts a call as waiting until it gets its slot (or is cancelled)
+struct Waiting<'a>(&'a AtomicUsize);
+
+impl Drop for Waiting<'_> {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+impl Scheduler {
+    pub fn new(max_concurrent: Option<usize>, max_queue: Option<usize>) -> Self {
+        Scheduler {
+            global: max_concurrent.filter(|max| *max > 0).map(|max| Arc::new(Semaphore::new(max))),
+            triggers: Mutex::new(HashMap::new()),
+            max_queue,
+            waiting: AtomicUsize::new(0),
+        }
+    }
+
+    // Wait for a slot for `trigger` (at most `limit` of its calls at once); the refusal
+    // to send back when the queue is full
+    pub async fn slot(&self, trigger: &str, limit: Option<usize>) -> Result<Slot, BackendResult> {
+        let own = limit.filter(|max| *max > 0).map(|max| {
+            let mut triggers = self.triggers.lock().unwrap();
+            // A new limit (config reloaded) gets a new semaphore; calls holding the old one finish as they are
+            let (made_for, semaphore) = triggers.entry(trigger.to_string()).or_insert_with(|| (max, Arc::new(Semaphore::new(max))));
+            if *made_for != max {
+                *made_for = max;
+                *semaphore = Arc::new(Semaphore::new(max));
+            }
+            semaphore.clone()
+        });
+        // The trigger's own limit first, so a queued call doesn't hold a global slot
+        let mut permits = Vec::new();
+        let mut waiting = None;
+        for semaphore in own.into_iter().chain(self.global.clone()) {
+            if let Ok(permit) = semaphore.clone().try_acquire_owned() {
+                permits.push(permit);
+                continue;
+            }
+            if waiting.is_none() {
+                let queued = self.waiting.fetch_add(1, Ordering::SeqCst);
+                waiting = Some(Waiting(&self.waiting));
+                if self.max_queue.is_some_and(|max| queued >= max) {
+                    warn!(target: "backend", "⚠️ [BACKEND] {} refused: {} calls are already waiting", trigger, queued);
+                    return Err(BackendResult { busy: true, ..BackendResult::failed(format!("'{}' was not run: too many calls are waiting", trigger)) });
+                }
+                debug!(target: "backend", "⏳ [BACKEND] {} waits for a free slot", trigger);
+            }
+            // Semaphores are never closed
+            if let Ok(permit) = semaphore.acquire_owned().await { permits.push(permit); }
+        }
+        Ok(Slot { _permits: permits })
+    }
+}
+
+// Every backend and worker process alive, so none outlives the app (and keeps
+// its files locked): on exit they're asked to stop, and whatever is left after
+// [backend] shutdown_timeout is killed along with the processes it started
+#[derive(Default)]
+pub struct ChildProcesses {
+    pids: Mutex<HashMap<u32, String>>, // Trigger or worker kind
+}
+
+// Keeps a process listed while its Child is alive
+pub struct TrackedChild {
+    children: Arc<ChildProcesses>,
+    pid: Option<u32>,
+}
+
+impl Drop for TrackedChild {
+    fn drop(&mut self) {
+        if let Some(pid) = self.pid {
+            self.children.pids.lock().unwrap().remove(&pid);
+        }
+    }
+}
+
+impl ChildProcesses {
+    pub fn track(self: &Arc<Self>, pid: Option<u32>, name: &str) -> TrackedChild {
+        if let Some(pid) = pid {
+            self.pids.lock().unwrap().insert(pid, name.to_string());
+        }
+        TrackedChild { children: self.clone(), pid }
+    }
+
+    // Ask every process to stop and wait up to `grace` for them (the tasks that
+    // own them see them exit), then kill the rest
+    pub fn shutdown(&self, grace: Duration) {
+        let running: Vec<u32> = self.pids.lock().unwrap().keys().copied().collect();
+        if running.is_empty() { return; }
+        info!(target: "backend", "⏹️ [BACKEND] Stopping {} process(es)", running.len());
+        let asked = running.iter().filter(|pid| terminate(**pid, false)).count();
+        let deadline = Instant::now() + grace;
+        while asked > 0 && Instant::now() < deadline && !self.pids.lock().unwrap().is_empty() {
+            std::thread::sleep(Duration::from_millis(50));
+        }
+        self.kill_all();
+    }
+
+    // Kill every process at once (crashes, and what ignored shutdown)
+    pub fn kill_all(&self) {
+        for (pid, name) in self.pids.lock().unwrap().drain() {
+            warn!(target: "backend", "⏹️ [BACKEND] Killing {} (pid {})", name, pid);
+            terminate(pid, true);
+        }
+    }
+}
+
+// Stop a process and the processes it started; true when the request was delivered.
+// Windows can only ask processes that have a window to close, so console backends
+// are killed once the grace period ends.
+fn terminate(pid: u32, force: bool) -> bool {
+    #[cfg(unix)]
+    let mut cmd = {
+        // The processes it started first, so they aren't left to init
+        let mut cmd = std::process::Command::new("sh");
+        let signal = if force { "KILL" } else { "TERM" };
+        cmd.args(["-c", &format!("pkill -{0} -P {1}; kill -{0} {1}", signal, pid)]);
+        cmd
+    };
+    #[cfg(windows)]
+    let mut cmd = {
+        use std::os::windows::process::CommandExt;
+        let mut cmd = std::process::Command::new("taskkill");
+        cmd.args(["/PID", &pid.to_string(), "/T"]);
+        if force { cmd.arg("/F"); }
+        cmd.creation_flags(CREATE_NO_WINDOW);
+        cmd
+    };
+    cmd.stdout(Stdio::null()).stderr(Stdio::null());
+    cmd.status().is_ok_and(|status| status.success())
+}
+
+// "new-editor" -> FRONTIER_FEATURE_NEW_EDITOR
+fn feature_env_name(name: &str) -> String {
+    let clean: String = name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c.to_ascii_uppercase() } else { '_' })
+        .collect();
+    format!("FRONTIER_FEATURE_{}", clean)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Whether a slot is free right away
+    async fn free(scheduler: &Scheduler, trigger: &str, limit: Option<usize>) -> bool {
+        tokio::time::timeout(Duration::from_millis(50), scheduler.slot(trigger, limit)).await.is_ok()
+    }
+
+    #[tokio::test]
+    async fn global_limit_makes_calls_wait() {
+        let scheduler = Scheduler::new(Some(1), None);
+        let first = scheduler.slot("a", None).await.ok().unwrap();
+        assert!(!free(&scheduler, "b", None).await);
+        drop(first);
+        assert!(free(&scheduler, "b", None).await);
+    }
+
+    #[tokio::test]
+    async fn trigger_limit_only_holds_its_own_calls() {
+        let scheduler = Scheduler::new(None, None);
+        let _first = scheduler.slot("save", Some(1)).await.ok().unwrap();
+        assert!(!free(&scheduler, "save", Some(1)).await);
+        assert!(free(&scheduler, "load", Some(1)).await);
+    }
+
+    #[tokio::test]
+    async fn full_queue_refuses_as_busy() {
+        let scheduler = Scheduler::new(Some(1), Some(0));
+        let _first = scheduler.slot("a", None).await.ok().unwrap();
+        let refused = scheduler.slot("a", None).await.err().unwrap();
+        assert!(refused.busy);
+        assert_eq!(scheduler.waiting.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn changed_trigger_limit_takes_effect() {
+        let scheduler = Scheduler::new(None, None);
+        let _first = scheduler.slot("save", Some(1)).await.ok().unwrap();
+        assert!(free(&scheduler, "save", Some(2)).await);
+    }
+}
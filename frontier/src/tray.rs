@@ -0,0 +1,126 @@
+// Copyright (c) 2026 The Frontier Framework Authors
+// SPDX-License-Identifier: Apache-2.0 WITH LLVM-exception OR MIT
+
+// System Tray Module
+//
+// The [tray] icon of apps that live in the background. Clicking it, or
+// pressing the [tray] hotkey, shows the app: its windows come forward, or the
+// startup pages open when none is open. Its menu has the [[tray.menu]] items,
+// which run like jump list tasks (--task values, see jumplist.rs), and Quit.
+// With start_hidden the app starts with only the icon, and by default it keeps
+// running in the tray when its last window closes; with close_to_tray closing
+// a window only hides it.
+// Linux trays (AppIndicator) don't report clicks, only menu picks.
+
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use tracing::{debug, warn};
+use wry::application::{
+    accelerator::{Accelerator, AcceleratorId},
+    event::{Event, TrayEvent},
+    event_loop::EventLoopWindowTarget,
+    global_shortcut::ShortcutManager,
+    menu::{ContextMenu, MenuId, MenuItemAttributes, MenuType},
+    system_tray::{SystemTray, SystemTrayBuilder},
+    window::Icon,
+};
+
+use crate::config::TrayConfig;
+use crate::jumplist;
+
+// What a click, menu pick or hotkey asks for
+pub enum TrayAction {
+    Show,
+    Task(String), // --task value
+    Quit,
+}
+
+pub struct Tray {
+    icon: Option<SystemTray>,
+    items: HashMap<MenuId, String>,
+    show: Option<MenuId>,
+    quit: MenuId,
+    hotkey: Option<AcceleratorId>,
+    _shortcuts: Option<ShortcutManager>, // Keeps the hotkey registered
+}
+
+impl Tray {
+    // None (logged) when the tray can't be shown
+    pub fn create<T>(event_loop: &EventLoopWindowTarget<T>, config: &TrayConfig, icon: Option<Icon>, app_name: &str) -> Option<Tray> {
+        let Some(icon) = icon else {
+            warn!(target: "app", "⚠️ [TRAY] No app icon to show in the tray ([window] icon)");
+            return None;
+        };
+        // Ids are numbered; the default ones come from the titles, which may repeat
+        let mut menu = ContextMenu::new();
+        let mut items = HashMap::new();
+        // Linux trays only report menu picks, so this is how they show hidden windows
+        let show = config.show_title.as_deref().map(|title| {
+            let id = MenuId::new("frontier-tray-show");
+            menu.add_item(MenuItemAttributes::new(title).with_id(id));
+            id
+        });
+        for (i, item) in config.menu.iter().enumerate() {
+            let Some(task) = jumplist::task_argument(item) else {
+                warn!(target: "config", "⚠️ [CONFIG] Tray menu item '{}' has neither open nor trigger", item.title);
+                continue;
+            };
+            let id = MenuId::new(&format!("frontier-tray-{}", i));
+            menu.add_item(MenuItemAttributes::new(&item.title).with_id(id));
+            items.insert(id, task);
+        }
+        let quit = MenuId::new("frontier-tray-quit");
+        menu.add_item(MenuItemAttributes::new(config.quit_title.as_deref().unwrap_or("Quit")).with_id(quit));
+
+        let icon = match SystemTrayBuilder::new(icon, Some(menu))
+            .with_tooltip(config.tooltip.as_deref().unwrap_or(app_name))
+            .build(event_loop)
+        {
+            Ok(icon) => icon,
+            Err(e) => {
+                warn!(target: "app", "⚠️ [TRAY] Cannot create the tray icon: {}", e);
+                return None;
+            }
+        };
+
+        let mut hotkey = None;
+        let mut shortcuts = None;
+        if let Some(keys) = &config.hotkey {
+            match Accelerator::from_str(keys) {
+                Ok(accelerator) => {
+                    let mut manager = ShortcutManager::new(event_loop);
+                    let id = accelerator.clone().id();
+                    match manager.register(accelerator) {
+                        Ok(_) => {
+                            hotkey = Some(id);
+                            shortcuts = Some(manager);
+                        }
+                        Err(e) => warn!(target: "app", "⚠️ [TRAY] Cannot register the hotkey {}: {}", keys, e),
+                    }
+                }
+                Err(e) => warn!(target: "config", "⚠️ [CONFIG] Invalid [tray] hotkey '{}': {}", keys, e),
+            }
+        }
+        debug!(target: "app", "📌 [TRAY] Tray icon shown ({} menu item(s))", items.len());
+        Some(Tray { icon: Some(icon), items, show, quit, hotkey, _shortcuts: shortcuts })
+    }
+
+    pub fn action<T>(&self, event: &Event<T>) -> Option<TrayAction> {
+        match event {
+            Event::TrayEvent { event: TrayEvent::LeftClick | TrayEvent::DoubleClick, .. } => Some(TrayAction::Show),
+            Event::GlobalShortcutEvent(id) if Some(*id) == self.hotkey => Some(TrayAction::Show),
+            Event::MenuEvent { menu_id, origin: MenuType::ContextMenu, .. } => {
+                if *menu_id == self.quit { return Some(TrayAction::Quit); }
+                if Some(*menu_id) == self.show { return Some(TrayAction::Show); }
+                self.items.get(menu_id).map(|task| TrayAction::Task(task.clone()))
+            }
+            _ => None,
+        }
+    }
+
+    // Windows leaves a dead icon in the tray until it's hovered unless it's removed before exiting
+    pub fn remove(&mut self) {
+        self.icon.take();
+    }
+}
@@ -0,0 +1,142 @@
+// Copyright (c) 2026 The Frontier Framework Authors
+// SPDX-License-Identifier: Apache-2.0 WITH LLVM-exception OR MIT
+
+// WebView2 Runtime Module
+//
+// On Windows every window is drawn by the Microsoft Edge WebView2 runtime.
+// When it is missing (older Windows 10 installs, trimmed corporate images) or
+// older than [webview2] min_version, wry can only fail with an opaque error, so
+// the runtime is checked before the first window is created:
+//
+// evergreen:    offer to download the Evergreen runtime and quit
+// bootstrapper: run the bundled MicrosoftEdgeWebview2Setup.exe silently first
+// fixed:        use the Fixed Version runtime shipped in <exe dir>/WebView2
+
+use std::env;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use native_dialog::{MessageDialog, MessageType};
+use tracing::{info, warn};
+
+use crate::config::WebView2Config;
+use crate::embedded;
+use crate::i18n;
+
+// Where `frontier build` puts the bootstrapper (inside the executable) and the
+// Fixed Version runtime (next to it)
+pub const BOOTSTRAPPER_ASSET: &str = "webview2/MicrosoftEdgeWebview2Setup.exe";
+pub const FIXED_RUNTIME_DIR: &str = "WebView2";
+
+// Evergreen bootstrapper download (Microsoft's permanent link)
+const DOWNLOAD_URL: &str = "https://go.microsoft.com/fwlink/p/?LinkId=2124703";
+
+// Read by the WebView2 loader when wry doesn't name a browser folder itself
+const BROWSER_FOLDER_VAR: &str = "WEBVIEW2_BROWSER_EXECUTABLE_FOLDER";
+
+// Make sure a usable runtime is present; false means the user was told what is
+// missing and the app should quit
+pub fn ensure_runtime(base: &Path, config: &WebView2Config, is_dev: bool) -> bool {
+    if !cfg!(windows) { return true; }
+    let min_version = config.min_version.as_deref();
+
+    match config.mode.as_deref().unwrap_or("evergreen") {
+        "fixed" => return use_fixed_runtime(base, config, is_dev),
+        "bootstrapper" if check_runtime(min_version).is_err() => {
+            // Falls back to the download prompt if the setup fails (offline, blocked by policy)
+            if !run_bootstrapper(base, config, is_dev) {
+                warn!(target: "app", "⚠️ [WEBVIEW2] Bundled bootstrapper didn't install the runtime");
+            }
+        }
+        "evergreen" | "bootstrapper" => {}
+        other => warn!(target: "config", "⚠️ [CONFIG] Unknown [webview2] mode '{}' (use evergreen, bootstrapper or fixed)", other),
+    }
+
+    match check_runtime(min_version) {
+        Ok(version) => {
+            info!(target: "app", "🧭 [WEBVIEW2] Runtime {}", version);
+            true
+        }
+        Err(problem) => {
+            warn!(target: "app", "⚠️ [WEBVIEW2] {}", problem);
+            let download = MessageDialog::new()
+                .set_type(MessageType::Warning)
+                .set_title(&i18n::message("webview2_required", &[]))
+                .set_text(&i18n::message("webview2_download", &[("problem", &problem)]))
+                .show_confirm()
+                .unwrap_or(false);
+            if download { let _ = webbrowser::open(DOWNLOAD_URL); }
+            false
+        }
+    }
+}
+
+// Installed runtime version, or why it can't be used (shown to the user, so translated)
+fn check_runtime(min_version: Option<&str>) -> Result<String, String> {
+    let version = wry::webview::webview_version()
+        .map_err(|_| i18n::message("webview2_not_installed", &[]))?;
+    match min_version {
+        Some(min) if older_than(&version, min) => Err(i18n::message("webview2_outdated", &[("version", &version), ("min", min)])),
+        _ => Ok(version),
+    }
+}
+
+// Compare dotted versions ("110.0.1587.40"); channel suffixes (" beta") are ignored
+fn older_than(version: &str, min: &str) -> bool {
+    let parse = |v: &str| -> Vec<u32> {
+        v.split_whitespace().next().unwrap_or("").split('.').map(|p| p.parse().unwrap_or(0)).collect()
+    };
+    parse(version) < parse(min)
+}
+
+// Run the bootstrapper silently (per-user install without admin rights, per-machine with them)
+fn run_bootstrapper(base: &Path, config: &WebView2Config, is_dev: bool) -> bool {
+    let setup = if is_dev {
+        config.bootstrapper.as_ref().map(|p| base.join(p))
+    } else {
+        embedded::extract(base, BOOTSTRAPPER_ASSET).ok()
+    };
+    let Some(setup) = setup.filter(|p| p.is_file()) else {
+        warn!(target: "app", "⚠️ [WEBVIEW2] No bootstrapper bundled ([webview2] bootstrapper)");
+        return false;
+    };
+
+    info!(target: "app", "📥 [WEBVIEW2] Installing the runtime with {}", setup.display());
+    matches!(Command::new(&setup).args(["/silent", "/install"]).status(), Ok(s) if s.success())
+}
+
+// Point the WebView2 loader at the Fixed Version runtime (a folder set by the
+// user through WEBVIEW2_BROWSER_EXECUTABLE_FOLDER wins)
+fn use_fixed_runtime(base: &Path, config: &WebView2Config, is_dev: bool) -> bool {
+    if env::var_os(BROWSER_FOLDER_VAR).is_none() {
+        let folder = if is_dev {
+            base.join(config.runtime_dir.as_deref().unwrap_or(FIXED_RUNTIME_DIR))
+        } else {
+            exe_dir().join(FIXED_RUNTIME_DIR)
+        };
+        env::set_var(BROWSER_FOLDER_VAR, &folder);
+    }
+
+    match check_runtime(config.min_version.as_deref()) {
+        Ok(version) => {
+            info!(target: "app", "🧭 [WEBVIEW2] Fixed Version runtime {}", version);
+            true
+        }
+        Err(_) => {
+            let folder = PathBuf::from(env::var_os(BROWSER_FOLDER_VAR).unwrap_or_default());
+            warn!(target: "app", "⚠️ [WEBVIEW2] No usable Fixed Version runtime in {}", folder.display());
+            let _ = MessageDialog::new()
+                .set_type(MessageType::Error)
+                .set_title(&i18n::message("webview2_missing", &[]))
+                .set_text(&i18n::message("webview2_fixed_broken", &[("folder", &folder.display().to_string())]))
+                .show_alert();
+            false
+        }
+    }
+}
+
+fn exe_dir() -> PathBuf {
+    env::current_exe().ok()
+        .and_then(|p| p.parent().map(|d| d.to_path_buf()))
+        .unwrap_or_else(|| PathBuf::from("."))
+}
@@ -8,16 +8,49 @@
 use regex::Regex;
 use evalexpr::*;
 use serde::{Deserialize, Serialize};
+use tracing::{debug, warn};
 
-#[derive(Serialize, Deserialize)]
+use crate::config::{WindowConfig, WindowOverride};
+
+#[derive(Serialize, Deserialize, Clone)]
 pub struct WindowState {
     pub width: f64,
     pub height: f64,
     pub x: f64,
     pub y: f64,
     pub maximized: bool,
+    // Monitor the window was on; the position is only restored while it is connected
+    #[serde(default)]
+    pub monitor: Option<String>,
+    #[serde(flatten)]
+    pub view: PageView,
+}
+
+// Zoom and scroll position of a page with frontier-remember-view, as the page
+// last reported them (Frontier.view)
+#[derive(Serialize, Deserialize, Clone, Default)]
+pub struct PageView {
+    #[serde(default)]
+    pub zoom: Option<f64>,
+    #[serde(default)]
+    pub scroll: Option<[f64; 2]>,
+}
+
+// A window open when the app last closed (session.json, [window] restore_session)
+#[derive(Serialize, Deserialize)]
+pub struct SessionWindow {
+    pub page: String,
+    pub state: Option<WindowState>,
 }
 
+impl SessionWindow {
+    // A page opened with its own (or the default) geometry
+    pub fn page(page: &str) -> Self {
+        SessionWindow { page: page.to_string(), state: None }
+    }
+}
+
+#[derive(Serialize)]
 pub struct PageConfig {
     pub title: String,
     pub width: f64,
@@ -27,6 +60,7 @@ pub struct PageConfig {
     pub resizable: bool,
     pub maximized: bool,
     pub persistent: bool,
+    pub remember_view: bool, // Keep the page's zoom and scroll position with its window state
     pub id: String,
     pub icon_path: Option<String>,
     pub min_width: Option<f64>,
@@ -40,32 +74,54 @@ pub struct PageConfig {
     pub allowed_internal: Vec<String>,
     pub allowed_browser: Vec<String>,
     pub ignore_global_security: bool,
+    // Separate cookies/storage ("temp:<name>" starts empty on every run); None shares the app's
+    pub partition: Option<String>,
+    pub menu: bool, // Show the [[menu]] menus in the window's menu bar
+    pub decorations: bool,   // Title bar and borders (false: frameless, see Frontier.window.startDrag)
+    pub transparent: bool,   // See-through window and webview background
+    pub always_on_top: bool,
+    pub skip_taskbar: bool,  // Not shown in the taskbar (Windows, Linux)
+    pub monitor: Option<usize>, // Monitor the window opens on, in the OS's order (default: the primary one)
 }
 
-pub fn parse_html_config(html: &str, filename: &str) -> PageConfig {
+pub fn parse_html_config(html: &str, filename: &str, defaults: &WindowConfig) -> PageConfig {
     let re_title = Regex::new(r"<title>(.*?)</title>").unwrap();
     // Regex melhorada para aceitar aspas simples ou duplas e espaços
     let re_meta = Regex::new(r#"<meta\s+name=["']frontier-(.*?)["']\s+content=["'](.*?)["']\s*/?>"#).unwrap();
 
+    // Baseline from [window] in frontier.toml, overridden by the page below
+    let default_width = defaults.width.unwrap_or(800.0);
+    let default_height = defaults.height.unwrap_or(600.0);
+
     let mut config = PageConfig {
-        title: re_title.captures(html).map(|c| c[1].to_string()).unwrap_or_else(|| "App".into()),
-        width: 800.0,
-        height: 600.0,
+        title: re_title.captures(html).map(|c| c[1].trim().to_string()).filter(|t| !t.is_empty())
+            .or_else(|| defaults.title.clone())
+            .unwrap_or_else(|| "App".into()),
+        width: default_width,
+        height: default_height,
         x: None, y: None,
-        resizable: true,
-        maximized: false,
+        resizable: defaults.resizable.unwrap_or(true),
+        maximized: defaults.maximized.unwrap_or(false),
         persistent: false,
+        remember_view: false,
         id: filename.replace('.', "_"),
         icon_path: None,
-        min_width: None,
-        min_height: None,
-        max_width: None,
-        max_height: None,
-        minimizable: true,
-        maximizable: true,
+        min_width: defaults.min_width,
+        min_height: defaults.min_height,
+        max_width: defaults.max_width,
+        max_height: defaults.max_height,
+        minimizable: defaults.minimizable.unwrap_or(true),
+        maximizable: defaults.maximizable.unwrap_or(true),
         allowed_internal: Vec::new(),
         allowed_browser: Vec::new(),
         ignore_global_security: false,
+        partition: None,
+        menu: true,
+        decorations: true,
+        transparent: false,
+        always_on_top: false,
+        skip_taskbar: false,
+        monitor: None,
     };
 
     for caps in re_meta.captures_iter(html) {
@@ -73,8 +129,8 @@ pub fn parse_html_config(html: &str, filename: &str) -> PageConfig {
         let val = &caps[2];
         match key {
             "title" => config.title = val.to_string(),
-            "width" => config.width = val.parse().unwrap_or(800.0),
-            "height" => config.height = val.parse().unwrap_or(600.0),
+            "width" => config.width = val.parse().unwrap_or(default_width),
+            "height" => config.height = val.parse().unwrap_or(default_height),
             "min-width" => config.min_width = val.parse().ok(),
             "min-height" => config.min_height = val.parse().ok(),
             "max-width" => config.max_width = val.parse().ok(),
@@ -82,6 +138,7 @@ pub fn parse_html_config(html: &str, filename: &str) -> PageConfig {
             "resizable" => config.resizable = val == "true",
             "maximized" => config.maximized = val == "true",
             "persistent" => config.persistent = val == "true",
+            "remember-view" => config.remember_view = val == "true",
             "minimizable" => config.minimizable = val != "false",
             "maximizable" => config.maximizable = val != "false",
             "icon" => config.icon_path = Some(val.into()),
@@ -95,9 +152,17 @@ pub fn parse_html_config(html: &str, filename: &str) -> PageConfig {
                 config.allowed_browser = val.split(',').map(|s| s.trim().to_string()).collect();
             }
             "ignore-global-security" => config.ignore_global_security = val == "true",
-            _ => {}
+            "partition" => config.partition = Some(val.into()).filter(|p: &String| !p.is_empty()),
+            "menu" => config.menu = val != "none",
+            "decorations" => config.decorations = val != "false",
+            "transparent" => config.transparent = val == "true",
+            "always-on-top" => config.always_on_top = val == "true",
+            "skip-taskbar" => config.skip_taskbar = val == "true",
+            "monitor" => config.monitor = val.parse().ok(),
+            _ => warn!(target: "window", "⚠️ [WINDOW] Unknown meta tag 'frontier-{}' in {}", key, filename),
         }
     }
+    debug!(target: "window", "📄 [WINDOW] {} -> id '{}', {}x{}", filename, config.id, config.width, config.height);
     config
 }
 
@@ -106,13 +171,20 @@ pub fn create_manual_config(url: &str, config_str: &str) -> PageConfig {
         title: "Frontier Window".into(),
         width: 800.0, height: 600.0,
         x: None, y: None,
-        resizable: true, maximized: false, persistent: false,
+        resizable: true, maximized: false, persistent: false, remember_view: false,
         id: url.replace(|c: char| !c.is_alphanumeric(), "_"),
         icon_path: None, min_width: None, min_height: None, max_width: None, max_height: None,
         minimizable: true, maximizable: true,
         allowed_internal: Vec::new(),
         allowed_browser: Vec::new(),
         ignore_global_security: false, // Default
+        partition: None,
+        menu: true,
+        decorations: true,
+        transparent: false,
+        always_on_top: false,
+        skip_taskbar: false,
+        monitor: None,
     };
 
     for part in config_str.split(',') {
@@ -133,6 +205,7 @@ pub fn create_manual_config(url: &str, config_str: &str) -> PageConfig {
                 "resizable" => config.resizable = val == "true",
                 "maximized" => config.maximized = val == "true",
                 "persistent" => config.persistent = val == "true",
+                "remember_view" => config.remember_view = val == "true",
                 "minimizable" => config.minimizable = val != "false",
                 "maximizable" => config.maximizable = val != "false",
                 "ignore_global_security" => {
@@ -140,6 +213,13 @@ pub fn create_manual_config(url: &str, config_str: &str) -> PageConfig {
                 },
                 "icon" => config.icon_path = Some(val.into()),
                 "id" => config.id = val.into(),
+                "partition" => config.partition = Some(val.into()).filter(|p: &String| !p.is_empty()),
+                "menu" => config.menu = val != "none",
+                "decorations" => config.decorations = val != "false",
+                "transparent" => config.transparent = val == "true",
+                "always_on_top" => config.always_on_top = val == "true",
+                "skip_taskbar" => config.skip_taskbar = val == "true",
+                "monitor" => config.monitor = val.parse().ok(),
                 "allowed_internal" => {
                     config.allowed_internal = val.split('|').map(|s| s.trim().to_string()).collect();
                 },
@@ -153,11 +233,52 @@ pub fn create_manual_config(url: &str, config_str: &str) -> PageConfig {
     config
 }
 
-pub fn evaluate_math_expression(formula: &str, screen_width: f64, screen_height: f64, window_width: f64, window_height: f64) -> f64 {
+// Apply a [windows."page"] section from frontier.toml on top of the page config
+pub fn apply_window_override(config: &mut PageConfig, ov: &WindowOverride) {
+    if let Some(v) = &ov.title { config.title = v.clone(); }
+    if let Some(v) = &ov.id { config.id = v.clone(); }
+    if let Some(v) = &ov.icon { config.icon_path = Some(v.clone()); }
+    if let Some(v) = ov.width { config.width = v; }
+    if let Some(v) = ov.height { config.height = v; }
+    if ov.min_width.is_some() { config.min_width = ov.min_width; }
+    if ov.min_height.is_some() { config.min_height = ov.min_height; }
+    if ov.max_width.is_some() { config.max_width = ov.max_width; }
+    if ov.max_height.is_some() { config.max_height = ov.max_height; }
+    if let Some(v) = &ov.x { config.x = Some(v.clone()); }
+    if let Some(v) = &ov.y { config.y = Some(v.clone()); }
+    if let Some(v) = ov.resizable { config.resizable = v; }
+    if let Some(v) = ov.maximized { config.maximized = v; }
+    if let Some(v) = ov.minimizable { config.minimizable = v; }
+    if let Some(v) = ov.maximizable { config.maximizable = v; }
+    if let Some(v) = ov.persistent { config.persistent = v; }
+    if let Some(v) = ov.remember_view { config.remember_view = v; }
+    if let Some(v) = &ov.allowed_internal { config.allowed_internal = v.clone(); }
+    if let Some(v) = &ov.allowed_browser { config.allowed_browser = v.clone(); }
+    if let Some(v) = ov.ignore_global_security { config.ignore_global_security = v; }
+    if let Some(v) = &ov.partition { config.partition = Some(v.clone()).filter(|p| !p.is_empty()); }
+    if let Some(v) = ov.menu { config.menu = v; }
+    if let Some(v) = ov.decorations { config.decorations = v; }
+    if let Some(v) = ov.transparent { config.transparent = v; }
+    if let Some(v) = ov.always_on_top { config.always_on_top = v; }
+    if let Some(v) = ov.skip_taskbar { config.skip_taskbar = v; }
+    if ov.monitor.is_some() { config.monitor = ov.monitor; }
+}
+
+// Variables: screen_w/screen_h of the window's monitor, win_w/win_h, and monitor_count
+// and screen_w_<i>/screen_h_<i> of every monitor (logical sizes, in the OS's order)
+pub fn evaluate_math_expression(formula: &str, screen: (f64, f64), window: (f64, f64), monitors: &[(f64, f64)]) -> f64 {
     let mut context = HashMapContext::new();
-    let _ = context.set_value("screen_w".into(), Value::Float(screen_width));
-    let _ = context.set_value("screen_h".into(), Value::Float(screen_height));
-    let _ = context.set_value("win_w".into(), Value::Float(window_width));
-    let _ = context.set_value("win_h".into(), Value::Float(window_height));
-    eval_number_with_context(formula, &context).unwrap_or(0.0)
+    let _ = context.set_value("screen_w".into(), Value::Float(screen.0));
+    let _ = context.set_value("screen_h".into(), Value::Float(screen.1));
+    let _ = context.set_value("win_w".into(), Value::Float(window.0));
+    let _ = context.set_value("win_h".into(), Value::Float(window.1));
+    let _ = context.set_value("monitor_count".into(), Value::Float(monitors.len() as f64));
+    for (i, (width, height)) in monitors.iter().enumerate() {
+        let _ = context.set_value(format!("screen_w_{}", i), Value::Float(*width));
+        let _ = context.set_value(format!("screen_h_{}", i), Value::Float(*height));
+    }
+    eval_number_with_context(formula, &context).unwrap_or_else(|e| {
+        warn!(target: "window", "⚠️ [WINDOW] Invalid position formula '{}': {}", formula, e);
+        0.0
+    })
 }